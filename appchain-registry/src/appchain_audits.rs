@@ -0,0 +1,86 @@
+use crate::*;
+use appchain_metadata_rules::{assert_valid_url, invalid_field};
+use types::{AppchainAuditRecord, AuditVerdict};
+
+/// The length of the hex string of a sha256 hash
+const SHA256_HEX_LENGTH: usize = 64;
+
+impl AppchainRegistry {
+    /// Add (or replace) the audit record of the caller for an appchain.
+    ///
+    /// An auditor has at most one record for an appchain, so that a re-audit
+    /// replaces the previous verdict of the auditor. The records submitted before the
+    /// registration of the appchain (for a removed appchain with the same id) are kept.
+    pub fn internal_add_audit_record(
+        &mut self,
+        appchain_id: &AppchainId,
+        report_url: String,
+        report_sha256: String,
+        verdict: AuditVerdict,
+    ) -> AppchainAuditRecord {
+        assert_valid_url("report_url", &report_url);
+        let report_sha256 = report_sha256.to_lowercase();
+        ensure!(
+            report_sha256.len() == SHA256_HEX_LENGTH
+                && report_sha256.chars().all(|c| c.is_ascii_hexdigit()),
+            invalid_field(
+                "report_sha256",
+                "It should be the hex string of a sha256 hash.".to_string()
+            )
+        );
+        let record = AppchainAuditRecord {
+            appchain_id: appchain_id.clone(),
+            auditor: env::predecessor_account_id(),
+            report_url,
+            report_sha256,
+            verdict,
+            submitted_timestamp: U64::from(env::block_timestamp()),
+        };
+        let registered_time = self.get_appchain_basedata(appchain_id).registered_time;
+        let mut records = self.get_audit_records_of(appchain_id);
+        records.retain(|existing| {
+            !existing.auditor.eq(&record.auditor)
+                || existing.submitted_timestamp.0 < registered_time
+        });
+        records.push(record.clone());
+        self.appchain_audit_records.insert(appchain_id, &records);
+        record
+    }
+    /// Get the audit records of an appchain.
+    pub fn get_audit_records_of(&self, appchain_id: &AppchainId) -> Vec<AppchainAuditRecord> {
+        self.appchain_audit_records
+            .get(appchain_id)
+            .unwrap_or_default()
+    }
+    /// Count the approving audits of an appchain, which are submitted by current auditors
+    /// after the registration of the appchain.
+    pub fn count_approving_audits_of(&self, appchain_id: &AppchainId) -> u32 {
+        let registry_roles = self.registry_roles.get().unwrap();
+        let registered_time = self.get_appchain_basedata(appchain_id).registered_time;
+        self.get_audit_records_of(appchain_id)
+            .iter()
+            .filter(|record| {
+                record.verdict == AuditVerdict::Approved
+                    && record.submitted_timestamp.0 >= registered_time
+                    && registry_roles.auditors.contains(&record.auditor)
+            })
+            .count() as u32
+    }
+    /// Assert that an appchain has enough approving audits to pass auditing.
+    pub fn assert_enough_approving_audits(&self, appchain_id: &AppchainId) {
+        let required_count = self
+            .registry_settings
+            .get()
+            .unwrap()
+            .required_approving_audits;
+        let approved_count = self.count_approving_audits_of(appchain_id);
+        ensure!(
+            approved_count >= required_count,
+            RegistryError::NotEnoughApprovingAudits {
+                appchain_id: appchain_id.clone(),
+                required_count,
+                approved_count,
+            }
+        );
+    }
+}
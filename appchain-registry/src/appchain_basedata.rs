@@ -1,10 +1,15 @@
-use crate::types::{AppchainMetadata, AppchainState, AppchainStatus};
+use crate::types::{
+    AppchainHealth, AppchainMetadata, AppchainMetadataSnapshot, AppchainState, AppchainStatus,
+    AppchainSummary, AppchainSyncMetrics,
+};
 use crate::*;
 use near_sdk::collections::LazyOption;
 use near_sdk::json_types::U64;
 use near_sdk::Timestamp;
 use std::convert::TryInto;
 
+const MAX_ANCHOR_VERSION_LENGTH: usize = 32;
+
 /// Appchain basedata
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct AppchainBasedata {
@@ -22,6 +27,10 @@ pub struct AppchainBasedata {
     pub validator_count: u32,
     pub total_stake: Balance,
     pub dao_proposal_url: Option<String>,
+    pub sync_metrics: Option<AppchainSyncMetrics>,
+    pub last_sync_time: Timestamp,
+    pub booting_time: Timestamp,
+    pub health: AppchainHealth,
 }
 
 impl AppchainBasedata {
@@ -51,6 +60,10 @@ impl AppchainBasedata {
             validator_count: 0,
             total_stake: 0,
             dao_proposal_url: None,
+            sync_metrics: None,
+            last_sync_time: 0,
+            booting_time: 0,
+            health: AppchainHealth::Healthy,
         }
     }
     /// Get appchain id
@@ -61,6 +74,14 @@ impl AppchainBasedata {
     pub fn metadata(&self) -> AppchainMetadata {
         self.appchain_metadata.get().unwrap()
     }
+    /// Get the values of all updatable fields, including metadata, EVM chain id and DAO proposal url
+    pub fn metadata_snapshot(&self) -> AppchainMetadataSnapshot {
+        AppchainMetadataSnapshot {
+            metadata: self.metadata(),
+            evm_chain_id: self.evm_chain_id,
+            dao_proposal_url: self.dao_proposal_url.clone(),
+        }
+    }
     /// Get acount id of anchor
     pub fn anchor(&self) -> Option<AccountId> {
         self.appchain_anchor.clone()
@@ -90,10 +111,11 @@ impl AppchainBasedata {
         if let Some(bytes) = env::storage_read(
             &StorageKey::AppchainVotingScore(self.appchain_id.clone()).into_bytes(),
         ) {
-            i128::from_be_bytes(bytes.try_into().expect(&format!(
-                "Invalid storage data for voting score of appchain {}",
-                self.appchain_id
-            )))
+            i128::from_be_bytes(bytes.try_into().unwrap_or_else(|_| {
+                panic_with(RegistryError::InvalidStorageData {
+                    key: format!("voting score of appchain {}", self.appchain_id),
+                })
+            }))
         } else {
             0
         }
@@ -116,14 +138,32 @@ impl AppchainBasedata {
             validator_count: self.validator_count,
             total_stake: self.total_stake.into(),
             dao_proposal_url: self.dao_proposal_url.clone(),
+            tags: self.tags(),
+            sync_metrics: self.sync_metrics.clone(),
+            last_sync_time: self.last_sync_time.into(),
+            health: self.health.clone(),
+        }
+    }
+    /// Get summary of status, without reading metadata from storage
+    pub fn summary(&self) -> AppchainSummary {
+        AppchainSummary {
+            appchain_id: self.appchain_id.clone(),
+            appchain_owner: self.appchain_owner.clone(),
+            appchain_state: self.appchain_state.clone(),
+            voting_score: self.voting_score().into(),
+            registered_time: self.registered_time.into(),
+            go_live_time: self.go_live_time.into(),
+            validator_count: self.validator_count,
+            total_stake: self.total_stake.into(),
         }
     }
     /// Change owner
     pub fn change_owner(&mut self, new_owner: AccountId) {
-        assert_ne!(
-            self.appchain_owner,
-            new_owner.clone(),
-            "The owner is not changed."
+        ensure!(
+            !self.appchain_owner.eq(&new_owner),
+            RegistryError::FieldNotChanged {
+                field: "appchain_owner".to_string()
+            }
         );
         self.appchain_owner = new_owner;
     }
@@ -148,12 +188,64 @@ impl AppchainBasedata {
         if self.appchain_state.eq(&AppchainState::Booting) && new_state.eq(&AppchainState::Active) {
             self.go_live_time = env::block_timestamp();
         }
+        if !self.appchain_state.eq(&AppchainState::Booting) && new_state.eq(&AppchainState::Booting)
+        {
+            self.booting_time = env::block_timestamp();
+        }
         self.appchain_state = new_state;
     }
-    /// Sync staking status
+    /// Sync staking status, record the time of this sync and clear the stale flag
     pub fn sync_staking_status(&mut self, validator_count: u32, total_stake: Balance) {
         self.validator_count = validator_count;
         self.total_stake = total_stake;
+        self.last_sync_time = env::block_timestamp();
+        self.health = AppchainHealth::Healthy;
+    }
+    /// Sync the metrics of appchain.
+    ///
+    /// The era number, the finalized block height and the total rewards distributed
+    /// can NOT move backward. The wrapped token supply can decrease when the tokens are burnt.
+    pub fn sync_metrics(&mut self, metrics: AppchainSyncMetrics) {
+        ensure!(
+            !metrics.anchor_version.trim().is_empty()
+                && metrics.anchor_version.len() <= MAX_ANCHOR_VERSION_LENGTH,
+            RegistryError::InvalidArgument {
+                name: "anchor_version".to_string(),
+                reason: format!(
+                    "It should NOT be blank, and the length should NOT be greater than {}.",
+                    MAX_ANCHOR_VERSION_LENGTH
+                ),
+            }
+        );
+        if let Some(last_metrics) = &self.sync_metrics {
+            for (metric, last_value, value) in [
+                (
+                    "era_number",
+                    u128::from(last_metrics.era_number.0),
+                    u128::from(metrics.era_number.0),
+                ),
+                (
+                    "finalized_block_height",
+                    u128::from(last_metrics.finalized_block_height.0),
+                    u128::from(metrics.finalized_block_height.0),
+                ),
+                (
+                    "total_rewards_distributed",
+                    last_metrics.total_rewards_distributed.0,
+                    metrics.total_rewards_distributed.0,
+                ),
+            ] {
+                ensure!(
+                    value >= last_value,
+                    RegistryError::SyncMetricMovedBackward {
+                        metric: metric.to_string(),
+                        last_value: U128::from(last_value),
+                        value: U128::from(value),
+                    }
+                );
+            }
+        }
+        self.sync_metrics = Some(metrics);
     }
     /// Increase upvote deposit
     pub fn increase_upvote_deposit(&mut self, value: Balance) {
@@ -161,10 +253,11 @@ impl AppchainBasedata {
     }
     /// Decrease upvote deposit
     pub fn decrease_upvote_deposit(&mut self, value: Balance) {
-        self.upvote_deposit = self
-            .upvote_deposit
-            .checked_sub(value)
-            .expect("Upvote deposit is not big enough to decrease.");
+        self.upvote_deposit = self.upvote_deposit.checked_sub(value).unwrap_or_else(|| {
+            panic_with(RegistryError::NotEnoughUpvoteDeposit {
+                deposit: U128::from(self.upvote_deposit),
+            })
+        });
     }
     /// Increase upvote deposit
     pub fn increase_downvote_deposit(&mut self, value: Balance) {
@@ -172,10 +265,11 @@ impl AppchainBasedata {
     }
     /// Decrease upvote deposit
     pub fn decrease_downvote_deposit(&mut self, value: Balance) {
-        self.downvote_deposit = self
-            .downvote_deposit
-            .checked_sub(value)
-            .expect("Downvote deposit is not big enough to decrease.");
+        self.downvote_deposit = self.downvote_deposit.checked_sub(value).unwrap_or_else(|| {
+            panic_with(RegistryError::NotEnoughDownvoteDeposit {
+                deposit: U128::from(self.downvote_deposit),
+            })
+        });
     }
     /// Count voting score
     pub fn count_voting_score(&self) {
@@ -188,7 +282,13 @@ impl AppchainBasedata {
     }
     /// Reduce voting score by the given percent
     pub fn reduce_voting_score_by_percent(&self, percent: u16) {
-        assert!(percent <= 100, "Invalid percent value.");
+        ensure!(
+            percent <= 100,
+            RegistryError::InvalidArgument {
+                name: "percent".to_string(),
+                reason: "The percent should not be greater than 100.".to_string(),
+            }
+        );
         let mut voting_score = self.voting_score();
         voting_score -= voting_score * percent as i128 / 100;
         env::storage_write(
@@ -0,0 +1,31 @@
+use crate::*;
+
+impl AppchainRegistry {
+    /// Stamp a change of an appchain with the next global sequence number.
+    ///
+    /// Only the latest change of each appchain is kept in the change feed,
+    /// so the previous change of the appchain (if any) is removed from it.
+    pub fn internal_record_appchain_change(
+        &mut self,
+        appchain_id: &AppchainId,
+        kind: AppchainChangeKind,
+    ) {
+        self.latest_appchain_change_sequence += 1;
+        let sequence = self.latest_appchain_change_sequence;
+        if let Some(previous_sequence) = self
+            .appchain_change_sequences
+            .insert(appchain_id, &sequence)
+        {
+            self.appchain_changes.remove(&previous_sequence);
+        }
+        self.appchain_changes.insert(
+            &sequence,
+            &AppchainChange {
+                sequence: U64::from(sequence),
+                appchain_id: appchain_id.clone(),
+                kind,
+                timestamp: U64::from(env::block_timestamp()),
+            },
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::*;
+use types::AppchainFilter;
+
+impl AppchainFilter {
+    /// Assert the conditions of the filter are valid.
+    pub fn assert_valid(&self) {
+        if let (Some(from), Some(to)) = (self.registered_time_from, self.registered_time_to) {
+            ensure!(
+                from.0 < to.0,
+                RegistryError::InvalidArgument {
+                    name: "filter".to_string(),
+                    reason: "The 'registered_time_from' should be less than 'registered_time_to'."
+                        .to_string(),
+                }
+            );
+        }
+    }
+    /// Whether the given appchain meets all of the conditions of the filter.
+    pub fn is_matched_by(&self, appchain_basedata: &AppchainBasedata) -> bool {
+        if let Some(owner) = &self.owner {
+            if !appchain_basedata.appchain_owner.eq(owner) {
+                return false;
+            }
+        }
+        if let Some(has_evm_chain_id) = self.has_evm_chain_id {
+            if appchain_basedata.evm_chain_id.is_some() != has_evm_chain_id {
+                return false;
+            }
+        }
+        if let Some(from) = self.registered_time_from {
+            if appchain_basedata.registered_time < from.0 {
+                return false;
+            }
+        }
+        if let Some(to) = self.registered_time_to {
+            if appchain_basedata.registered_time >= to.0 {
+                return false;
+            }
+        }
+        if let Some(tags) = &self.tags {
+            let appchain_tags = appchain_basedata.tags();
+            if !tags.iter().all(|tag| appchain_tags.contains(tag)) {
+                return false;
+            }
+        }
+        if self.template_types.is_none() && self.custom_metadata.is_none() {
+            return true;
+        }
+        // The metadata is only read from storage if it is needed.
+        let metadata = appchain_basedata.metadata();
+        self.template_types.as_ref().map_or(true, |template_types| {
+            template_types.contains(&metadata.template_type)
+        }) && self.custom_metadata.as_ref().map_or(true, |entries| {
+            entries
+                .iter()
+                .all(|(key, value)| metadata.custom_metadata.get(key) == Some(value))
+        })
+    }
+}
@@ -0,0 +1,206 @@
+use crate::*;
+use std::fmt::Display;
+use types::{AppchainIdAvailability, ReservedAppchainId};
+
+/// The min length of appchain id
+const MIN_APPCHAIN_ID_LENGTH: usize = 3;
+/// The max length of appchain id
+const MAX_APPCHAIN_ID_LENGTH: usize = 20;
+/// The wildcard of deny patterns, which matches any number of characters
+const DENY_PATTERN_WILDCARD: char = '*';
+
+/// Get the normalized form of an appchain id (or a deny pattern), which is used for
+/// matching reserved ids and deny patterns.
+///
+/// The letters are converted to lowercase and the separators ('-' and '_') are removed,
+/// so that `Octo-Pus` and `octo_pus` are both normalized to `octopus`.
+pub fn normalize_appchain_id(appchain_id: &str) -> String {
+    appchain_id
+        .chars()
+        .filter(|c| !is_separator(*c))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+// Check the naming rules of appchain id, and return the reason if it is invalid.
+fn check_appchain_id_format(appchain_id: &AppchainId) -> Result<(), String> {
+    if appchain_id.trim().is_empty() {
+        return Err("Missing necessary field 'appchain_id'.".to_string());
+    }
+    if appchain_id.len() < MIN_APPCHAIN_ID_LENGTH || appchain_id.len() > MAX_APPCHAIN_ID_LENGTH {
+        return Err(format!(
+            "The length of appchain id should be in range [{}, {}].",
+            MIN_APPCHAIN_ID_LENGTH, MAX_APPCHAIN_ID_LENGTH
+        ));
+    }
+    if appchain_id.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err("Uppercase letters are not allowed in appchain id.".to_string());
+    }
+    if !appchain_id
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || is_separator(c))
+    {
+        return Err(
+            "Only lowercase letters, digits, '-' and '_' are allowed in appchain id.".to_string(),
+        );
+    }
+    if !appchain_id.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return Err("Appchain id should start with a lowercase letter.".to_string());
+    }
+    if appchain_id.ends_with(is_separator) {
+        return Err("Appchain id should NOT end with '-' or '_'.".to_string());
+    }
+    if appchain_id
+        .chars()
+        .zip(appchain_id.chars().skip(1))
+        .any(|(a, b)| is_separator(a) && is_separator(b))
+    {
+        return Err("Appchain id should NOT contain consecutive '-' or '_'.".to_string());
+    }
+    if AccountId::try_from(format!("{}.{}", appchain_id, env::current_account_id())).is_err() {
+        return Err("Invalid 'appchain_id'.".to_string());
+    }
+    Ok(())
+}
+
+/// Assert that the given deny pattern is valid, and return its normalized form.
+pub fn normalize_deny_pattern(pattern: &str) -> String {
+    let normalized_pattern = normalize_appchain_id(pattern);
+    ensure!(
+        normalized_pattern
+            .chars()
+            .any(|c| c != DENY_PATTERN_WILDCARD),
+        RegistryError::InvalidArgument {
+            name: "pattern".to_string(),
+            reason: format!(
+                "The deny pattern should contain at least one character other than '{}'.",
+                DENY_PATTERN_WILDCARD
+            ),
+        }
+    );
+    ensure!(
+        normalized_pattern
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == DENY_PATTERN_WILDCARD),
+        RegistryError::InvalidArgument {
+            name: "pattern".to_string(),
+            reason: format!(
+                "Only letters, digits, separators and '{}' are allowed in deny pattern.",
+                DENY_PATTERN_WILDCARD
+            ),
+        }
+    );
+    normalized_pattern
+}
+
+// Whether the given text matches the given pattern, in which `*` matches
+// any number of characters.
+fn matches_deny_pattern(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut last_wildcard: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == DENY_PATTERN_WILDCARD {
+            last_wildcard = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((wildcard_p, wildcard_t)) = last_wildcard {
+            p = wildcard_p + 1;
+            t = wildcard_t + 1;
+            last_wildcard = Some((wildcard_p, wildcard_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == DENY_PATTERN_WILDCARD)
+}
+
+fn is_separator(c: char) -> bool {
+    c == '-' || c == '_'
+}
+
+impl AppchainRegistry {
+    /// Check whether the given appchain id can be registered by the given account.
+    ///
+    /// If `account_id` is `None`, the ids assigned to certain accounts are regarded as reserved.
+    pub fn internal_check_appchain_id_availability(
+        &self,
+        appchain_id: &AppchainId,
+        account_id: Option<&AccountId>,
+    ) -> AppchainIdAvailability {
+        if let Err(reason) = check_appchain_id_format(appchain_id) {
+            return AppchainIdAvailability::Invalid { reason };
+        }
+        let normalized_id = normalize_appchain_id(appchain_id);
+        if self.normalized_appchain_ids.contains_key(&normalized_id) {
+            return AppchainIdAvailability::Registered;
+        }
+        if let Some(reserved_appchain_id) = self.reserved_appchain_ids.get(&normalized_id) {
+            let assigned_to_account = account_id.map_or(false, |account_id| {
+                reserved_appchain_id.assigned_to.as_ref() == Some(account_id)
+            });
+            if assigned_to_account {
+                return AppchainIdAvailability::Available;
+            }
+            return AppchainIdAvailability::Reserved {
+                reserved_id: reserved_appchain_id.appchain_id,
+                assigned_to: reserved_appchain_id.assigned_to,
+            };
+        }
+        let registry_settings = self.registry_settings.get().unwrap();
+        for pattern in registry_settings.appchain_id_deny_patterns {
+            if matches_deny_pattern(&pattern, &normalized_id) {
+                return AppchainIdAvailability::Denied { pattern };
+            }
+        }
+        AppchainIdAvailability::Available
+    }
+    //
+    pub fn internal_reserve_appchain_id(
+        &mut self,
+        appchain_id: &AppchainId,
+        assigned_to: Option<AccountId>,
+    ) {
+        let normalized_id = normalize_appchain_id(appchain_id);
+        ensure!(
+            !normalized_id.is_empty(),
+            RegistryError::MissingField {
+                field: "appchain_id".to_string()
+            }
+        );
+        self.reserved_appchain_ids.insert(
+            &normalized_id,
+            &ReservedAppchainId {
+                appchain_id: appchain_id.clone(),
+                assigned_to,
+            },
+        );
+    }
+}
+
+impl Display for AppchainIdAvailability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppchainIdAvailability::Available => write!(f, "Appchain id is available."),
+            AppchainIdAvailability::Invalid { reason } => write!(f, "{}", reason),
+            AppchainIdAvailability::Registered => write!(f, "Appchain already registered."),
+            AppchainIdAvailability::Reserved {
+                reserved_id,
+                assigned_to,
+            } => match assigned_to {
+                Some(account_id) => write!(
+                    f,
+                    "Appchain id is reserved as '{}' for '{}'.",
+                    reserved_id, account_id
+                ),
+                None => write!(f, "Appchain id is reserved as '{}'.", reserved_id),
+            },
+            AppchainIdAvailability::Denied { pattern } => {
+                write!(f, "Appchain id matches deny pattern '{}'.", pattern)
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::*;
+use types::AppchainLiveness;
+
+impl AppchainBasedata {
+    /// Get the time since which the anchor should sync, which is the later one of
+    /// the latest sync and the start of booting
+    pub fn last_alive_time(&self) -> Timestamp {
+        self.last_sync_time.max(self.booting_time)
+    }
+    /// Get the liveness of the anchor
+    pub fn liveness(&self) -> AppchainLiveness {
+        AppchainLiveness {
+            appchain_id: self.appchain_id.clone(),
+            appchain_state: self.appchain_state.clone(),
+            appchain_anchor: self.appchain_anchor.clone(),
+            last_alive_time: self.last_alive_time().into(),
+            health: self.health.clone(),
+        }
+    }
+}
+
+impl AppchainRegistry {
+    /// Get whether the given appchain is booting or active, and its anchor has not synced
+    /// within `anchor staleness threshold` of registry settings.
+    pub fn appchain_anchor_is_stale(&self, appchain_basedata: &AppchainBasedata) -> bool {
+        let registry_settings = self.registry_settings.get().unwrap();
+        (appchain_basedata.state().eq(&AppchainState::Booting)
+            || appchain_basedata.state().eq(&AppchainState::Active))
+            && env::block_timestamp()
+                > appchain_basedata.last_alive_time()
+                    + registry_settings.anchor_staleness_threshold_in_secs * NANO_SECONDS_MULTIPLE
+    }
+    /// Get the ids of the appchains whose anchors are stale.
+    pub fn get_stale_appchain_ids(&self) -> Vec<AppchainId> {
+        self.appchain_ids
+            .iter()
+            .filter(|appchain_id| {
+                self.appchain_anchor_is_stale(&self.get_appchain_basedata(appchain_id))
+            })
+            .collect()
+    }
+}
+
+#[near_bindgen]
+impl AppchainRegistry {
+    /// Flag an appchain as stale, whose anchor has not synced within the staleness threshold.
+    /// The flag is cleared by the next sync of the anchor. Can be called by anyone.
+    pub fn flag_stale_appchain(&mut self, appchain_id: AppchainId) {
+        let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        ensure!(
+            self.appchain_anchor_is_stale(&appchain_basedata),
+            RegistryError::AppchainAnchorNotStale {
+                appchain_id: appchain_id.clone(),
+                last_alive_time: appchain_basedata.last_alive_time().into(),
+            }
+        );
+        ensure!(
+            !appchain_basedata.health.eq(&AppchainHealth::Stale),
+            RegistryError::AppchainAlreadyStale {
+                appchain_id: appchain_id.clone()
+            }
+        );
+        appchain_basedata.health = AppchainHealth::Stale;
+        self.appchain_basedatas
+            .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::HealthChanged);
+        log!(
+            "Appchain '{}' is flagged as stale by '{}'.",
+            appchain_id,
+            env::predecessor_account_id()
+        );
+    }
+}
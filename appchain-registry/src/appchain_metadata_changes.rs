@@ -0,0 +1,399 @@
+use crate::*;
+use std::ops::Bound;
+use types::{
+    AppchainMetadataChangeRequest, AppchainMetadataField, AppchainMetadataPatch,
+    AppchainMetadataSnapshot, FieldPatch,
+};
+
+impl AppchainMetadataField {
+    /// The fields which can be updated by appchain owners directly by default.
+    pub fn default_owner_updatable_fields() -> Vec<AppchainMetadataField> {
+        vec![
+            AppchainMetadataField::Description,
+            AppchainMetadataField::WebsiteUrl,
+            AppchainMetadataField::FunctionSpecUrl,
+            AppchainMetadataField::GithubAddress,
+            AppchainMetadataField::GithubRelease,
+            AppchainMetadataField::ContactEmail,
+            AppchainMetadataField::CustomMetadata,
+        ]
+    }
+    /// Whether the field is one of the token economics of the wrapped appchain token.
+    pub fn is_economic(&self) -> bool {
+        matches!(
+            self,
+            AppchainMetadataField::PreminedWrappedAppchainTokenBeneficiary
+                | AppchainMetadataField::PreminedWrappedAppchainToken
+                | AppchainMetadataField::InitialSupplyOfWrappedAppchainToken
+                | AppchainMetadataField::IdoAmountOfWrappedAppchainToken
+                | AppchainMetadataField::InitialEraReward
+                | AppchainMetadataField::FungibleTokenMetadata
+        )
+    }
+    /// Whether the field can be allowed (by registry settings) to be updated by
+    /// appchain owners directly.
+    ///
+    /// The economic fields and the fields decided by the registry (template type,
+    /// EVM chain id and DAO proposal url) can NOT.
+    pub fn can_be_updated_by_owner(&self) -> bool {
+        !self.is_economic()
+            && !matches!(
+                self,
+                AppchainMetadataField::TemplateType
+                    | AppchainMetadataField::EvmChainId
+                    | AppchainMetadataField::DaoProposalUrl
+            )
+    }
+    /// Get the name of the field, as the name of the field in appchain metadata.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AppchainMetadataField::Description => "description",
+            AppchainMetadataField::TemplateType => "template_type",
+            AppchainMetadataField::EvmChainId => "evm_chain_id",
+            AppchainMetadataField::DaoProposalUrl => "dao_proposal_url",
+            AppchainMetadataField::WebsiteUrl => "website_url",
+            AppchainMetadataField::FunctionSpecUrl => "function_spec_url",
+            AppchainMetadataField::GithubAddress => "github_address",
+            AppchainMetadataField::GithubRelease => "github_release",
+            AppchainMetadataField::ContactEmail => "contact_email",
+            AppchainMetadataField::PreminedWrappedAppchainTokenBeneficiary => {
+                "premined_wrapped_appchain_token_beneficiary"
+            }
+            AppchainMetadataField::PreminedWrappedAppchainToken => {
+                "premined_wrapped_appchain_token"
+            }
+            AppchainMetadataField::InitialSupplyOfWrappedAppchainToken => {
+                "initial_supply_of_wrapped_appchain_token"
+            }
+            AppchainMetadataField::IdoAmountOfWrappedAppchainToken => {
+                "ido_amount_of_wrapped_appchain_token"
+            }
+            AppchainMetadataField::InitialEraReward => "initial_era_reward",
+            AppchainMetadataField::FungibleTokenMetadata => "fungible_token_metadata",
+            AppchainMetadataField::CustomMetadata => "custom_metadata",
+        }
+    }
+}
+
+impl<T: Clone> FieldPatch<T> {
+    /// Whether the field is left unchanged by the patch.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, FieldPatch::Unchanged)
+    }
+    // Apply the patch to a field which can NOT be cleared.
+    fn apply_to_required(&self, field: AppchainMetadataField, value: &mut T) {
+        match self {
+            FieldPatch::Unchanged => (),
+            FieldPatch::Set(new_value) => *value = new_value.clone(),
+            FieldPatch::Clear => panic_with(RegistryError::InvalidField {
+                field: field.name().to_string(),
+                reason: "The field can NOT be cleared.".to_string(),
+            }),
+        }
+    }
+    // Apply the patch to an optional field.
+    fn apply_to_optional(&self, value: &mut Option<T>) {
+        match self {
+            FieldPatch::Unchanged => (),
+            FieldPatch::Set(new_value) => *value = Some(new_value.clone()),
+            FieldPatch::Clear => *value = None,
+        }
+    }
+}
+
+impl<T: Clone + Default> FieldPatch<T> {
+    // Apply the patch to a field which is cleared by setting it to the default (empty) value.
+    fn apply_to_clearable(&self, value: &mut T) {
+        match self {
+            FieldPatch::Unchanged => (),
+            FieldPatch::Set(new_value) => *value = new_value.clone(),
+            FieldPatch::Clear => *value = T::default(),
+        }
+    }
+}
+
+impl AppchainMetadataPatch {
+    /// Get the fields which are patched.
+    pub fn patched_fields(&self) -> Vec<AppchainMetadataField> {
+        let mut fields = Vec::new();
+        let mut add_if = |is_patched: bool, field: AppchainMetadataField| {
+            if is_patched {
+                fields.push(field);
+            }
+        };
+        add_if(
+            !self.description.is_unchanged(),
+            AppchainMetadataField::Description,
+        );
+        add_if(
+            !self.template_type.is_unchanged(),
+            AppchainMetadataField::TemplateType,
+        );
+        add_if(
+            !self.evm_chain_id.is_unchanged(),
+            AppchainMetadataField::EvmChainId,
+        );
+        add_if(
+            !self.dao_proposal_url.is_unchanged(),
+            AppchainMetadataField::DaoProposalUrl,
+        );
+        add_if(
+            !self.website_url.is_unchanged(),
+            AppchainMetadataField::WebsiteUrl,
+        );
+        add_if(
+            !self.function_spec_url.is_unchanged(),
+            AppchainMetadataField::FunctionSpecUrl,
+        );
+        add_if(
+            !self.github_address.is_unchanged(),
+            AppchainMetadataField::GithubAddress,
+        );
+        add_if(
+            !self.github_release.is_unchanged(),
+            AppchainMetadataField::GithubRelease,
+        );
+        add_if(
+            !self.contact_email.is_unchanged(),
+            AppchainMetadataField::ContactEmail,
+        );
+        add_if(
+            !self
+                .premined_wrapped_appchain_token_beneficiary
+                .is_unchanged(),
+            AppchainMetadataField::PreminedWrappedAppchainTokenBeneficiary,
+        );
+        add_if(
+            !self.premined_wrapped_appchain_token.is_unchanged(),
+            AppchainMetadataField::PreminedWrappedAppchainToken,
+        );
+        add_if(
+            !self.initial_supply_of_wrapped_appchain_token.is_unchanged(),
+            AppchainMetadataField::InitialSupplyOfWrappedAppchainToken,
+        );
+        add_if(
+            !self.ido_amount_of_wrapped_appchain_token.is_unchanged(),
+            AppchainMetadataField::IdoAmountOfWrappedAppchainToken,
+        );
+        add_if(
+            !self.initial_era_reward.is_unchanged(),
+            AppchainMetadataField::InitialEraReward,
+        );
+        add_if(
+            !self.fungible_token_metadata.is_unchanged(),
+            AppchainMetadataField::FungibleTokenMetadata,
+        );
+        add_if(
+            !self.custom_metadata.is_unchanged()
+                || self
+                    .custom_metadata_entries
+                    .values()
+                    .any(|patch| !patch.is_unchanged()),
+            AppchainMetadataField::CustomMetadata,
+        );
+        fields
+    }
+    /// Apply the patch to the given values of the updatable fields of an appchain.
+    ///
+    /// The result is NOT validated in this function.
+    pub fn apply_to(&self, snapshot: &mut AppchainMetadataSnapshot) {
+        let metadata = &mut snapshot.metadata;
+        self.description
+            .apply_to_clearable(&mut metadata.description);
+        self.template_type.apply_to_required(
+            AppchainMetadataField::TemplateType,
+            &mut metadata.template_type,
+        );
+        self.evm_chain_id
+            .apply_to_optional(&mut snapshot.evm_chain_id);
+        self.dao_proposal_url
+            .apply_to_optional(&mut snapshot.dao_proposal_url);
+        self.website_url
+            .apply_to_required(AppchainMetadataField::WebsiteUrl, &mut metadata.website_url);
+        self.function_spec_url
+            .apply_to_clearable(&mut metadata.function_spec_url);
+        self.github_address.apply_to_required(
+            AppchainMetadataField::GithubAddress,
+            &mut metadata.github_address,
+        );
+        self.github_release
+            .apply_to_clearable(&mut metadata.github_release);
+        self.contact_email.apply_to_required(
+            AppchainMetadataField::ContactEmail,
+            &mut metadata.contact_email,
+        );
+        self.premined_wrapped_appchain_token_beneficiary
+            .apply_to_optional(&mut metadata.premined_wrapped_appchain_token_beneficiary);
+        self.premined_wrapped_appchain_token.apply_to_required(
+            AppchainMetadataField::PreminedWrappedAppchainToken,
+            &mut metadata.premined_wrapped_appchain_token,
+        );
+        self.initial_supply_of_wrapped_appchain_token
+            .apply_to_required(
+                AppchainMetadataField::InitialSupplyOfWrappedAppchainToken,
+                &mut metadata.initial_supply_of_wrapped_appchain_token,
+            );
+        self.ido_amount_of_wrapped_appchain_token.apply_to_required(
+            AppchainMetadataField::IdoAmountOfWrappedAppchainToken,
+            &mut metadata.ido_amount_of_wrapped_appchain_token,
+        );
+        self.initial_era_reward.apply_to_required(
+            AppchainMetadataField::InitialEraReward,
+            &mut metadata.initial_era_reward,
+        );
+        self.fungible_token_metadata.apply_to_required(
+            AppchainMetadataField::FungibleTokenMetadata,
+            &mut metadata.fungible_token_metadata,
+        );
+        self.custom_metadata
+            .apply_to_clearable(&mut metadata.custom_metadata);
+        for (key, patch) in self.custom_metadata_entries.iter() {
+            match patch {
+                FieldPatch::Unchanged => (),
+                FieldPatch::Set(value) => {
+                    metadata.custom_metadata.insert(key.clone(), value.clone());
+                }
+                FieldPatch::Clear => {
+                    metadata.custom_metadata.remove(key);
+                }
+            }
+        }
+    }
+}
+
+impl AppchainRegistry {
+    /// Apply the patch to the given values of the updatable fields of an appchain,
+    /// and return the validated result.
+    ///
+    /// The result is validated in the same way as the registration of appchains.
+    pub fn internal_apply_metadata_patch(
+        &self,
+        previous_snapshot: &AppchainMetadataSnapshot,
+        patch: &AppchainMetadataPatch,
+    ) -> AppchainMetadataSnapshot {
+        ensure!(
+            !patch.patched_fields().is_empty(),
+            RegistryError::EmptyAppchainMetadataPatch
+        );
+        let mut snapshot = previous_snapshot.clone();
+        patch.apply_to(&mut snapshot);
+        let template = self.get_appchain_template(&snapshot.metadata.template_type);
+        ensure!(
+            template.is_active
+                || snapshot
+                    .metadata
+                    .template_type
+                    .eq(&previous_snapshot.metadata.template_type),
+            RegistryError::AppchainTemplateNotActive {
+                template_type: template.name.clone()
+            }
+        );
+        self.assert_evm_chain_id_matches_template(&template, &snapshot.evm_chain_id);
+        if let Some(evm_chain_id) = snapshot.evm_chain_id {
+            if !previous_snapshot.evm_chain_id.eq(&Some(evm_chain_id)) {
+                self.assert_evm_chain_id_is_available(&evm_chain_id);
+            }
+        }
+        snapshot.metadata.assert_valid();
+        snapshot
+    }
+    /// Apply the patch to the metadata (and basedata) of an appchain, record a revision
+    /// of the changes, and return the updated appchain basedata.
+    ///
+    /// The storage used by the changes is NOT charged in this function.
+    pub fn internal_update_appchain_metadata(
+        &mut self,
+        appchain_id: &AppchainId,
+        patch: AppchainMetadataPatch,
+        change_request_id: Option<u64>,
+    ) -> AppchainBasedata {
+        let mut appchain_basedata = self.get_appchain_basedata(appchain_id);
+        let previous_snapshot = appchain_basedata.metadata_snapshot();
+        let snapshot = self.internal_apply_metadata_patch(&previous_snapshot, &patch);
+        if !previous_snapshot.evm_chain_id.eq(&snapshot.evm_chain_id) {
+            if let Some(evm_chain_id) = previous_snapshot.evm_chain_id {
+                self.evm_chain_ids.remove(&evm_chain_id.0);
+            }
+            if let Some(evm_chain_id) = snapshot.evm_chain_id {
+                self.evm_chain_ids.insert(&evm_chain_id.0, appchain_id);
+            }
+        }
+        appchain_basedata.evm_chain_id = snapshot.evm_chain_id;
+        appchain_basedata.dao_proposal_url = snapshot.dao_proposal_url.clone();
+        appchain_basedata.set_metadata(snapshot.metadata.clone());
+        self.appchain_basedatas
+            .insert(appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(appchain_id, AppchainChangeKind::MetadataUpdated);
+        self.internal_record_metadata_revision(
+            appchain_id,
+            &previous_snapshot,
+            &snapshot,
+            change_request_id,
+        );
+        appchain_basedata
+    }
+    /// Store a change request of the metadata of an appchain, and return the request id.
+    pub fn internal_add_metadata_change_request(
+        &mut self,
+        appchain_id: &AppchainId,
+        patch: AppchainMetadataPatch,
+    ) -> u64 {
+        // The patch is checked against the current metadata when it is submitted, and checked
+        // again when it is approved, as the metadata may be changed in between.
+        let appchain_basedata = self.get_appchain_basedata(appchain_id);
+        self.internal_apply_metadata_patch(&appchain_basedata.metadata_snapshot(), &patch);
+        self.latest_metadata_change_request_id += 1;
+        let request_id = self.latest_metadata_change_request_id;
+        self.appchain_metadata_change_requests.insert(
+            &request_id,
+            &AppchainMetadataChangeRequest {
+                request_id: U64::from(request_id),
+                appchain_id: appchain_id.clone(),
+                requested_by: env::predecessor_account_id(),
+                patch,
+                requested_timestamp: U64::from(env::block_timestamp()),
+            },
+        );
+        self.appchain_metadata_change_request_index
+            .insert(&(appchain_id.clone(), request_id), &());
+        request_id
+    }
+    /// Get a pending change request of appchain metadata.
+    pub fn get_metadata_change_request(&self, request_id: u64) -> AppchainMetadataChangeRequest {
+        self.appchain_metadata_change_requests
+            .get(&request_id)
+            .unwrap_or_else(|| {
+                panic_with(RegistryError::MetadataChangeRequestNotFound {
+                    request_id: U64::from(request_id),
+                })
+            })
+    }
+    /// Remove a pending change request of appchain metadata.
+    pub fn internal_remove_metadata_change_request(
+        &mut self,
+        request: &AppchainMetadataChangeRequest,
+    ) {
+        self.appchain_metadata_change_requests
+            .remove(&request.request_id.0);
+        self.appchain_metadata_change_request_index
+            .remove(&(request.appchain_id.clone(), request.request_id.0));
+    }
+    /// Get the ids of the pending metadata change requests of an appchain, in ascending order.
+    pub fn get_metadata_change_request_ids_of(&self, appchain_id: &AppchainId) -> Vec<u64> {
+        self.appchain_metadata_change_request_index
+            .range((
+                Bound::Included((appchain_id.clone(), 0)),
+                Bound::Included((appchain_id.clone(), u64::MAX)),
+            ))
+            .map(|((_, request_id), _)| request_id)
+            .collect()
+    }
+    /// Remove all pending metadata change requests of an appchain.
+    pub fn internal_remove_metadata_change_requests_of(&mut self, appchain_id: &AppchainId) {
+        for request_id in self.get_metadata_change_request_ids_of(appchain_id) {
+            self.appchain_metadata_change_requests.remove(&request_id);
+            self.appchain_metadata_change_request_index
+                .remove(&(appchain_id.clone(), request_id));
+        }
+    }
+}
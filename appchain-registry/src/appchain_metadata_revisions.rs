@@ -0,0 +1,222 @@
+use crate::*;
+use near_sdk::serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use types::{
+    AppchainMetadataField, AppchainMetadataFieldChange, AppchainMetadataRevision,
+    AppchainMetadataSnapshot,
+};
+
+impl AppchainMetadataField {
+    /// Get all of the fields.
+    pub fn all() -> Vec<AppchainMetadataField> {
+        vec![
+            AppchainMetadataField::Description,
+            AppchainMetadataField::TemplateType,
+            AppchainMetadataField::EvmChainId,
+            AppchainMetadataField::DaoProposalUrl,
+            AppchainMetadataField::WebsiteUrl,
+            AppchainMetadataField::FunctionSpecUrl,
+            AppchainMetadataField::GithubAddress,
+            AppchainMetadataField::GithubRelease,
+            AppchainMetadataField::ContactEmail,
+            AppchainMetadataField::PreminedWrappedAppchainTokenBeneficiary,
+            AppchainMetadataField::PreminedWrappedAppchainToken,
+            AppchainMetadataField::InitialSupplyOfWrappedAppchainToken,
+            AppchainMetadataField::IdoAmountOfWrappedAppchainToken,
+            AppchainMetadataField::InitialEraReward,
+            AppchainMetadataField::FungibleTokenMetadata,
+            AppchainMetadataField::CustomMetadata,
+        ]
+    }
+}
+
+impl AppchainMetadataSnapshot {
+    /// Get the value (in JSON) of the given field.
+    pub fn value_of(&self, field: &AppchainMetadataField) -> String {
+        match field {
+            AppchainMetadataField::Description => to_json(&self.metadata.description),
+            AppchainMetadataField::TemplateType => to_json(&self.metadata.template_type),
+            AppchainMetadataField::EvmChainId => to_json(&self.evm_chain_id),
+            AppchainMetadataField::DaoProposalUrl => to_json(&self.dao_proposal_url),
+            AppchainMetadataField::WebsiteUrl => to_json(&self.metadata.website_url),
+            AppchainMetadataField::FunctionSpecUrl => to_json(&self.metadata.function_spec_url),
+            AppchainMetadataField::GithubAddress => to_json(&self.metadata.github_address),
+            AppchainMetadataField::GithubRelease => to_json(&self.metadata.github_release),
+            AppchainMetadataField::ContactEmail => to_json(&self.metadata.contact_email),
+            AppchainMetadataField::PreminedWrappedAppchainTokenBeneficiary => {
+                to_json(&self.metadata.premined_wrapped_appchain_token_beneficiary)
+            }
+            AppchainMetadataField::PreminedWrappedAppchainToken => {
+                to_json(&self.metadata.premined_wrapped_appchain_token)
+            }
+            AppchainMetadataField::InitialSupplyOfWrappedAppchainToken => {
+                to_json(&self.metadata.initial_supply_of_wrapped_appchain_token)
+            }
+            AppchainMetadataField::IdoAmountOfWrappedAppchainToken => {
+                to_json(&self.metadata.ido_amount_of_wrapped_appchain_token)
+            }
+            AppchainMetadataField::InitialEraReward => to_json(&self.metadata.initial_era_reward),
+            AppchainMetadataField::FungibleTokenMetadata => {
+                to_json(&self.metadata.fungible_token_metadata)
+            }
+            AppchainMetadataField::CustomMetadata => to_json(
+                &self
+                    .metadata
+                    .custom_metadata
+                    .iter()
+                    .collect::<BTreeMap<&String, &String>>(),
+            ),
+        }
+    }
+    /// Set the value (in JSON) of the given field.
+    pub fn set_value_of(&mut self, field: &AppchainMetadataField, value: &str) {
+        match field {
+            AppchainMetadataField::Description => self.metadata.description = from_json(value),
+            AppchainMetadataField::TemplateType => self.metadata.template_type = from_json(value),
+            AppchainMetadataField::EvmChainId => self.evm_chain_id = from_json(value),
+            AppchainMetadataField::DaoProposalUrl => self.dao_proposal_url = from_json(value),
+            AppchainMetadataField::WebsiteUrl => self.metadata.website_url = from_json(value),
+            AppchainMetadataField::FunctionSpecUrl => {
+                self.metadata.function_spec_url = from_json(value)
+            }
+            AppchainMetadataField::GithubAddress => self.metadata.github_address = from_json(value),
+            AppchainMetadataField::GithubRelease => self.metadata.github_release = from_json(value),
+            AppchainMetadataField::ContactEmail => self.metadata.contact_email = from_json(value),
+            AppchainMetadataField::PreminedWrappedAppchainTokenBeneficiary => {
+                self.metadata.premined_wrapped_appchain_token_beneficiary = from_json(value)
+            }
+            AppchainMetadataField::PreminedWrappedAppchainToken => {
+                self.metadata.premined_wrapped_appchain_token = from_json(value)
+            }
+            AppchainMetadataField::InitialSupplyOfWrappedAppchainToken => {
+                self.metadata.initial_supply_of_wrapped_appchain_token = from_json(value)
+            }
+            AppchainMetadataField::IdoAmountOfWrappedAppchainToken => {
+                self.metadata.ido_amount_of_wrapped_appchain_token = from_json(value)
+            }
+            AppchainMetadataField::InitialEraReward => {
+                self.metadata.initial_era_reward = from_json(value)
+            }
+            AppchainMetadataField::FungibleTokenMetadata => {
+                self.metadata.fungible_token_metadata = from_json(value)
+            }
+            AppchainMetadataField::CustomMetadata => {
+                self.metadata.custom_metadata = from_json(value)
+            }
+        }
+    }
+    /// Get the changes of the fields from this snapshot to the given snapshot.
+    pub fn changes_to(&self, other: &AppchainMetadataSnapshot) -> Vec<AppchainMetadataFieldChange> {
+        AppchainMetadataField::all()
+            .into_iter()
+            .filter_map(|field| {
+                let old_value = self.value_of(&field);
+                let new_value = other.value_of(&field);
+                match old_value.eq(&new_value) {
+                    true => None,
+                    false => Some(AppchainMetadataFieldChange {
+                        field,
+                        old_value,
+                        new_value,
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+fn from_json<T: DeserializeOwned>(value: &str) -> T {
+    serde_json::from_str(value).unwrap_or_else(|_| {
+        panic_with(RegistryError::InvalidStorageData {
+            key: "appchain_metadata_revisions".to_string(),
+        })
+    })
+}
+
+impl AppchainRegistry {
+    /// Get the count of metadata revisions of an appchain.
+    pub fn get_metadata_revision_count(&self, appchain_id: &AppchainId) -> u64 {
+        self.appchain_metadata_revision_counts
+            .get(appchain_id)
+            .unwrap_or(0)
+    }
+    /// Record a revision of the metadata of an appchain with the changes between
+    /// the given snapshots. Nothing is recorded if there is no change.
+    pub fn internal_record_metadata_revision(
+        &mut self,
+        appchain_id: &AppchainId,
+        previous_snapshot: &AppchainMetadataSnapshot,
+        current_snapshot: &AppchainMetadataSnapshot,
+        change_request_id: Option<u64>,
+    ) {
+        let changes = previous_snapshot.changes_to(current_snapshot);
+        if changes.is_empty() {
+            return;
+        }
+        let revision = self.get_metadata_revision_count(appchain_id) + 1;
+        self.appchain_metadata_revisions.insert(
+            &(appchain_id.clone(), revision),
+            &AppchainMetadataRevision {
+                appchain_id: appchain_id.clone(),
+                revision: U64::from(revision),
+                changes,
+                actor: env::predecessor_account_id(),
+                change_request_id: change_request_id.map(U64::from),
+                block_height: U64::from(env::block_height()),
+                timestamp: U64::from(env::block_timestamp()),
+            },
+        );
+        self.appchain_metadata_revision_counts
+            .insert(appchain_id, &revision);
+    }
+    /// Get the values of the updatable fields of an appchain as they were at the given revision.
+    ///
+    /// The initial revision (`0` in general) is the appchain as it was registered (or as it
+    /// was migrated, for the appchains registered before the revisions are recorded).
+    pub fn internal_get_metadata_snapshot_at(
+        &self,
+        appchain_id: &AppchainId,
+        revision: u64,
+    ) -> AppchainMetadataSnapshot {
+        let revision_count = self.get_metadata_revision_count(appchain_id);
+        ensure!(
+            revision >= self.get_initial_metadata_revision(appchain_id)
+                && revision <= revision_count,
+            RegistryError::AppchainMetadataRevisionNotFound {
+                appchain_id: appchain_id.clone(),
+                revision: U64::from(revision),
+            }
+        );
+        let mut snapshot = self.get_appchain_basedata(appchain_id).metadata_snapshot();
+        for number in ((revision + 1)..=revision_count).rev() {
+            let metadata_revision = self
+                .appchain_metadata_revisions
+                .get(&(appchain_id.clone(), number))
+                .unwrap();
+            for change in metadata_revision.changes.iter().rev() {
+                snapshot.set_value_of(&change.field, &change.old_value);
+            }
+        }
+        snapshot
+    }
+    /// Get the count of metadata revisions recorded before the registration of an appchain,
+    /// which are the revisions of the removed appchains with the same id.
+    pub fn get_initial_metadata_revision(&self, appchain_id: &AppchainId) -> u64 {
+        self.appchain_metadata_initial_revisions
+            .get(appchain_id)
+            .unwrap_or(0)
+    }
+    /// Start the metadata revisions of a newly registered appchain after the revisions
+    /// of the removed appchains with the same id, which are kept as history.
+    pub fn internal_start_metadata_revisions_of(&mut self, appchain_id: &AppchainId) {
+        let revision_count = self.get_metadata_revision_count(appchain_id);
+        if revision_count > 0 {
+            self.appchain_metadata_initial_revisions
+                .insert(appchain_id, &revision_count);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::*;
+use types::AppchainMetadata;
+
+/// The max length of the description of an appchain
+const MAX_DESCRIPTION_LENGTH: usize = 1000;
+/// The max length of urls in appchain metadata
+const MAX_URL_LENGTH: usize = 256;
+/// The max length of contact email
+const MAX_EMAIL_LENGTH: usize = 128;
+/// The max length of github release
+const MAX_GITHUB_RELEASE_LENGTH: usize = 64;
+/// The max count of entries in custom metadata
+const MAX_CUSTOM_METADATA_ENTRIES: usize = 20;
+/// The max length of keys in custom metadata
+const MAX_CUSTOM_METADATA_KEY_LENGTH: usize = 64;
+/// The max length of values in custom metadata
+const MAX_CUSTOM_METADATA_VALUE_LENGTH: usize = 512;
+/// The prefix of github repository address
+const GITHUB_ADDRESS_PREFIX: &str = "https://github.com/";
+/// The max length of github user or organization name
+const MAX_GITHUB_OWNER_LENGTH: usize = 39;
+/// The max length of github repository name
+const MAX_GITHUB_REPOSITORY_LENGTH: usize = 100;
+
+impl AppchainMetadata {
+    /// Assert that all fields of the metadata are valid.
+    ///
+    /// This is applied to the metadata of a newly registered appchain and to the result of
+    /// every metadata update, so that an update can NOT produce a record which registration
+    /// would reject.
+    pub fn assert_valid(&self) {
+        assert_length_in_range("description", &self.description, MAX_DESCRIPTION_LENGTH);
+        assert_not_blank("website_url", &self.website_url);
+        assert_valid_url("website_url", &self.website_url);
+        if !self.function_spec_url.is_empty() {
+            assert_valid_url("function_spec_url", &self.function_spec_url);
+        }
+        assert_not_blank("github_address", &self.github_address);
+        if let Err(reason) = check_github_address(&self.github_address) {
+            panic_with(invalid_field("github_address", reason));
+        }
+        assert_length_in_range(
+            "github_release",
+            &self.github_release,
+            MAX_GITHUB_RELEASE_LENGTH,
+        );
+        assert_not_blank("contact_email", &self.contact_email);
+        if let Err(reason) = check_email(&self.contact_email) {
+            panic_with(invalid_field("contact_email", reason));
+        }
+        self.fungible_token_metadata.assert_valid();
+        assert_not_blank(
+            "fungible_token_metadata.name",
+            &self.fungible_token_metadata.name,
+        );
+        assert_not_blank(
+            "fungible_token_metadata.symbol",
+            &self.fungible_token_metadata.symbol,
+        );
+        ensure!(
+            self.premined_wrapped_appchain_token
+                .0
+                .checked_add(self.ido_amount_of_wrapped_appchain_token.0)
+                .map_or(false, |amount| amount
+                    <= self.initial_supply_of_wrapped_appchain_token.0),
+            RegistryError::InitialSupplyNotEnough {
+                initial_supply: self.initial_supply_of_wrapped_appchain_token,
+                premined_amount: self.premined_wrapped_appchain_token,
+                ido_amount: self.ido_amount_of_wrapped_appchain_token,
+            }
+        );
+        ensure!(
+            self.custom_metadata.len() <= MAX_CUSTOM_METADATA_ENTRIES,
+            invalid_field(
+                "custom_metadata",
+                format!(
+                    "The custom metadata should NOT have more than {} entries.",
+                    MAX_CUSTOM_METADATA_ENTRIES
+                )
+            )
+        );
+        for (key, value) in self.custom_metadata.iter() {
+            assert_not_blank("custom_metadata", key);
+            assert_length_in_range("custom_metadata", key, MAX_CUSTOM_METADATA_KEY_LENGTH);
+            assert_length_in_range(
+                &format!("custom_metadata.{}", key),
+                value,
+                MAX_CUSTOM_METADATA_VALUE_LENGTH,
+            );
+        }
+    }
+}
+
+pub(crate) fn invalid_field(field: &str, reason: String) -> RegistryError {
+    RegistryError::InvalidField {
+        field: field.to_string(),
+        reason,
+    }
+}
+
+fn assert_not_blank(field: &str, value: &str) {
+    ensure!(
+        !value.trim().is_empty(),
+        RegistryError::MissingField {
+            field: field.to_string()
+        }
+    );
+}
+
+fn assert_length_in_range(field: &str, value: &str, max_length: usize) {
+    ensure!(
+        value.len() <= max_length,
+        invalid_field(
+            field,
+            format!("The length should NOT be greater than {}.", max_length)
+        )
+    );
+}
+
+pub(crate) fn assert_valid_url(field: &str, url: &str) {
+    assert_length_in_range(field, url, MAX_URL_LENGTH);
+    if let Err(reason) = check_url(url) {
+        panic_with(invalid_field(field, reason));
+    }
+}
+
+// Check that the url is an absolute http(s) url with a valid host.
+fn check_url(url: &str) -> Result<(), String> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(|| "The url should start with 'https://' or 'http://'.".to_string())?;
+    if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err("Whitespaces are not allowed in url.".to_string());
+    }
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host
+        .rsplit_once(':')
+        .map_or(host, |(host, port)| match port.parse::<u16>() {
+            Ok(_) => host,
+            Err(_) => "",
+        });
+    if !is_valid_domain(host) {
+        return Err("Invalid host of url.".to_string());
+    }
+    Ok(())
+}
+
+// Check that the address is a github repository address,
+// like `https://github.com/octopus-network/barnacle`.
+fn check_github_address(address: &str) -> Result<(), String> {
+    let path = address
+        .strip_prefix(GITHUB_ADDRESS_PREFIX)
+        .ok_or_else(|| format!("The address should start with '{}'.", GITHUB_ADDRESS_PREFIX))?;
+    let path = path.strip_suffix('/').unwrap_or(path);
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repository) = path
+        .split_once('/')
+        .ok_or_else(|| "The address should be in form of '<owner>/<repository>'.".to_string())?;
+    if owner.is_empty()
+        || owner.len() > MAX_GITHUB_OWNER_LENGTH
+        || owner.starts_with('-')
+        || owner.ends_with('-')
+        || !owner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err("Invalid owner of github repository.".to_string());
+    }
+    if repository.is_empty()
+        || repository.len() > MAX_GITHUB_REPOSITORY_LENGTH
+        || repository == "."
+        || repository == ".."
+        || !repository
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    {
+        return Err("Invalid name of github repository.".to_string());
+    }
+    Ok(())
+}
+
+// Check the syntax of an email address.
+fn check_email(email: &str) -> Result<(), String> {
+    if email.len() > MAX_EMAIL_LENGTH {
+        return Err(format!(
+            "The length should NOT be greater than {}.",
+            MAX_EMAIL_LENGTH
+        ));
+    }
+    let (local_part, domain) = email
+        .split_once('@')
+        .ok_or_else(|| "Missing '@' in email address.".to_string())?;
+    if local_part.is_empty()
+        || local_part.starts_with('.')
+        || local_part.ends_with('.')
+        || local_part.contains("..")
+        || !local_part
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+    {
+        return Err("Invalid local part of email address.".to_string());
+    }
+    if !domain.contains('.') || !is_valid_domain(domain) {
+        return Err("Invalid domain of email address.".to_string());
+    }
+    Ok(())
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+    !domain.is_empty()
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
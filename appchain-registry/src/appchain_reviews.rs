@@ -0,0 +1,99 @@
+use crate::*;
+use types::AppchainReviewAction;
+
+/// The max length of the reason of a review action
+const MAX_REVIEW_REASON_LENGTH: usize = 512;
+
+impl AppchainRegistry {
+    /// Append a record to the review trail of an appchain.
+    pub fn internal_record_review(
+        &mut self,
+        appchain_id: &AppchainId,
+        action: AppchainReviewAction,
+        reason: Option<String>,
+        previous_state: AppchainState,
+    ) {
+        let mut trail = self.get_review_trail_of(appchain_id);
+        trail.push(AppchainReviewRecord {
+            appchain_id: appchain_id.clone(),
+            action,
+            actor: env::predecessor_account_id(),
+            reason,
+            previous_state,
+            timestamp: U64::from(env::block_timestamp()),
+        });
+        self.appchain_review_trails.insert(appchain_id, &trail);
+    }
+    /// Get the review trail of an appchain.
+    pub fn get_review_trail_of(&self, appchain_id: &AppchainId) -> Vec<AppchainReviewRecord> {
+        self.appchain_review_trails
+            .get(appchain_id)
+            .unwrap_or_default()
+    }
+    /// Whether the latest review action of an appchain is an appeal which is not handled yet.
+    pub fn has_pending_appeal(&self, appchain_id: &AppchainId) -> bool {
+        self.get_review_trail_of(appchain_id)
+            .last()
+            .map_or(false, |record| {
+                record.action == AppchainReviewAction::AppealFiled
+            })
+    }
+    /// Get the time until which the rejection of an appchain can be appealed,
+    /// if the latest review action of the appchain is a rejection.
+    pub fn get_appeal_deadline(&self, appchain_id: &AppchainId) -> Option<Timestamp> {
+        let registry_settings = self.registry_settings.get().unwrap();
+        self.get_review_trail_of(appchain_id)
+            .last()
+            .filter(|record| record.action == AppchainReviewAction::Rejected)
+            .map(|rejection| {
+                rejection.timestamp.0
+                    + registry_settings.appeal_window_in_secs * NANO_SECONDS_MULTIPLE
+            })
+    }
+    /// Assert that the rejection of an appchain can be appealed now.
+    pub fn assert_appchain_appealable(&self, appchain_id: &AppchainId) {
+        let appeal_deadline = self.get_appeal_deadline(appchain_id).unwrap_or_else(|| {
+            panic_with(RegistryError::AppchainNotAppealable {
+                appchain_id: appchain_id.clone(),
+            })
+        });
+        ensure!(
+            env::block_timestamp() <= appeal_deadline,
+            RegistryError::AppealWindowExpired {
+                appchain_id: appchain_id.clone()
+            }
+        );
+    }
+    /// Get the rejection record of an appchain which is under a pending appeal.
+    pub fn get_appealed_rejection(&self, appchain_id: &AppchainId) -> AppchainReviewRecord {
+        ensure!(
+            self.has_pending_appeal(appchain_id),
+            RegistryError::AppchainAppealNotFound {
+                appchain_id: appchain_id.clone()
+            }
+        );
+        let trail = self.get_review_trail_of(appchain_id);
+        trail[trail.len() - 2].clone()
+    }
+}
+
+/// Assert that the reason of a review action is not blank and not too long.
+pub fn assert_valid_review_reason(name: &str, reason: &str) {
+    ensure!(
+        !reason.trim().is_empty(),
+        RegistryError::InvalidArgument {
+            name: name.to_string(),
+            reason: "It should NOT be blank.".to_string(),
+        }
+    );
+    ensure!(
+        reason.len() <= MAX_REVIEW_REASON_LENGTH,
+        RegistryError::InvalidArgument {
+            name: name.to_string(),
+            reason: format!(
+                "The length should NOT be greater than {}.",
+                MAX_REVIEW_REASON_LENGTH
+            ),
+        }
+    );
+}
@@ -0,0 +1,186 @@
+use crate::*;
+use std::cmp::Ordering;
+use types::{
+    AppchainFilter, AppchainSortingField, AppchainSortingSpec, AppchainStatus, PagedResult,
+    SortingOrder,
+};
+
+/// The keys of an appchain in the ordered indexes of appchains
+pub struct AppchainSortingKeys {
+    pub total_stake: Balance,
+    pub voting_score: i128,
+}
+
+impl AppchainBasedata {
+    /// Get the keys of the appchain in the ordered indexes of appchains
+    pub fn sorting_keys(&self) -> AppchainSortingKeys {
+        AppchainSortingKeys {
+            total_stake: self.total_stake,
+            voting_score: self.voting_score(),
+        }
+    }
+}
+
+impl AppchainRegistry {
+    /// Move an appchain in the ordered indexes from the `previous` keys to the `current` keys.
+    /// `None` means the appchain is not (or no longer) in the indexes.
+    pub fn internal_update_sorting_indexes(
+        &mut self,
+        appchain_id: &AppchainId,
+        previous: Option<AppchainSortingKeys>,
+        current: Option<AppchainSortingKeys>,
+    ) {
+        if let Some(keys) = previous {
+            self.appchain_total_stake_index
+                .remove(&(keys.total_stake, appchain_id.clone()));
+            self.appchain_voting_score_index
+                .remove(&(keys.voting_score, appchain_id.clone()));
+        }
+        if let Some(keys) = current {
+            self.appchain_total_stake_index
+                .insert(&(keys.total_stake, appchain_id.clone()), &());
+            self.appchain_voting_score_index
+                .insert(&(keys.voting_score, appchain_id.clone()), &());
+        }
+    }
+    /// Get a page of the appchains in the given states (or all appchains if `None`)
+    /// which match the given filter (if any), sorted by the given specs in turn.
+    ///
+    /// The appchains which are equal in all of the specs are ordered by appchain id,
+    /// in the order of the last spec.
+    pub fn internal_get_sorted_appchains(
+        &self,
+        appchain_state: Option<Vec<AppchainState>>,
+        filter: Option<AppchainFilter>,
+        sorting: Vec<AppchainSortingSpec>,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainStatus> {
+        assert_valid_sorting_specs(&sorting);
+        if let Some(filter) = &filter {
+            filter.assert_valid();
+        }
+        let is_matched = |appchain_basedata: &AppchainBasedata| {
+            appchain_state
+                .as_ref()
+                .map_or(true, |states| states.contains(&appchain_basedata.state()))
+                && filter
+                    .as_ref()
+                    .map_or(true, |filter| filter.is_matched_by(appchain_basedata))
+        };
+        if let Some(appchain_ids) = self.get_indexed_appchain_ids(&sorting) {
+            let page = match appchain_state.is_none() && filter.is_none() {
+                true => {
+                    PagedResult::from_iter(appchain_ids, self.appchain_ids.len(), from_index, limit)
+                }
+                false => PagedResult::from_vec(
+                    appchain_ids
+                        .filter(|appchain_id| is_matched(&self.get_appchain_basedata(appchain_id)))
+                        .collect(),
+                    from_index,
+                    limit,
+                ),
+            };
+            return page.map(|appchain_id| self.get_appchain_basedata(&appchain_id).status());
+        }
+        let mut results: Vec<AppchainStatus> = self
+            .appchain_ids
+            .iter()
+            .map(|appchain_id| self.get_appchain_basedata(&appchain_id))
+            .filter(|appchain_basedata| is_matched(appchain_basedata))
+            .map(|appchain_basedata| appchain_basedata.status())
+            .collect();
+        results.sort_by(|a, b| compare_appchains(a, b, &sorting));
+        PagedResult::from_vec(results, from_index, limit)
+    }
+    // Get all appchain ids in the order of an ordered index,
+    // if the sorting specs are backed by one.
+    fn get_indexed_appchain_ids<'a>(
+        &'a self,
+        sorting: &[AppchainSortingSpec],
+    ) -> Option<Box<dyn Iterator<Item = AppchainId> + 'a>> {
+        let spec = match sorting {
+            [spec] => spec,
+            _ => return None,
+        };
+        match (&spec.field, &spec.order) {
+            (AppchainSortingField::TotalStake, SortingOrder::Ascending) => Some(Box::new(
+                self.appchain_total_stake_index
+                    .iter()
+                    .map(|((_, appchain_id), _)| appchain_id),
+            )),
+            (AppchainSortingField::TotalStake, SortingOrder::Descending) => Some(Box::new(
+                self.appchain_total_stake_index
+                    .iter_rev()
+                    .map(|((_, appchain_id), _)| appchain_id),
+            )),
+            (AppchainSortingField::VotingScore, SortingOrder::Ascending) => Some(Box::new(
+                self.appchain_voting_score_index
+                    .iter()
+                    .map(|((_, appchain_id), _)| appchain_id),
+            )),
+            (AppchainSortingField::VotingScore, SortingOrder::Descending) => Some(Box::new(
+                self.appchain_voting_score_index
+                    .iter_rev()
+                    .map(|((_, appchain_id), _)| appchain_id),
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn assert_valid_sorting_specs(sorting: &[AppchainSortingSpec]) {
+    for (index, spec) in sorting.iter().enumerate() {
+        ensure!(
+            !sorting[..index]
+                .iter()
+                .any(|other| other.field == spec.field),
+            RegistryError::InvalidArgument {
+                name: "sorting".to_string(),
+                reason: format!(
+                    "The sorting field '{:?}' is specified more than once.",
+                    spec.field
+                ),
+            }
+        );
+    }
+}
+
+fn compare_appchains(
+    a: &AppchainStatus,
+    b: &AppchainStatus,
+    sorting: &[AppchainSortingSpec],
+) -> Ordering {
+    for spec in sorting {
+        let ordering = match spec.field {
+            AppchainSortingField::AppchainId => a.appchain_id.cmp(&b.appchain_id),
+            AppchainSortingField::VotingScore => a.voting_score.0.cmp(&b.voting_score.0),
+            AppchainSortingField::RegisteredTime => a.registered_time.0.cmp(&b.registered_time.0),
+            AppchainSortingField::TotalStake => a.total_stake.0.cmp(&b.total_stake.0),
+            AppchainSortingField::ValidatorCount => a.validator_count.cmp(&b.validator_count),
+            AppchainSortingField::GoLiveTime => a.go_live_time.0.cmp(&b.go_live_time.0),
+            AppchainSortingField::UpvoteDeposit => a.upvote_deposit.0.cmp(&b.upvote_deposit.0),
+            AppchainSortingField::DownvoteDeposit => {
+                a.downvote_deposit.0.cmp(&b.downvote_deposit.0)
+            }
+            AppchainSortingField::AppchainState => a.appchain_state.cmp(&b.appchain_state),
+        };
+        match apply_sorting_order(ordering, &spec.order) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+    apply_sorting_order(
+        a.appchain_id.cmp(&b.appchain_id),
+        sorting
+            .last()
+            .map_or(&SortingOrder::Ascending, |spec| &spec.order),
+    )
+}
+
+fn apply_sorting_order(ordering: Ordering, order: &SortingOrder) -> Ordering {
+    match order {
+        SortingOrder::Ascending => ordering,
+        SortingOrder::Descending => ordering.reverse(),
+    }
+}
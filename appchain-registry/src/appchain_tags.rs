@@ -0,0 +1,116 @@
+use crate::*;
+
+/// The max number of tags of an appchain
+const MAX_APPCHAIN_TAGS_COUNT: usize = 5;
+/// The max length of an appchain category
+const MAX_APPCHAIN_CATEGORY_LENGTH: usize = 32;
+
+impl AppchainBasedata {
+    /// Get the tags of the appchain
+    pub fn tags(&self) -> Vec<String> {
+        if let Some(bytes) =
+            env::storage_read(&StorageKey::AppchainTags(self.appchain_id.clone()).into_bytes())
+        {
+            Vec::<String>::try_from_slice(&bytes).unwrap_or_else(|_| {
+                panic_with(RegistryError::InvalidStorageData {
+                    key: format!("tags of appchain {}", self.appchain_id),
+                })
+            })
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl AppchainRegistry {
+    /// Assert the given tags can be used as the tags of an appchain.
+    pub fn assert_valid_appchain_tags(&self, tags: &[String]) {
+        ensure!(
+            tags.len() <= MAX_APPCHAIN_TAGS_COUNT,
+            RegistryError::InvalidArgument {
+                name: "tags".to_string(),
+                reason: format!(
+                    "An appchain can have at most {} tags.",
+                    MAX_APPCHAIN_TAGS_COUNT
+                ),
+            }
+        );
+        let registry_settings = self.registry_settings.get().unwrap();
+        for (index, tag) in tags.iter().enumerate() {
+            ensure!(
+                registry_settings.appchain_categories.contains(tag),
+                RegistryError::AppchainCategoryNotFound {
+                    category: tag.clone()
+                }
+            );
+            ensure!(
+                !tags[..index].contains(tag),
+                RegistryError::InvalidArgument {
+                    name: "tags".to_string(),
+                    reason: format!("Tag '{}' is duplicated.", tag),
+                }
+            );
+        }
+    }
+    /// Set the tags of an appchain, and move the appchain in the index of appchain ids by tag.
+    pub fn internal_set_appchain_tags(
+        &mut self,
+        appchain_basedata: &AppchainBasedata,
+        tags: Vec<String>,
+    ) {
+        let appchain_id = appchain_basedata.id();
+        let previous_tags = appchain_basedata.tags();
+        for tag in previous_tags.iter().filter(|tag| !tags.contains(tag)) {
+            let mut appchain_ids = self.get_appchain_ids_by_tag(tag);
+            appchain_ids.remove(appchain_id);
+            match appchain_ids.is_empty() {
+                true => self.appchain_ids_by_tag.remove(tag),
+                false => self.appchain_ids_by_tag.insert(tag, &appchain_ids),
+            };
+        }
+        for tag in tags.iter().filter(|tag| !previous_tags.contains(tag)) {
+            let mut appchain_ids = self.get_appchain_ids_by_tag(tag);
+            appchain_ids.insert(appchain_id);
+            self.appchain_ids_by_tag.insert(tag, &appchain_ids);
+        }
+        let storage_key = StorageKey::AppchainTags(appchain_id.clone()).into_bytes();
+        match tags.is_empty() {
+            true => env::storage_remove(&storage_key),
+            false => env::storage_write(&storage_key, &tags.try_to_vec().unwrap()),
+        };
+    }
+    /// Get the ids of the appchains which have the given tag.
+    pub fn get_appchain_ids_by_tag(&self, tag: &String) -> UnorderedSet<AppchainId> {
+        self.appchain_ids_by_tag.get(tag).unwrap_or_else(|| {
+            UnorderedSet::new(StorageKey::AppchainIdsOfTag(tag.clone()).into_bytes())
+        })
+    }
+    /// Get the pending tag proposal of an appchain, panic if it doesn't exist.
+    pub fn get_appchain_tag_proposal(&self, appchain_id: &AppchainId) -> AppchainTagProposal {
+        self.appchain_tag_proposals
+            .get(appchain_id)
+            .unwrap_or_else(|| {
+                panic_with(RegistryError::AppchainTagProposalNotFound {
+                    appchain_id: appchain_id.clone(),
+                })
+            })
+    }
+}
+
+/// Assert the given category is a lowercase word (with digits and `-`) of at most 32 characters.
+pub fn assert_valid_appchain_category(category: &str) {
+    ensure!(
+        !category.is_empty()
+            && category.len() <= MAX_APPCHAIN_CATEGORY_LENGTH
+            && category
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'),
+        RegistryError::InvalidArgument {
+            name: "category".to_string(),
+            reason: format!(
+                "A category should be 1 to {} characters of lowercase letters, digits and '-'.",
+                MAX_APPCHAIN_CATEGORY_LENGTH
+            ),
+        }
+    );
+}
@@ -0,0 +1,645 @@
+use crate::*;
+use std::fmt::Display;
+use types::{AppchainIdAvailability, AppchainMetadataField};
+
+/// The errors of appchain registry.
+///
+/// An error is panicked as a JSON string like
+/// `{"code":"APPCHAIN_NOT_FOUND","params":{"appchain_id":"myappchain"},"message":"Appchain 'myappchain' not found."}`.
+/// The `code` and `params` of an error are stable, while the `message` is only for human.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "code", content = "params", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RegistryError {
+    //
+    // Access control
+    //
+    ContractAlreadyInitialized,
+    ContractNotInitialized,
+    CallerIsNotOwner,
+    CallerIsNotAppchainLifecycleManager,
+    CallerIsNotRegistrySettingsManager,
+    CallerIsNotOctopusCouncil,
+    CallerIsNotAuditor,
+    CallerIsNotAppchainOwner {
+        appchain_id: AppchainId,
+    },
+    CallerIsNotAppchainAnchor {
+        appchain_id: AppchainId,
+    },
+    OctopusCouncilNotSet,
+    AccountAlreadyHasRole {
+        account_id: AccountId,
+    },
+    AccountIsNotAuditor {
+        account_id: AccountId,
+    },
+    AssetTransferIsPaused,
+    //
+    // Common
+    //
+    InvalidArgument {
+        name: String,
+        reason: String,
+    },
+    MissingField {
+        field: String,
+    },
+    InvalidField {
+        field: String,
+        reason: String,
+    },
+    FieldNotChanged {
+        field: String,
+    },
+    InvalidStorageData {
+        key: String,
+    },
+    //
+    // Deposits
+    //
+    UnknownDepositToken {
+        token_id: AccountId,
+    },
+    InvalidDepositMessage {
+        msg: String,
+    },
+    NotEnoughRegisterDeposit {
+        required_amount: U128,
+    },
+    NotEnoughUpvoteDeposit {
+        deposit: U128,
+    },
+    NotEnoughDownvoteDeposit {
+        deposit: U128,
+    },
+    NotEnoughStorageDeposit {
+        required_amount: U128,
+    },
+    //
+    // Storage balances
+    //
+    AccountNotRegistered {
+        account_id: AccountId,
+    },
+    NotEnoughStorageBalance {
+        account_id: AccountId,
+        required_amount: U128,
+    },
+    StorageBalanceInUse {
+        account_id: AccountId,
+    },
+    //
+    // Appchains
+    //
+    OwnerCanNotRegisterAppchain,
+    AppchainNotFound {
+        appchain_id: AppchainId,
+    },
+    AppchainIdNotAvailable {
+        appchain_id: AppchainId,
+        availability: AppchainIdAvailability,
+    },
+    InvalidAppchainState {
+        appchain_id: AppchainId,
+        state: AppchainState,
+    },
+    AppchainAlreadyInState {
+        appchain_id: AppchainId,
+        state: AppchainState,
+    },
+    InvalidStateToSync {
+        state: AppchainState,
+    },
+    SyncMetricMovedBackward {
+        metric: String,
+        last_value: U128,
+        value: U128,
+    },
+    AppchainAnchorNotStale {
+        appchain_id: AppchainId,
+        last_alive_time: U64,
+    },
+    AppchainAlreadyStale {
+        appchain_id: AppchainId,
+    },
+    AppchainAnchorNotSet {
+        appchain_id: AppchainId,
+    },
+    AppchainHasUpvoteDeposit {
+        appchain_id: AppchainId,
+    },
+    AppchainHasDownvoteDeposit {
+        appchain_id: AppchainId,
+    },
+    InitialSupplyNotEnough {
+        initial_supply: U128,
+        premined_amount: U128,
+        ido_amount: U128,
+    },
+    //
+    // Appchain templates
+    //
+    AppchainTemplateNotFound {
+        template_type: AppchainTemplateType,
+    },
+    AppchainTemplateAlreadyExists {
+        template_type: AppchainTemplateType,
+    },
+    AppchainTemplateNotActive {
+        template_type: AppchainTemplateType,
+    },
+    AppchainTemplateAlreadyActive {
+        template_type: AppchainTemplateType,
+    },
+    AppchainTemplateAlreadyInactive {
+        template_type: AppchainTemplateType,
+    },
+    //
+    // EVM chain ids
+    //
+    MissingEvmChainId {
+        template_type: AppchainTemplateType,
+    },
+    EvmChainIdNotSupported {
+        template_type: AppchainTemplateType,
+    },
+    EvmChainIdReserved {
+        evm_chain_id: U64,
+    },
+    EvmChainIdNotReserved {
+        evm_chain_id: U64,
+    },
+    EvmChainIdAlreadyUsed {
+        evm_chain_id: U64,
+        appchain_id: AppchainId,
+    },
+    //
+    // Appchain id rules
+    //
+    AppchainIdNotReserved {
+        appchain_id: AppchainId,
+    },
+    DenyPatternAlreadyExists {
+        pattern: String,
+    },
+    DenyPatternNotFound {
+        pattern: String,
+    },
+    //
+    // Register deposit vouchers
+    //
+    RegisterDepositVoucherNotFound {
+        voucher_id: String,
+    },
+    RegisterDepositVoucherAlreadyExists {
+        voucher_id: String,
+    },
+    RegisterDepositVoucherUsedUp {
+        voucher_id: String,
+    },
+    RegisterDepositVoucherNotRedeemable {
+        voucher_id: String,
+        account_id: AccountId,
+    },
+    //
+    // Registration commitments
+    //
+    RegistrationCommitmentRequired,
+    RegistrationCommitmentNotFound,
+    RegistrationCommitmentAlreadyExists,
+    RegistrationCommitmentNotExpired,
+    RegistrationCommitmentExpired,
+    RegistrationRevealedInCommittedBlock,
+    UnrefundedCommitmentDepositNotFound {
+        account_id: AccountId,
+    },
+    //
+    // Appchain metadata changes
+    //
+    EmptyAppchainMetadataPatch,
+    MetadataFieldNotUpdatableByOwner {
+        field: AppchainMetadataField,
+    },
+    MetadataChangeRequestNotFound {
+        request_id: U64,
+    },
+    AppchainMetadataRevisionNotFound {
+        appchain_id: AppchainId,
+        revision: U64,
+    },
+    //
+    // Appchain audits
+    //
+    NotEnoughApprovingAudits {
+        appchain_id: AppchainId,
+        required_count: u32,
+        approved_count: u32,
+    },
+    //
+    // Appchain reviews
+    //
+    AppchainNotAppealable {
+        appchain_id: AppchainId,
+    },
+    AppealWindowExpired {
+        appchain_id: AppchainId,
+    },
+    AppchainAppealNotFound {
+        appchain_id: AppchainId,
+    },
+    AppchainAppealPending {
+        appchain_id: AppchainId,
+    },
+    AppealWindowNotExpired {
+        appchain_id: AppchainId,
+        appeal_deadline: U64,
+    },
+    //
+    // Appchain categories and tags
+    //
+    AppchainCategoryNotFound {
+        category: String,
+    },
+    AppchainCategoryAlreadyExists {
+        category: String,
+    },
+    AppchainCategoryInUse {
+        category: String,
+    },
+    AppchainTagProposalNotFound {
+        appchain_id: AppchainId,
+    },
+    //
+    // Contract upgrade
+    //
+    ContractCodeNotStaged,
+}
+
+impl RegistryError {
+    /// Get the JSON string of this error, which contains its code, params and message.
+    pub fn to_json_string(&self) -> String {
+        let mut value = serde_json::to_value(self).unwrap();
+        value["message"] = serde_json::Value::String(self.to_string());
+        value.to_string()
+    }
+}
+
+/// Panic with the given error.
+pub fn panic_with(error: RegistryError) -> ! {
+    env::panic_str(&error.to_json_string())
+}
+
+/// Panic with the given error if the condition is `false`.
+///
+/// The error is only constructed when the condition is `false`.
+#[macro_export]
+macro_rules! ensure {
+    ($condition:expr, $error:expr $(,)?) => {
+        if !$condition {
+            $crate::errors::panic_with($error)
+        }
+    };
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistryError::ContractAlreadyInitialized => {
+                write!(f, "The contract is already initialized.")
+            }
+            RegistryError::ContractNotInitialized => write!(f, "The contract is not initialized."),
+            RegistryError::CallerIsNotOwner => write!(f, "Function can only be called by owner."),
+            RegistryError::CallerIsNotAppchainLifecycleManager => write!(
+                f,
+                "Function can only be called by appchain lifecycle manager."
+            ),
+            RegistryError::CallerIsNotRegistrySettingsManager => write!(
+                f,
+                "Function can only be called by registry settings manager."
+            ),
+            RegistryError::CallerIsNotOctopusCouncil => {
+                write!(f, "Only octopus council account can call this function.")
+            }
+            RegistryError::CallerIsNotAuditor => {
+                write!(f, "Only auditors can call this function.")
+            }
+            RegistryError::CallerIsNotAppchainOwner { appchain_id } => write!(
+                f,
+                "Function can only be called by the owner of appchain '{}'.",
+                appchain_id
+            ),
+            RegistryError::CallerIsNotAppchainAnchor { appchain_id } => write!(
+                f,
+                "Only the anchor of appchain '{}' can call this function.",
+                appchain_id
+            ),
+            RegistryError::OctopusCouncilNotSet => {
+                write!(f, "Octopus council account is not setup.")
+            }
+            RegistryError::AccountAlreadyHasRole { account_id } => {
+                write!(f, "The account '{}' already has role in contract.", account_id)
+            }
+            RegistryError::AccountIsNotAuditor { account_id } => {
+                write!(f, "The account '{}' is not an auditor.", account_id)
+            }
+            RegistryError::AssetTransferIsPaused => {
+                write!(f, "The asset transfer in this contract has been paused.")
+            }
+            RegistryError::InvalidArgument { name, reason } => {
+                write!(f, "Invalid argument '{}': {}", name, reason)
+            }
+            RegistryError::MissingField { field } => {
+                write!(f, "Missing necessary field '{}'.", field)
+            }
+            RegistryError::InvalidField { field, reason } => {
+                write!(f, "Invalid field '{}'. {}", field, reason)
+            }
+            RegistryError::FieldNotChanged { field } => {
+                write!(f, "The field '{}' is not changed.", field)
+            }
+            RegistryError::InvalidStorageData { key } => {
+                write!(f, "Invalid storage data of '{}'.", key)
+            }
+            RegistryError::UnknownDepositToken { token_id } => write!(
+                f,
+                "Invalid deposit of unknown NEP-141 asset '{}' received. Return deposit.",
+                token_id
+            ),
+            RegistryError::InvalidDepositMessage { msg } => write!(
+                f,
+                "Invalid msg '{}' attached in `ft_transfer_call`. Refund deposit.",
+                msg
+            ),
+            RegistryError::NotEnoughRegisterDeposit { required_amount } => write!(
+                f,
+                "Not enough register deposit. Required amount: {}",
+                required_amount.0
+            ),
+            RegistryError::NotEnoughUpvoteDeposit { deposit } => write!(
+                f,
+                "Not enough upvote deposit to withdraw. Current deposit: {}",
+                deposit.0
+            ),
+            RegistryError::NotEnoughDownvoteDeposit { deposit } => write!(
+                f,
+                "Not enough downvote deposit to withdraw. Current deposit: {}",
+                deposit.0
+            ),
+            RegistryError::NotEnoughStorageDeposit { required_amount } => write!(
+                f,
+                "Not enough deposit for storage. Required amount: {}",
+                required_amount.0
+            ),
+            RegistryError::AccountNotRegistered { account_id } => write!(
+                f,
+                "Account '{}' is not registered for storage balance.",
+                account_id
+            ),
+            RegistryError::NotEnoughStorageBalance {
+                account_id,
+                required_amount,
+            } => write!(
+                f,
+                "Not enough storage balance of '{}'. Required amount: {}",
+                account_id, required_amount.0
+            ),
+            RegistryError::StorageBalanceInUse { account_id } => write!(
+                f,
+                "The storage balance of '{}' is still used by appchain(s).",
+                account_id
+            ),
+            RegistryError::OwnerCanNotRegisterAppchain => write!(
+                f,
+                "The register account should NOT be the contract owner."
+            ),
+            RegistryError::AppchainNotFound { appchain_id } => {
+                write!(f, "Appchain '{}' not found.", appchain_id)
+            }
+            RegistryError::AppchainIdNotAvailable {
+                appchain_id,
+                availability,
+            } => write!(
+                f,
+                "Appchain id '{}' is not available. {}",
+                appchain_id, availability
+            ),
+            RegistryError::InvalidAppchainState { appchain_id, state } => write!(
+                f,
+                "The state of appchain '{}' can NOT be '{}'.",
+                appchain_id, state
+            ),
+            RegistryError::AppchainAlreadyInState { appchain_id, state } => write!(
+                f,
+                "Appchain '{}' is already in state '{}'.",
+                appchain_id, state
+            ),
+            RegistryError::InvalidStateToSync { state } => {
+                write!(f, "Invalid state '{}' to sync.", state)
+            }
+            RegistryError::SyncMetricMovedBackward {
+                metric,
+                last_value,
+                value,
+            } => write!(
+                f,
+                "The synced '{}' ({}) should not be less than the last synced value ({}).",
+                metric, value.0, last_value.0
+            ),
+            RegistryError::AppchainAnchorNotStale {
+                appchain_id,
+                last_alive_time,
+            } => write!(
+                f,
+                "Anchor of appchain '{}' is not stale, it is alive at {}.",
+                appchain_id, last_alive_time.0
+            ),
+            RegistryError::AppchainAlreadyStale { appchain_id } => {
+                write!(f, "Appchain '{}' is already flagged as stale.", appchain_id)
+            }
+            RegistryError::AppchainAnchorNotSet { appchain_id } => {
+                write!(f, "Anchor of appchain '{}' is not set.", appchain_id)
+            }
+            RegistryError::AppchainHasUpvoteDeposit { appchain_id } => write!(
+                f,
+                "Appchain '{}' still has upvote deposit(s).",
+                appchain_id
+            ),
+            RegistryError::AppchainHasDownvoteDeposit { appchain_id } => write!(
+                f,
+                "Appchain '{}' still has downvote deposit(s).",
+                appchain_id
+            ),
+            RegistryError::InitialSupplyNotEnough {
+                initial_supply,
+                premined_amount,
+                ido_amount,
+            } => write!(
+                f,
+                "The initial supply of wrapped appchain token ({}) should not be less than the sum of premined amount ({}) and IDO amount ({}).",
+                initial_supply.0, premined_amount.0, ido_amount.0
+            ),
+            RegistryError::AppchainTemplateNotFound { template_type } => {
+                write!(f, "Appchain template '{}' not found.", template_type)
+            }
+            RegistryError::AppchainTemplateAlreadyExists { template_type } => {
+                write!(f, "Appchain template '{}' already exists.", template_type)
+            }
+            RegistryError::AppchainTemplateNotActive { template_type } => {
+                write!(f, "Appchain template '{}' is not active.", template_type)
+            }
+            RegistryError::AppchainTemplateAlreadyActive { template_type } => {
+                write!(f, "Appchain template '{}' is already active.", template_type)
+            }
+            RegistryError::AppchainTemplateAlreadyInactive { template_type } => {
+                write!(f, "Appchain template '{}' is already inactive.", template_type)
+            }
+            RegistryError::MissingEvmChainId { template_type } => write!(
+                f,
+                "Missing EVM chain id for appchain template '{}'.",
+                template_type
+            ),
+            RegistryError::EvmChainIdNotSupported { template_type } => write!(
+                f,
+                "Appchain template '{}' does not support EVM chain id.",
+                template_type
+            ),
+            RegistryError::EvmChainIdReserved { evm_chain_id } => {
+                write!(f, "EVM chain id {} is reserved.", evm_chain_id.0)
+            }
+            RegistryError::EvmChainIdNotReserved { evm_chain_id } => {
+                write!(f, "EVM chain id {} is not reserved.", evm_chain_id.0)
+            }
+            RegistryError::EvmChainIdAlreadyUsed {
+                evm_chain_id,
+                appchain_id,
+            } => write!(
+                f,
+                "EVM chain id {} is already used by appchain '{}'.",
+                evm_chain_id.0, appchain_id
+            ),
+            RegistryError::AppchainIdNotReserved { appchain_id } => {
+                write!(f, "Appchain id '{}' is not reserved.", appchain_id)
+            }
+            RegistryError::DenyPatternAlreadyExists { pattern } => {
+                write!(f, "Deny pattern '{}' already exists.", pattern)
+            }
+            RegistryError::DenyPatternNotFound { pattern } => {
+                write!(f, "Deny pattern '{}' not found.", pattern)
+            }
+            RegistryError::RegisterDepositVoucherNotFound { voucher_id } => {
+                write!(f, "Register deposit voucher '{}' not found.", voucher_id)
+            }
+            RegistryError::RegisterDepositVoucherAlreadyExists { voucher_id } => {
+                write!(f, "Register deposit voucher '{}' already exists.", voucher_id)
+            }
+            RegistryError::RegisterDepositVoucherUsedUp { voucher_id } => {
+                write!(f, "Register deposit voucher '{}' is used up.", voucher_id)
+            }
+            RegistryError::RegisterDepositVoucherNotRedeemable {
+                voucher_id,
+                account_id,
+            } => write!(
+                f,
+                "Register deposit voucher '{}' can NOT be redeemed by '{}'.",
+                voucher_id, account_id
+            ),
+            RegistryError::RegistrationCommitmentRequired => write!(
+                f,
+                "The appchain registration should be committed before revealing."
+            ),
+            RegistryError::RegistrationCommitmentNotFound => {
+                write!(f, "Registration commitment not found.")
+            }
+            RegistryError::RegistrationCommitmentAlreadyExists => {
+                write!(f, "The registration commitment already exists.")
+            }
+            RegistryError::RegistrationCommitmentNotExpired => {
+                write!(f, "The registration commitment is not expired.")
+            }
+            RegistryError::RegistrationCommitmentExpired => {
+                write!(f, "The registration commitment is expired.")
+            }
+            RegistryError::RegistrationRevealedInCommittedBlock => write!(
+                f,
+                "The registration can NOT be revealed in the same block as the commitment."
+            ),
+            RegistryError::UnrefundedCommitmentDepositNotFound { account_id } => write!(
+                f,
+                "Account '{}' has no registration commitment deposit to be refunded.",
+                account_id
+            ),
+            RegistryError::EmptyAppchainMetadataPatch => {
+                write!(f, "No field of appchain metadata is patched.")
+            }
+            RegistryError::MetadataFieldNotUpdatableByOwner { field } => write!(
+                f,
+                "The field '{}' can NOT be updated by appchain owner directly. Submit a change request instead.",
+                field.name()
+            ),
+            RegistryError::MetadataChangeRequestNotFound { request_id } => write!(
+                f,
+                "Appchain metadata change request '{}' not found.",
+                request_id.0
+            ),
+            RegistryError::AppchainMetadataRevisionNotFound {
+                appchain_id,
+                revision,
+            } => write!(
+                f,
+                "Revision {} of the metadata of appchain '{}' not found.",
+                revision.0, appchain_id
+            ),
+            RegistryError::NotEnoughApprovingAudits {
+                appchain_id,
+                required_count,
+                approved_count,
+            } => write!(
+                f,
+                "Appchain '{}' needs {} approving audits, but only has {}.",
+                appchain_id, required_count, approved_count
+            ),
+            RegistryError::AppchainNotAppealable { appchain_id } => write!(
+                f,
+                "Appchain '{}' is not rejected, or its rejection is already appealed.",
+                appchain_id
+            ),
+            RegistryError::AppealWindowExpired { appchain_id } => write!(
+                f,
+                "The window for appealing the rejection of appchain '{}' has expired.",
+                appchain_id
+            ),
+            RegistryError::AppchainAppealNotFound { appchain_id } => {
+                write!(f, "Appchain '{}' has no pending appeal.", appchain_id)
+            }
+            RegistryError::AppchainAppealPending { appchain_id } => {
+                write!(f, "Appchain '{}' has a pending appeal.", appchain_id)
+            }
+            RegistryError::AppealWindowNotExpired {
+                appchain_id,
+                appeal_deadline,
+            } => write!(
+                f,
+                "The rejection of appchain '{}' can be appealed until {}.",
+                appchain_id, appeal_deadline.0
+            ),
+            RegistryError::AppchainCategoryNotFound { category } => {
+                write!(f, "Appchain category '{}' not found.", category)
+            }
+            RegistryError::AppchainCategoryAlreadyExists { category } => {
+                write!(f, "Appchain category '{}' already exists.", category)
+            }
+            RegistryError::AppchainCategoryInUse { category } => write!(
+                f,
+                "Appchain category '{}' is used as a tag of some appchains.",
+                category
+            ),
+            RegistryError::AppchainTagProposalNotFound { appchain_id } => {
+                write!(f, "Appchain '{}' has no pending tag proposal.", appchain_id)
+            }
+            RegistryError::ContractCodeNotStaged => {
+                write!(f, "Wasm file for deployment is not staged yet.")
+            }
+        }
+    }
+}
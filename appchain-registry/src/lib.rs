@@ -1,47 +1,121 @@
+// `Option::map_or` is used instead of `is_some_and` and `is_none_or`, which are not available
+// in the older toolchains that the wasm of this contract may be built with.
+#![allow(unknown_lints, clippy::unnecessary_map_or)]
+
+mod appchain_audits;
 mod appchain_basedata;
-mod registry_status;
+mod appchain_changes;
+mod appchain_filter;
+mod appchain_id_rules;
+mod appchain_liveness;
+mod appchain_metadata_changes;
+mod appchain_metadata_revisions;
+mod appchain_metadata_rules;
+mod appchain_reviews;
+mod appchain_sorting;
+mod appchain_tags;
+pub mod errors;
+mod pagination;
+pub mod registration_commitment;
+pub mod registry_queries;
+mod registry_statistics;
+pub mod registry_status;
 mod storage_key;
+mod storage_manager;
 pub mod storage_migration;
 pub mod types;
 mod upgrade;
-mod user_actions;
+pub mod user_actions;
 
 use core::convert::TryFrom;
 use std::collections::HashMap;
 
 use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
+#[allow(deprecated)]
 use near_contract_standards::upgrade::Ownable;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LazyOption, LookupMap, UnorderedSet};
+use near_sdk::collections::{LazyOption, LookupMap, TreeMap, UnorderedMap, UnorderedSet};
 use near_sdk::json_types::{U128, U64};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{
     assert_self, env, ext_contract, log, near_bindgen, serde_json, AccountId, Balance, Duration,
-    Gas, PanicOnDefault, Promise, PromiseOrValue, PromiseResult, PublicKey, Timestamp,
+    Gas, PanicOnDefault, Promise, PromiseOrValue, PromiseResult, PublicKey, StorageUsage,
+    Timestamp,
 };
 
 use appchain_basedata::AppchainBasedata;
+use appchain_id_rules::normalize_appchain_id;
+use errors::{panic_with, RegistryError};
 use storage_key::StorageKey;
 use types::{
-    AppchainId, AppchainMetadata, AppchainState, AppchainTemplateType, RegistryRoles,
-    RegistrySettings,
+    AccountStorageBalance, AppchainAuditRecord, AppchainChange, AppchainChangeKind, AppchainHealth,
+    AppchainId, AppchainIdAvailability, AppchainMetadata, AppchainMetadataChangeRequest,
+    AppchainMetadataPatch, AppchainMetadataRevision, AppchainReviewAction, AppchainReviewRecord,
+    AppchainState, AppchainStateStatistics, AppchainSyncMetrics, AppchainTagProposal,
+    AppchainTemplate, AppchainTemplateType, RegisterDepositVoucher, RegisterDepositVoucherHolder,
+    RegistrationCommitment, RegistryRoles, RegistrySettings, ReservedAppchainId,
 };
+use user_actions::builtin_appchain_templates;
 
 const VERSION: &str = "v3.1.0";
-/// Initial balance for the AppchainAnchor contract to cover storage and related.
-const APPCHAIN_ANCHOR_INIT_BALANCE: Balance = 26_000_000_000_000_000_000_000_000;
-const T_GAS_FOR_RESOLVER_FUNCTION: u64 = 10;
-const T_GAS_FOR_FT_TRANSFER: u64 = 20;
-const T_GAS_FOR_CALLING_ANCHOR_FUNCTION: u64 = 150;
-const OCT_DECIMALS_BASE: u128 = 1000_000_000_000_000_000;
+/// Default initial balance for the AppchainAnchor contract to cover storage and related.
+const DEFAULT_APPCHAIN_ANCHOR_INIT_BALANCE: Balance = 26_000_000_000_000_000_000_000_000;
+/// Maximum initial balance for the AppchainAnchor contract.
+const MAXIMUM_APPCHAIN_ANCHOR_INIT_BALANCE: Balance = 100_000_000_000_000_000_000_000_000;
+/// Default gas (in T) for resolver functions of this contract.
+const DEFAULT_T_GAS_FOR_RESOLVER_FUNCTION: u64 = 10;
+/// Default gas (in T) for calling `ft_transfer` of OCT token contract.
+const DEFAULT_T_GAS_FOR_FT_TRANSFER: u64 = 20;
+/// Default gas (in T) for calling functions of appchain anchor contract.
+const DEFAULT_T_GAS_FOR_CALLING_ANCHOR_FUNCTION: u64 = 150;
+/// The range of gas (in T) for resolver functions of this contract.
+const T_GAS_RANGE_FOR_RESOLVER_FUNCTION: (u64, u64) = (5, 50);
+/// The range of gas (in T) for calling `ft_transfer` of OCT token contract.
+const T_GAS_RANGE_FOR_FT_TRANSFER: (u64, u64) = (5, 100);
+/// The range of gas (in T) for calling functions of appchain anchor contract.
+const T_GAS_RANGE_FOR_CALLING_ANCHOR_FUNCTION: (u64, u64) = (10, 250);
+const OCT_DECIMALS_BASE: u128 = 1_000_000_000_000_000_000;
 /// Default register deposit amount
 const DEFAULT_REGISTER_DEPOSIT: u128 = 1000;
+/// Default deposit amount for committing an appchain registration
+const DEFAULT_REGISTRATION_COMMITMENT_DEPOSIT: u128 = 10;
+/// Default time range (in seconds) for revealing an appchain registration
+const DEFAULT_REGISTRATION_REVEAL_WINDOW_IN_SECS: u64 = 3600 * 24;
+/// Default number of approving audits which an appchain needs before passing auditing
+const DEFAULT_REQUIRED_APPROVING_AUDITS: u32 = 1;
+/// Default time range (in seconds) for appealing the rejection of an appchain
+const DEFAULT_APPEAL_WINDOW_IN_SECS: u64 = 3600 * 24 * 7;
+/// The categories which can be used as the tags of appchains by default
+const DEFAULT_APPCHAIN_CATEGORIES: [&str; 6] =
+    ["defi", "gaming", "social", "nft", "infrastructure", "dao"];
+/// Default time range (in seconds) in which the anchor of a booting or active appchain should sync
+const DEFAULT_ANCHOR_STALENESS_THRESHOLD_IN_SECS: u64 = 3600 * 24 * 2;
 /// Multiple of nano seconds for a second
 const NANO_SECONDS_MULTIPLE: u64 = 1_000_000_000;
 /// Default staging duration of contract code for upgrade
 const DEFAULT_CONTRACT_CODE_STAGING_DURATION: u64 = 3600 * 24;
-
-const APPCHAIN_NOT_FOUND: &'static str = "Appchain not found.";
+/// The appchain ids which are reserved by default.
+const DEFAULT_RESERVED_APPCHAIN_IDS: [&str; 6] =
+    ["near", "octopus", "oct", "aurora", "registry", "admin"];
+/// The EVM chain ids of well-known public chains, which are reserved by default.
+const DEFAULT_RESERVED_EVM_CHAIN_IDS: [u64; 16] = [
+    1,          // Ethereum Mainnet
+    5,          // Goerli
+    10,         // Optimism
+    56,         // BNB Smart Chain
+    97,         // BNB Smart Chain Testnet
+    100,        // Gnosis
+    137,        // Polygon
+    250,        // Fantom Opera
+    1284,       // Moonbeam
+    1285,       // Moonriver
+    42161,      // Arbitrum One
+    43114,      // Avalanche C-Chain
+    80001,      // Polygon Mumbai
+    11155111,   // Sepolia
+    1313161554, // Aurora Mainnet
+    1313161555, // Aurora Testnet
+];
 
 #[ext_contract(ext_self)]
 pub trait SelfCallback {
@@ -71,6 +145,15 @@ pub trait AppchainAnchorCallback {
         validator_count: u32,
         total_stake: U128,
     );
+    /// Sync state of an appchain to registry, together with the metrics of the appchain
+    fn sync_state_and_metrics_of(
+        &mut self,
+        appchain_id: AppchainId,
+        appchain_state: AppchainState,
+        validator_count: u32,
+        total_stake: U128,
+        metrics: AppchainSyncMetrics,
+    );
 }
 
 #[near_bindgen]
@@ -102,11 +185,80 @@ pub struct AppchainRegistry {
     registry_roles: LazyOption<RegistryRoles>,
     /// Whether the asset transfer is paused
     asset_transfer_is_paused: bool,
+    /// The map from template name to the appchain templates
+    appchain_templates: UnorderedMap<AppchainTemplateType, AppchainTemplate>,
+    /// The map from voucher id to the register deposit vouchers
+    register_deposit_vouchers: UnorderedMap<String, RegisterDepositVoucher>,
+    /// The map from EVM chain id to the appchain id which uses it
+    evm_chain_ids: LookupMap<u64, AppchainId>,
+    /// The map from normalized appchain id to the reserved appchain ids
+    reserved_appchain_ids: UnorderedMap<String, ReservedAppchainId>,
+    /// The map from commitment hash to the appchain registration commitments
+    registration_commitments: UnorderedMap<String, RegistrationCommitment>,
+    /// The map from account id to their storage balance
+    storage_balances: LookupMap<AccountId, AccountStorageBalance>,
+    /// The map from appchain id to the bytes of storage used by the appchain
+    appchain_storage_usages: LookupMap<AppchainId, StorageUsage>,
+    /// The map from request id to the pending appchain metadata change requests
+    appchain_metadata_change_requests: UnorderedMap<u64, AppchainMetadataChangeRequest>,
+    /// The id of the latest appchain metadata change request
+    latest_metadata_change_request_id: u64,
+    /// The map from appchain id to the count of its metadata revisions
+    appchain_metadata_revision_counts: LookupMap<AppchainId, u64>,
+    /// The map from pair (appchain id, revision number) to the appchain metadata revisions
+    appchain_metadata_revisions: LookupMap<(AppchainId, u64), AppchainMetadataRevision>,
+    /// The map from appchain id to the audit records of the appchain
+    appchain_audit_records: LookupMap<AppchainId, Vec<AppchainAuditRecord>>,
+    /// The map from appchain id to the review trail of the appchain
+    appchain_review_trails: LookupMap<AppchainId, Vec<AppchainReviewRecord>>,
+    /// The ordered index of pair (total stake, appchain id) of all appchains
+    appchain_total_stake_index: TreeMap<(Balance, AppchainId), ()>,
+    /// The ordered index of pair (voting score, appchain id) of all appchains
+    appchain_voting_score_index: TreeMap<(i128, AppchainId), ()>,
+    /// The map from appchain id to the pending tag proposal of the appchain
+    appchain_tag_proposals: UnorderedMap<AppchainId, AppchainTagProposal>,
+    /// The map from tag to the set of ids of the appchains which have the tag
+    appchain_ids_by_tag: LookupMap<String, UnorderedSet<AppchainId>>,
+    /// The sequence number of the latest change of appchains
+    latest_appchain_change_sequence: u64,
+    /// The map from sequence number to the latest change of each appchain
+    appchain_changes: TreeMap<u64, AppchainChange>,
+    /// The map from appchain id to the sequence number of its latest change
+    appchain_change_sequences: LookupMap<AppchainId, u64>,
+    /// The map from appchain state to the statistics of the appchains in the state
+    appchain_state_statistics: LookupMap<AppchainState, AppchainStateStatistics>,
+    /// The sum of register deposits collected in all registrations
+    total_register_deposit: Balance,
+    /// The map from account id to the number of appchains owned by the account
+    owner_appchain_counts: LookupMap<AccountId, u32>,
+    /// The number of the accounts which own appchains
+    distinct_owner_count: u64,
+    /// The map from account id to the number of upvote and downvote deposits of the account
+    voter_deposit_counts: LookupMap<AccountId, u32>,
+    /// The number of the accounts which have upvote or downvote deposits
+    distinct_voter_count: u64,
+    /// The map from account id to the registration commitment deposit
+    /// which is failed to be refunded to the account
+    unrefunded_commitment_deposits: LookupMap<AccountId, Balance>,
+    /// The map from anchor account id to the id of the appchain
+    appchain_ids_by_anchor: LookupMap<AccountId, AppchainId>,
+    /// The map from appchain id to the count of the metadata revisions recorded
+    /// (for removed appchains with the same id) before the registration of the appchain
+    appchain_metadata_initial_revisions: LookupMap<AppchainId, u64>,
+    /// The ordered index of pair (appchain id, request id) of the pending
+    /// appchain metadata change requests
+    appchain_metadata_change_request_index: TreeMap<(AppchainId, u64), ()>,
+    /// The map from normalized appchain id to the id of the registered appchain
+    normalized_appchain_ids: LookupMap<String, AppchainId>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
+#[allow(clippy::large_enum_variant)]
 enum RegistryDepositMessage {
+    CommitAppchainRegistration {
+        commitment: String,
+    },
     RegisterAppchain {
         appchain_id: String,
         description: String,
@@ -122,6 +274,9 @@ enum RegistryDepositMessage {
         initial_era_reward: U128,
         fungible_token_metadata: FungibleTokenMetadata,
         custom_metadata: HashMap<String, String>,
+        voucher_id: Option<String>,
+        voucher_secret: Option<String>,
+        registration_salt: Option<String>,
     },
 }
 
@@ -129,8 +284,18 @@ enum RegistryDepositMessage {
 impl AppchainRegistry {
     #[init]
     pub fn new(oct_token: AccountId) -> Self {
-        assert!(!env::state_exists(), "The contract is already initialized.");
-        Self {
+        ensure!(
+            !env::state_exists(),
+            RegistryError::ContractAlreadyInitialized
+        );
+        let registry_settings = RegistrySettings::default();
+        let mut appchain_templates = UnorderedMap::new(StorageKey::AppchainTemplates.into_bytes());
+        for template in
+            builtin_appchain_templates(U128::from(DEFAULT_REGISTER_DEPOSIT * OCT_DECIMALS_BASE))
+        {
+            appchain_templates.insert(&template.name, &template);
+        }
+        let mut contract = Self {
             owner: env::signer_account_id(),
             owner_pk: env::signer_account_pk(),
             contract_code_staging_timestamp: u64::MAX,
@@ -139,7 +304,7 @@ impl AppchainRegistry {
             oct_token,
             registry_settings: LazyOption::new(
                 StorageKey::RegistrySettings.into_bytes(),
-                Some(&RegistrySettings::default()),
+                Some(&registry_settings),
             ),
             appchain_ids: UnorderedSet::new(StorageKey::AppchainIds.into_bytes()),
             appchain_basedatas: LookupMap::new(StorageKey::AppchainBasedatas.into_bytes()),
@@ -151,83 +316,226 @@ impl AppchainRegistry {
                 Some(&RegistryRoles::default()),
             ),
             asset_transfer_is_paused: false,
+            appchain_templates,
+            register_deposit_vouchers: UnorderedMap::new(
+                StorageKey::RegisterDepositVouchers.into_bytes(),
+            ),
+            evm_chain_ids: LookupMap::new(StorageKey::EvmChainIds.into_bytes()),
+            reserved_appchain_ids: UnorderedMap::new(StorageKey::ReservedAppchainIds.into_bytes()),
+            registration_commitments: UnorderedMap::new(
+                StorageKey::RegistrationCommitments.into_bytes(),
+            ),
+            storage_balances: LookupMap::new(StorageKey::StorageBalances.into_bytes()),
+            appchain_storage_usages: LookupMap::new(StorageKey::AppchainStorageUsages.into_bytes()),
+            appchain_metadata_change_requests: UnorderedMap::new(
+                StorageKey::AppchainMetadataChangeRequests.into_bytes(),
+            ),
+            latest_metadata_change_request_id: 0,
+            appchain_metadata_revision_counts: LookupMap::new(
+                StorageKey::AppchainMetadataRevisionCounts.into_bytes(),
+            ),
+            appchain_metadata_revisions: LookupMap::new(
+                StorageKey::AppchainMetadataRevisions.into_bytes(),
+            ),
+            appchain_audit_records: LookupMap::new(StorageKey::AppchainAuditRecords.into_bytes()),
+            appchain_review_trails: LookupMap::new(StorageKey::AppchainReviewTrails.into_bytes()),
+            appchain_total_stake_index: TreeMap::new(
+                StorageKey::AppchainTotalStakeIndex.into_bytes(),
+            ),
+            appchain_voting_score_index: TreeMap::new(
+                StorageKey::AppchainVotingScoreIndex.into_bytes(),
+            ),
+            appchain_tag_proposals: UnorderedMap::new(
+                StorageKey::AppchainTagProposals.into_bytes(),
+            ),
+            appchain_ids_by_tag: LookupMap::new(StorageKey::AppchainIdsByTag.into_bytes()),
+            latest_appchain_change_sequence: 0,
+            appchain_changes: TreeMap::new(StorageKey::AppchainChanges.into_bytes()),
+            appchain_change_sequences: LookupMap::new(
+                StorageKey::AppchainChangeSequences.into_bytes(),
+            ),
+            appchain_state_statistics: LookupMap::new(
+                StorageKey::AppchainStateStatistics.into_bytes(),
+            ),
+            total_register_deposit: 0,
+            owner_appchain_counts: LookupMap::new(StorageKey::OwnerAppchainCounts.into_bytes()),
+            distinct_owner_count: 0,
+            voter_deposit_counts: LookupMap::new(StorageKey::VoterDepositCounts.into_bytes()),
+            distinct_voter_count: 0,
+            unrefunded_commitment_deposits: LookupMap::new(
+                StorageKey::UnrefundedCommitmentDeposits.into_bytes(),
+            ),
+            appchain_ids_by_anchor: LookupMap::new(StorageKey::AppchainIdsByAnchor.into_bytes()),
+            appchain_metadata_initial_revisions: LookupMap::new(
+                StorageKey::AppchainMetadataInitialRevisions.into_bytes(),
+            ),
+            appchain_metadata_change_request_index: TreeMap::new(
+                StorageKey::AppchainMetadataChangeRequestIndex.into_bytes(),
+            ),
+            normalized_appchain_ids: LookupMap::new(StorageKey::NormalizedAppchainIds.into_bytes()),
+        };
+        for appchain_id in DEFAULT_RESERVED_APPCHAIN_IDS {
+            contract.internal_reserve_appchain_id(&appchain_id.to_string(), None);
         }
+        contract
     }
     // Assert the asset transfer is not paused.
     fn assert_asset_transfer_is_not_paused(&self) {
-        assert!(
+        ensure!(
             !self.asset_transfer_is_paused,
-            "The asset transfer in this contract has been paused."
+            RegistryError::AssetTransferIsPaused
         );
     }
     // Assert that the contract called by the owner.
     fn assert_owner(&self) {
-        assert_eq!(
-            env::predecessor_account_id(),
-            self.owner,
-            "Function can only be called by owner."
+        ensure!(
+            env::predecessor_account_id().eq(&self.owner),
+            RegistryError::CallerIsNotOwner
         );
     }
     // Assert that the contract is called by appchain lifecycle manager.
     fn assert_appchain_lifecycle_manager(&self) {
         let registry_roles = self.registry_roles.get().unwrap();
-        assert_eq!(
-            env::predecessor_account_id(),
-            registry_roles.appchain_lifecycle_manager,
-            "Function can only be called by appchain lifecycle manager."
+        ensure!(
+            env::predecessor_account_id().eq(&registry_roles.appchain_lifecycle_manager),
+            RegistryError::CallerIsNotAppchainLifecycleManager
         );
     }
     // Assert that the contract is called by registry settings manager.
     fn assert_registry_settings_manager(&self) {
         let registry_roles = self.registry_roles.get().unwrap();
-        assert_eq!(
-            env::predecessor_account_id(),
-            registry_roles.registry_settings_manager,
-            "Function can only be called by registry settings manager."
+        ensure!(
+            env::predecessor_account_id().eq(&registry_roles.registry_settings_manager),
+            RegistryError::CallerIsNotRegistrySettingsManager
         );
     }
     //
     fn assert_octopus_council(&self) {
         let registry_roles = self.registry_roles.get().unwrap();
-        assert!(
+        let octopus_council = registry_roles
+            .octopus_council
+            .unwrap_or_else(|| panic_with(RegistryError::OctopusCouncilNotSet));
+        ensure!(
+            octopus_council.eq(&env::predecessor_account_id()),
+            RegistryError::CallerIsNotOctopusCouncil
+        );
+    }
+    // Assert that the contract is called by an auditor.
+    fn assert_auditor(&self) {
+        let registry_roles = self.registry_roles.get().unwrap();
+        ensure!(
             registry_roles
-                .octopus_council
-                .expect("Octopus council account is not setup.")
-                .eq(&env::predecessor_account_id()),
-            "Only octopus council account can call this function."
+                .auditors
+                .contains(&env::predecessor_account_id()),
+            RegistryError::CallerIsNotAuditor
         );
     }
     // Assert that the given account has no role in this contract.
     fn assert_account_has_no_role(&self, account: &AccountId) {
         let registry_roles = self.registry_roles.get().unwrap();
-        assert!(
-            !registry_roles.has_role(&account) && !account.eq(&self.owner),
-            "The account already has role in contract."
+        ensure!(
+            !registry_roles.has_role(account) && !account.eq(&self.owner),
+            RegistryError::AccountAlreadyHasRole {
+                account_id: account.clone()
+            }
         );
     }
     // Assert that the contract is called by the owner of the given appchain.
     fn assert_appchain_owner(&self, appchain_id: &AppchainId) {
         let appchain_basedata = self.get_appchain_basedata(appchain_id);
-        assert_eq!(
-            env::predecessor_account_id(),
-            appchain_basedata.owner().clone(),
-            "Function can only be called by appchain owner."
+        ensure!(
+            env::predecessor_account_id().eq(&appchain_basedata.owner()),
+            RegistryError::CallerIsNotAppchainOwner {
+                appchain_id: appchain_id.clone()
+            }
+        );
+    }
+    // Assert that the contract is called by the anchor of the given appchain.
+    fn assert_appchain_anchor(&self, appchain_id: &AppchainId) {
+        let appchain_basedata = self.get_appchain_basedata(appchain_id);
+        ensure!(
+            appchain_basedata.anchor().is_some(),
+            RegistryError::AppchainAnchorNotSet {
+                appchain_id: appchain_id.clone()
+            }
+        );
+        ensure!(
+            self.appchain_ids_by_anchor
+                .get(&env::predecessor_account_id())
+                .map_or(false, |id| id.eq(appchain_id)),
+            RegistryError::CallerIsNotAppchainAnchor {
+                appchain_id: appchain_id.clone()
+            }
         );
     }
     // Assert that the state of the given appchain is one of the given `AppchainState`s.
     fn assert_appchain_state(&self, appchain_id: &AppchainId, appchain_states: Vec<AppchainState>) {
         let appchain_basedata = self.get_appchain_basedata(appchain_id);
-        assert!(
+        ensure!(
             appchain_states.contains(&appchain_basedata.state()),
-            "Appchain state can NOT be '{}'.",
-            appchain_basedata.state(),
+            RegistryError::InvalidAppchainState {
+                appchain_id: appchain_id.clone(),
+                state: appchain_basedata.state(),
+            }
+        );
+    }
+    // Assert that the given EVM chain id is neither reserved nor used by another appchain.
+    fn assert_evm_chain_id_is_available(&self, evm_chain_id: &U64) {
+        let registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            !registry_settings
+                .reserved_evm_chain_ids
+                .contains(evm_chain_id),
+            RegistryError::EvmChainIdReserved {
+                evm_chain_id: *evm_chain_id
+            }
         );
+        if let Some(appchain_id) = self.evm_chain_ids.get(&evm_chain_id.0) {
+            panic_with(RegistryError::EvmChainIdAlreadyUsed {
+                evm_chain_id: *evm_chain_id,
+                appchain_id,
+            });
+        }
+    }
+    // Assert that the EVM chain id is provided if and only if the template requires one.
+    fn assert_evm_chain_id_matches_template(
+        &self,
+        template: &AppchainTemplate,
+        evm_chain_id: &Option<U64>,
+    ) {
+        if template.requires_evm_chain_id {
+            ensure!(
+                evm_chain_id.is_some(),
+                RegistryError::MissingEvmChainId {
+                    template_type: template.name.clone()
+                }
+            );
+        } else {
+            ensure!(
+                evm_chain_id.is_none(),
+                RegistryError::EvmChainIdNotSupported {
+                    template_type: template.name.clone()
+                }
+            );
+        }
     }
     // Get AppchainBasedata from storage
     fn get_appchain_basedata(&self, appchain_id: &AppchainId) -> AppchainBasedata {
-        self.appchain_basedatas
-            .get(appchain_id)
-            .expect(APPCHAIN_NOT_FOUND)
+        self.appchain_basedatas.get(appchain_id).unwrap_or_else(|| {
+            panic_with(RegistryError::AppchainNotFound {
+                appchain_id: appchain_id.clone(),
+            })
+        })
+    }
+    // Get AppchainTemplate from storage
+    fn get_appchain_template(&self, template_type: &AppchainTemplateType) -> AppchainTemplate {
+        self.appchain_templates
+            .get(template_type)
+            .unwrap_or_else(|| {
+                panic_with(RegistryError::AppchainTemplateNotFound {
+                    template_type: template_type.clone(),
+                })
+            })
     }
 }
 
@@ -247,23 +555,22 @@ impl AppchainRegistry {
             &sender_id,
             msg
         );
-        assert_eq!(
-            &env::predecessor_account_id(),
-            &self.oct_token,
-            "Invalid deposit '{}' of unknown NEP-141 asset from '{}' received. Return deposit.",
-            amount.0,
-            sender_id,
+        ensure!(
+            env::predecessor_account_id().eq(&self.oct_token),
+            RegistryError::UnknownDepositToken {
+                token_id: env::predecessor_account_id()
+            }
         );
 
-        let parse_result = serde_json::from_str(msg.as_str());
-        assert!(
-            parse_result.is_ok(),
-            "Invalid msg '{}' attached in `ft_transfer_call`. Refund deposit.",
-            msg
-        );
-        let deposit_message = parse_result.unwrap();
+        let deposit_message = serde_json::from_str(msg.as_str())
+            .unwrap_or_else(|_| panic_with(RegistryError::InvalidDepositMessage { msg }));
 
         match deposit_message {
+            RegistryDepositMessage::CommitAppchainRegistration { commitment } => {
+                let unused_amount =
+                    self.commit_appchain_registration(sender_id, commitment, amount.0);
+                PromiseOrValue::Value(unused_amount.into())
+            }
             RegistryDepositMessage::RegisterAppchain {
                 appchain_id,
                 description,
@@ -279,8 +586,11 @@ impl AppchainRegistry {
                 initial_era_reward,
                 fungible_token_metadata,
                 custom_metadata,
+                voucher_id,
+                voucher_secret,
+                registration_salt,
             } => {
-                self.register_appchain(
+                let unused_amount = self.register_appchain(
                     sender_id,
                     appchain_id,
                     description,
@@ -297,12 +607,17 @@ impl AppchainRegistry {
                     initial_era_reward,
                     fungible_token_metadata,
                     custom_metadata,
+                    voucher_id,
+                    voucher_secret,
+                    registration_salt,
                 );
-                PromiseOrValue::Value(0.into())
+                PromiseOrValue::Value(unused_amount.into())
             }
         }
     }
-    //
+    // Register an appchain with the given deposit amount,
+    // and return the amount which is not used as register deposit.
+    #[allow(clippy::too_many_arguments)]
     fn register_appchain(
         &mut self,
         sender_id: AccountId,
@@ -310,7 +625,7 @@ impl AppchainRegistry {
         description: String,
         template_type: AppchainTemplateType,
         evm_chain_id: Option<U64>,
-        register_deposit: Balance,
+        deposit_amount: Balance,
         website_url: String,
         github_address: String,
         contact_email: String,
@@ -321,95 +636,165 @@ impl AppchainRegistry {
         initial_era_reward: U128,
         fungible_token_metadata: FungibleTokenMetadata,
         custom_metadata: HashMap<String, String>,
-    ) {
-        assert_ne!(
-            sender_id, self.owner,
-            "The register account should NOT be the contract owner."
-        );
-        assert!(
-            self.appchain_basedatas.get(&appchain_id).is_none(),
-            "Appchain already registered."
-        );
-        assert!(
-            register_deposit.eq(&self
-                .registry_settings
-                .get()
-                .unwrap()
-                .minimum_register_deposit
-                .0),
-            "Invalid register deposit."
-        );
-        assert!(
-            !appchain_id.trim().is_empty(),
-            "Missing necessary field 'appchain_id'."
-        );
-        assert!(appchain_id.find(".").is_none(), "Invalid 'appchain_id'.");
-        assert!(
-            appchain_id.len() <= 20,
-            "Appchain id is too long (max length is 20)."
-        );
-        assert!(
-            AccountId::try_from(format!("{}.{}", appchain_id, env::current_account_id())).is_ok(),
-            "Invalid 'appchain_id'."
-        );
-        assert!(
-            !website_url.trim().is_empty(),
-            "Missing necessary field 'website_url'."
-        );
-        assert!(
-            !github_address.trim().is_empty(),
-            "Missing necessary field 'github_address'."
-        );
-        assert!(
-            !contact_email.trim().is_empty(),
-            "Missing necessary field 'contact_email'."
-        );
-        fungible_token_metadata.assert_valid();
-        assert!(
-            !fungible_token_metadata.name.trim().is_empty(),
-            "Missing necessary field 'fungible token name'."
-        );
-        assert!(
-            !fungible_token_metadata.symbol.trim().is_empty(),
-            "Missing necessary field 'fungible token symbol'."
-        );
-        assert!(
-            initial_supply_of_wrapped_appchain_token.0 >= premined_wrapped_appchain_token.0,
-            "The initial supply of wrapped appchain token should not be less than the premined amount."
+        voucher_id: Option<String>,
+        voucher_secret: Option<String>,
+        registration_salt: Option<String>,
+    ) -> Balance {
+        ensure!(
+            !sender_id.eq(&self.owner),
+            RegistryError::OwnerCanNotRegisterAppchain
+        );
+        match self.internal_check_appchain_id_availability(&appchain_id, Some(&sender_id)) {
+            AppchainIdAvailability::Available => (),
+            availability => panic_with(RegistryError::AppchainIdNotAvailable {
+                appchain_id,
+                availability,
+            }),
+        }
+        let template = self.get_appchain_template(&template_type);
+        ensure!(
+            template.is_active,
+            RegistryError::AppchainTemplateNotActive {
+                template_type: template_type.clone()
+            }
         );
+        self.assert_evm_chain_id_matches_template(&template, &evm_chain_id);
+        if let Some(evm_chain_id) = evm_chain_id {
+            self.assert_evm_chain_id_is_available(&evm_chain_id);
+        }
+        let mut voucher: Option<RegisterDepositVoucher> = None;
+        let mut register_deposit = template.register_deposit.0;
+        if let Some(voucher_id) = voucher_id {
+            let register_deposit_voucher = self
+                .register_deposit_vouchers
+                .get(&voucher_id)
+                .unwrap_or_else(|| {
+                    panic_with(RegistryError::RegisterDepositVoucherNotFound {
+                        voucher_id: voucher_id.clone(),
+                    })
+                });
+            ensure!(
+                register_deposit_voucher.used_count < register_deposit_voucher.max_uses,
+                RegistryError::RegisterDepositVoucherUsedUp {
+                    voucher_id: voucher_id.clone()
+                }
+            );
+            ensure!(
+                register_deposit_voucher.can_be_redeemed_by(&sender_id, &voucher_secret),
+                RegistryError::RegisterDepositVoucherNotRedeemable {
+                    voucher_id: voucher_id.clone(),
+                    account_id: sender_id.clone(),
+                }
+            );
+            // The secret is exposed in the message, so it is only accepted in revealing
+            // a commitment which is bound to the secret and the sender.
+            if let RegisterDepositVoucherHolder::SecretHash(_) = register_deposit_voucher.holder {
+                ensure!(
+                    registration_salt.is_some(),
+                    RegistryError::RegistrationCommitmentRequired
+                );
+            }
+            register_deposit = register_deposit_voucher.discounted_amount_of(register_deposit);
+            voucher = Some(register_deposit_voucher);
+        }
+        let commitment_deposit = match registration_salt {
+            Some(salt) => self.reveal_registration_commitment(
+                &sender_id,
+                &appchain_id,
+                &salt,
+                &voucher_secret,
+            ),
+            None => {
+                ensure!(
+                    !self
+                        .registry_settings
+                        .get()
+                        .unwrap()
+                        .registration_commitment_required,
+                    RegistryError::RegistrationCommitmentRequired
+                );
+                0
+            }
+        };
+        ensure!(
+            deposit_amount + commitment_deposit >= register_deposit,
+            RegistryError::NotEnoughRegisterDeposit {
+                required_amount: U128::from(register_deposit.saturating_sub(commitment_deposit))
+            }
+        );
+        let appchain_metadata = AppchainMetadata {
+            description,
+            template_type,
+            website_url,
+            function_spec_url: String::new(),
+            github_address,
+            github_release: String::new(),
+            contact_email,
+            premined_wrapped_appchain_token_beneficiary: Some(
+                premined_wrapped_appchain_token_beneficiary,
+            ),
+            premined_wrapped_appchain_token,
+            initial_supply_of_wrapped_appchain_token,
+            ido_amount_of_wrapped_appchain_token,
+            initial_era_reward,
+            fungible_token_metadata,
+            custom_metadata,
+        };
+        appchain_metadata.assert_valid();
         //
+        let initial_storage_usage = env::storage_usage();
         let appchain_basedata = AppchainBasedata::new(
             appchain_id.clone(),
             evm_chain_id,
-            AppchainMetadata {
-                description,
-                template_type,
-                website_url,
-                function_spec_url: String::new(),
-                github_address,
-                github_release: String::new(),
-                contact_email,
-                premined_wrapped_appchain_token_beneficiary: Some(
-                    premined_wrapped_appchain_token_beneficiary,
-                ),
-                premined_wrapped_appchain_token,
-                initial_supply_of_wrapped_appchain_token,
-                ido_amount_of_wrapped_appchain_token,
-                initial_era_reward,
-                fungible_token_metadata,
-                custom_metadata,
-            },
+            appchain_metadata,
             sender_id,
             register_deposit,
         );
         self.appchain_ids.insert(&appchain_id);
+        self.normalized_appchain_ids
+            .insert(&normalize_appchain_id(&appchain_id), &appchain_id);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        if let Some(evm_chain_id) = appchain_basedata.evm_chain_id {
+            self.evm_chain_ids.insert(&evm_chain_id.0, &appchain_id);
+        }
+        self.internal_start_metadata_revisions_of(&appchain_id);
+        // Write the initial voting score, so that the storage of it is charged in registration.
+        appchain_basedata.count_voting_score();
+        self.internal_update_sorting_indexes(
+            &appchain_id,
+            None,
+            Some(appchain_basedata.sorting_keys()),
+        );
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::Registered);
+        self.internal_update_statistics(None, Some(appchain_basedata.statistics_values()));
+        self.internal_update_owner_statistics(None, Some(&appchain_basedata.owner()));
+        self.total_register_deposit += register_deposit;
+        self.internal_charge_appchain_storage(&appchain_basedata, initial_storage_usage);
         log!(
             "Appchain '{}' is registered by '{}'.",
             appchain_basedata.id(),
             appchain_basedata.owner()
         );
+        if let Some(mut voucher) = voucher {
+            voucher.used_count += 1;
+            self.register_deposit_vouchers
+                .insert(&voucher.voucher_id, &voucher);
+            log!(
+                "Register deposit voucher '{}' is redeemed by '{}'.",
+                voucher.voucher_id,
+                appchain_basedata.owner()
+            );
+        }
+        let unused_amount = deposit_amount + commitment_deposit - register_deposit;
+        if unused_amount > deposit_amount {
+            self.refund_registration_commitment_deposit(
+                appchain_basedata.owner().clone(),
+                unused_amount - deposit_amount,
+            );
+            return deposit_amount;
+        }
+        unused_amount
     }
     //
     pub fn call_anchor_function(
@@ -426,16 +811,18 @@ impl AppchainRegistry {
         //
         let anchor_account_id =
             AccountId::try_from(format!("{}.{}", &appchain_id, env::current_account_id())).unwrap();
+        let registry_settings = self.registry_settings.get().unwrap();
         Promise::new(anchor_account_id).function_call(
             function_name,
             args.into_bytes(),
             0,
-            Gas::ONE_TERA * T_GAS_FOR_CALLING_ANCHOR_FUNCTION,
+            Gas::ONE_TERA * registry_settings.t_gas_for_calling_anchor_function,
         );
     }
 }
 
 #[near_bindgen]
+#[allow(deprecated)]
 impl Ownable for AppchainRegistry {
     //
     fn get_owner(&self) -> AccountId {
@@ -444,19 +831,65 @@ impl Ownable for AppchainRegistry {
     //
     fn set_owner(&mut self, owner: AccountId) {
         self.assert_owner();
-        assert_ne!(owner, self.owner, "The account is the same.");
+        ensure!(
+            !owner.eq(&self.owner),
+            RegistryError::FieldNotChanged {
+                field: "owner".to_string()
+            }
+        );
         self.owner = owner;
     }
 }
 
 impl AppchainRegistry {
-    ///
+    /// Set the anchor account of an appchain, and move the appchain in the index of
+    /// appchain ids by anchor. The basedata is NOT saved in this function.
+    pub fn internal_set_appchain_anchor(
+        &mut self,
+        appchain_basedata: &mut AppchainBasedata,
+        anchor: AccountId,
+    ) {
+        if let Some(previous_anchor) = appchain_basedata.anchor() {
+            self.appchain_ids_by_anchor.remove(&previous_anchor);
+        }
+        self.appchain_ids_by_anchor
+            .insert(&anchor, appchain_basedata.id());
+        appchain_basedata.set_anchor_account(anchor);
+    }
+    //
     fn internal_remove_appchain(&mut self, appchain_id: &AppchainId) {
+        let appchain_basedata = self.get_appchain_basedata(appchain_id);
+        if let Some(evm_chain_id) = appchain_basedata.evm_chain_id {
+            self.evm_chain_ids.remove(&evm_chain_id.0);
+        }
+        self.internal_remove_metadata_change_requests_of(appchain_id);
+        self.internal_record_review(
+            appchain_id,
+            AppchainReviewAction::Removed,
+            None,
+            appchain_basedata.state(),
+        );
+        self.internal_update_sorting_indexes(
+            appchain_id,
+            Some(appchain_basedata.sorting_keys()),
+            None,
+        );
+        self.internal_set_appchain_tags(&appchain_basedata, Vec::new());
+        self.internal_update_statistics(Some(appchain_basedata.statistics_values()), None);
+        self.internal_update_owner_statistics(Some(&appchain_basedata.owner()), None);
+        if let Some(anchor) = appchain_basedata.anchor() {
+            self.appchain_ids_by_anchor.remove(&anchor);
+        }
+        self.appchain_tag_proposals.remove(appchain_id);
+        self.internal_release_appchain_storage(appchain_id, &appchain_basedata.owner());
         env::storage_remove(&StorageKey::AppchainAnchorCode(appchain_id.clone()).into_bytes());
         env::storage_remove(&StorageKey::AppchainMetadata(appchain_id.clone()).into_bytes());
         env::storage_remove(&StorageKey::AppchainVotingScore(appchain_id.clone()).into_bytes());
-        self.appchain_ids.remove(&appchain_id);
-        self.appchain_basedatas.remove(&appchain_id);
+        self.appchain_ids.remove(appchain_id);
+        self.normalized_appchain_ids
+            .remove(&normalize_appchain_id(appchain_id));
+        self.appchain_basedatas.remove(appchain_id);
+        self.internal_record_appchain_change(appchain_id, AppchainChangeKind::Removed);
     }
 }
 
@@ -470,26 +903,77 @@ impl AppchainAnchorCallback for AppchainRegistry {
         validator_count: u32,
         total_stake: U128,
     ) {
-        let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
-        assert!(
-            appchain_basedata.anchor().is_some(),
-            "Anchor of appchain {} is not set.",
-            appchain_id
+        self.internal_sync_state_of(
+            &appchain_id,
+            appchain_state,
+            validator_count,
+            total_stake.0,
+            None,
         );
-        assert_eq!(
-            env::predecessor_account_id(),
-            appchain_basedata
-                .anchor()
-                .unwrap_or(AccountId::new_unchecked(String::new())),
-            "Only appchain anchor can call this function."
+    }
+    //
+    fn sync_state_and_metrics_of(
+        &mut self,
+        appchain_id: AppchainId,
+        appchain_state: AppchainState,
+        validator_count: u32,
+        total_stake: U128,
+        metrics: AppchainSyncMetrics,
+    ) {
+        self.internal_sync_state_of(
+            &appchain_id,
+            appchain_state,
+            validator_count,
+            total_stake.0,
+            Some(metrics),
         );
-        assert!(
+    }
+}
+
+impl AppchainRegistry {
+    //
+    fn internal_sync_state_of(
+        &mut self,
+        appchain_id: &AppchainId,
+        appchain_state: AppchainState,
+        validator_count: u32,
+        total_stake: Balance,
+        metrics: Option<AppchainSyncMetrics>,
+    ) {
+        self.assert_appchain_anchor(appchain_id);
+        let mut appchain_basedata = self.get_appchain_basedata(appchain_id);
+        ensure!(
             appchain_state.is_managed_by_anchor(),
-            "Invalid state to sync."
+            RegistryError::InvalidStateToSync {
+                state: appchain_state
+            }
         );
+        let previous_sorting_keys = appchain_basedata.sorting_keys();
+        let previous_statistics_values = appchain_basedata.statistics_values();
+        let was_stale = appchain_basedata.health.eq(&AppchainHealth::Stale);
         appchain_basedata.set_state(appchain_state);
-        appchain_basedata.sync_staking_status(validator_count, total_stake.0);
+        appchain_basedata.sync_staking_status(validator_count, total_stake);
+        if let Some(metrics) = metrics {
+            appchain_basedata.sync_metrics(metrics);
+        }
         self.appchain_basedatas
-            .insert(&appchain_id, &appchain_basedata);
+            .insert(appchain_id, &appchain_basedata);
+        self.internal_update_sorting_indexes(
+            appchain_id,
+            Some(previous_sorting_keys),
+            Some(appchain_basedata.sorting_keys()),
+        );
+        // The sync clears the stale flag, which is recorded as a change of health.
+        self.internal_record_appchain_change(
+            appchain_id,
+            match was_stale {
+                true => AppchainChangeKind::HealthChanged,
+                false => AppchainChangeKind::AnchorSynced,
+            },
+        );
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
     }
 }
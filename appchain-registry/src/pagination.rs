@@ -0,0 +1,83 @@
+use crate::*;
+use types::PagedResult;
+
+/// The number of items in a page of listing views, if the limit is not specified
+const DEFAULT_PAGE_LIMIT: u32 = 20;
+/// The max number of items in a page of listing views
+const MAX_PAGE_LIMIT: u32 = 100;
+
+impl<T> PagedResult<T> {
+    /// Take a page of at most `limit` items, starting at `from_index`,
+    /// from the given items which are `total_count` in all.
+    ///
+    /// The items before `from_index` are skipped by `Iterator::nth`, which doesn't
+    /// read them from storage if the iterator is over a `Vector` (or the `UnorderedMap`
+    /// and `UnorderedSet` backed by it) of `near_sdk`. A `TreeMap` iterator still reads
+    /// the keys before `from_index` one by one, so a page of an ordered index costs
+    /// O(from_index) reads. To load the items of a page from storage, take the page
+    /// of their keys and `map` it.
+    pub fn from_iter<I: Iterator<Item = T>>(
+        items: I,
+        total_count: u64,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> Self {
+        let limit = assert_valid_page_limit(limit);
+        let from_index = from_index.map_or(0, |index| index.0);
+        let items: Vec<T> = match from_index < total_count {
+            true => items
+                .skip(from_index as usize)
+                .take(limit as usize)
+                .collect(),
+            false => Vec::new(),
+        };
+        let end_index = from_index + items.len() as u64;
+        Self {
+            items,
+            next_index: match end_index < total_count {
+                true => Some(U64::from(end_index)),
+                false => None,
+            },
+            total_count: U64::from(total_count),
+        }
+    }
+    /// Take a page of at most `limit` items, starting at `from_index`, from the given vector.
+    pub fn from_vec(items: Vec<T>, from_index: Option<U64>, limit: Option<u32>) -> Self {
+        let total_count = items.len() as u64;
+        Self::from_iter(items.into_iter(), total_count, from_index, limit)
+    }
+    /// Map the items in this page, without changing the position of the page.
+    pub fn map<R, F: FnMut(T) -> R>(self, f: F) -> PagedResult<R> {
+        PagedResult {
+            items: self.items.into_iter().map(f).collect(),
+            next_index: self.next_index,
+            total_count: self.total_count,
+        }
+    }
+}
+
+/// Assert the number of the keys to look up in a batch view is not greater than
+/// the max number of items in a page.
+pub fn assert_valid_batch_size(name: &str, count: usize) {
+    ensure!(
+        count <= MAX_PAGE_LIMIT as usize,
+        RegistryError::InvalidArgument {
+            name: name.to_string(),
+            reason: format!("At most {} items can be looked up at once.", MAX_PAGE_LIMIT),
+        }
+    );
+}
+
+/// Assert the limit of a listing view is in range, and return the limit
+/// (or the default one if it is not specified).
+pub fn assert_valid_page_limit(limit: Option<u32>) -> u32 {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+    ensure!(
+        limit > 0 && limit <= MAX_PAGE_LIMIT,
+        RegistryError::InvalidArgument {
+            name: "limit".to_string(),
+            reason: format!("The limit should be in range [1, {}].", MAX_PAGE_LIMIT),
+        }
+    );
+    limit
+}
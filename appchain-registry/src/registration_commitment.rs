@@ -0,0 +1,205 @@
+use crate::*;
+use near_contract_standards::fungible_token::core::ext_ft_core;
+use std::ops::Mul;
+use types::RegistrationCommitment;
+
+#[ext_contract(ext_registration_commitment_resolver)]
+pub trait RegistrationCommitmentResolver {
+    /// Resolver for refunding the part of registration commitment deposit
+    fn resolve_refund_registration_commitment_deposit(
+        &mut self,
+        account_id: AccountId,
+        amount: U128,
+    );
+}
+
+/// Compute the commitment of an appchain registration, which is the sha256 hash (in hex)
+/// of `{appchain_id}:{salt}:{account_id}`, or `{appchain_id}:{salt}:{account_id}:{voucher_secret}`
+/// if the registration has a voucher secret.
+pub fn compute_registration_commitment(
+    appchain_id: &AppchainId,
+    salt: &String,
+    account_id: &AccountId,
+    voucher_secret: &Option<String>,
+) -> String {
+    let preimage = match voucher_secret {
+        Some(voucher_secret) => {
+            format!("{}:{}:{}:{}", appchain_id, salt, account_id, voucher_secret)
+        }
+        None => format!("{}:{}:{}", appchain_id, salt, account_id),
+    };
+    hex::encode(env::sha256(preimage.as_bytes()))
+}
+
+impl AppchainRegistry {
+    // Store a registration commitment with the given deposit amount,
+    // and return the amount which is not used as commitment deposit.
+    pub fn commit_appchain_registration(
+        &mut self,
+        sender_id: AccountId,
+        commitment: String,
+        deposit_amount: Balance,
+    ) -> Balance {
+        ensure!(
+            hex::decode(&commitment).map_or(false, |bytes| bytes.len() == 32)
+                && commitment.eq(&commitment.to_lowercase()),
+            RegistryError::InvalidArgument {
+                name: "commitment".to_string(),
+                reason: "The commitment should be a sha256 hash in lowercase hex.".to_string(),
+            }
+        );
+        ensure!(
+            self.registration_commitments.get(&commitment).is_none(),
+            RegistryError::RegistrationCommitmentAlreadyExists
+        );
+        let registry_settings = self.registry_settings.get().unwrap();
+        let commitment_deposit = registry_settings.registration_commitment_deposit.0;
+        ensure!(
+            deposit_amount >= commitment_deposit,
+            RegistryError::NotEnoughRegisterDeposit {
+                required_amount: U128::from(commitment_deposit)
+            }
+        );
+        self.registration_commitments.insert(
+            &commitment,
+            &RegistrationCommitment {
+                commitment: commitment.clone(),
+                account_id: sender_id.clone(),
+                deposit: U128::from(commitment_deposit),
+                committed_block_height: U64::from(env::block_height()),
+                committed_timestamp: U64::from(env::block_timestamp()),
+            },
+        );
+        log!(
+            "Registration commitment '{}' is stored by '{}'.",
+            commitment,
+            sender_id
+        );
+        deposit_amount - commitment_deposit
+    }
+    // Remove the registration commitment matching the given registration,
+    // and return the deposit of the commitment.
+    pub fn reveal_registration_commitment(
+        &mut self,
+        sender_id: &AccountId,
+        appchain_id: &AppchainId,
+        salt: &String,
+        voucher_secret: &Option<String>,
+    ) -> Balance {
+        let commitment =
+            compute_registration_commitment(appchain_id, salt, sender_id, voucher_secret);
+        let registration_commitment = self
+            .registration_commitments
+            .get(&commitment)
+            .unwrap_or_else(|| panic_with(RegistryError::RegistrationCommitmentNotFound));
+        ensure!(
+            registration_commitment.committed_block_height.0 < env::block_height(),
+            RegistryError::RegistrationRevealedInCommittedBlock
+        );
+        ensure!(
+            !self.registration_commitment_is_expired(&registration_commitment),
+            RegistryError::RegistrationCommitmentExpired
+        );
+        self.registration_commitments.remove(&commitment);
+        registration_commitment.deposit.0
+    }
+    // Whether the reveal window of the given registration commitment has passed.
+    fn registration_commitment_is_expired(
+        &self,
+        registration_commitment: &RegistrationCommitment,
+    ) -> bool {
+        let registry_settings = self.registry_settings.get().unwrap();
+        env::block_timestamp()
+            > registration_commitment.committed_timestamp.0
+                + registry_settings.registration_reveal_window_in_secs * NANO_SECONDS_MULTIPLE
+    }
+    // Refund the part of commitment deposit which exceeds the register deposit.
+    pub fn refund_registration_commitment_deposit(&self, account_id: AccountId, amount: Balance) {
+        let registry_settings = self.registry_settings.get().unwrap();
+        ext_ft_core::ext(self.oct_token.clone())
+            .with_attached_deposit(1)
+            .with_static_gas(Gas::ONE_TERA.mul(registry_settings.t_gas_for_ft_transfer))
+            .with_unused_gas_weight(0)
+            .ft_transfer(account_id.clone(), amount.into(), None)
+            .then(
+                ext_registration_commitment_resolver::ext(env::current_account_id())
+                    .with_static_gas(
+                        Gas::ONE_TERA.mul(registry_settings.t_gas_for_resolver_function),
+                    )
+                    .with_unused_gas_weight(0)
+                    .resolve_refund_registration_commitment_deposit(account_id, amount.into()),
+            );
+    }
+}
+
+#[near_bindgen]
+impl AppchainRegistry {
+    /// Remove an expired registration commitment, the deposit of which is forfeited.
+    /// Can be called by anyone.
+    pub fn remove_expired_registration_commitment(&mut self, commitment: String) {
+        let registration_commitment = self
+            .registration_commitments
+            .get(&commitment)
+            .unwrap_or_else(|| panic_with(RegistryError::RegistrationCommitmentNotFound));
+        ensure!(
+            self.registration_commitment_is_expired(&registration_commitment),
+            RegistryError::RegistrationCommitmentNotExpired
+        );
+        self.registration_commitments.remove(&commitment);
+        log!(
+            "Expired registration commitment '{}' of '{}' is removed, deposit {} is forfeited.",
+            commitment,
+            registration_commitment.account_id,
+            registration_commitment.deposit.0
+        );
+    }
+    /// Withdraw the registration commitment deposit which is failed to be refunded
+    /// to the caller.
+    pub fn withdraw_unrefunded_commitment_deposit(&mut self) {
+        self.assert_asset_transfer_is_not_paused();
+        let account_id = env::predecessor_account_id();
+        let amount = self
+            .unrefunded_commitment_deposits
+            .remove(&account_id)
+            .unwrap_or_else(|| {
+                panic_with(RegistryError::UnrefundedCommitmentDepositNotFound {
+                    account_id: account_id.clone(),
+                })
+            });
+        self.refund_registration_commitment_deposit(account_id, amount);
+    }
+}
+
+#[near_bindgen]
+impl RegistrationCommitmentResolver for AppchainRegistry {
+    //
+    fn resolve_refund_registration_commitment_deposit(
+        &mut self,
+        account_id: AccountId,
+        amount: U128,
+    ) {
+        assert_self();
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => log!(
+                "Registration commitment deposit refunded to '{}'. Amount: '{}'",
+                &account_id,
+                &amount.0
+            ),
+            // The deposit is kept for the account, and can be withdrawn by the account later.
+            PromiseResult::Failed => {
+                let unrefunded_deposit = self
+                    .unrefunded_commitment_deposits
+                    .get(&account_id)
+                    .unwrap_or(0);
+                self.unrefunded_commitment_deposits
+                    .insert(&account_id, &(unrefunded_deposit + amount.0));
+                log!(
+                    "Failed to refund registration commitment deposit to '{}'. Amount: '{}'",
+                    &account_id,
+                    &amount.0
+                );
+            }
+        }
+    }
+}
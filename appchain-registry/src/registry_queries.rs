@@ -0,0 +1,51 @@
+use crate::{
+    types::{VersionedAppchainAnchor, VersionedAppchainOwner},
+    *,
+};
+
+/// The interface for other contracts to query the appchains in registry
+///
+/// The results do NOT contain the metadata of appchains, and an appchain which is
+/// not registered results in `false` or `None` rather than a failure, so that the
+/// callbacks of cross-contract calls can handle it without checking promise failures.
+/// Other contracts can call these functions by `ext_appchain_registry`.
+#[ext_contract(ext_appchain_registry)]
+pub trait RegistryQueries {
+    /// Get whether an appchain is in the given state
+    fn is_appchain_in_state(&self, appchain_id: AppchainId, appchain_state: AppchainState) -> bool;
+    /// Get the owner of an appchain
+    fn get_appchain_owner(&self, appchain_id: AppchainId) -> Option<VersionedAppchainOwner>;
+    /// Get the anchor account of an appchain
+    fn get_appchain_anchor(&self, appchain_id: AppchainId) -> Option<VersionedAppchainAnchor>;
+}
+
+#[near_bindgen]
+impl RegistryQueries for AppchainRegistry {
+    //
+    fn is_appchain_in_state(&self, appchain_id: AppchainId, appchain_state: AppchainState) -> bool {
+        self.appchain_basedatas
+            .get(&appchain_id)
+            .map_or(false, |appchain_basedata| {
+                appchain_basedata.state().eq(&appchain_state)
+            })
+    }
+    //
+    fn get_appchain_owner(&self, appchain_id: AppchainId) -> Option<VersionedAppchainOwner> {
+        self.appchain_basedatas
+            .get(&appchain_id)
+            .map(|appchain_basedata| VersionedAppchainOwner::V1 {
+                appchain_id,
+                owner: appchain_basedata.owner(),
+            })
+    }
+    //
+    fn get_appchain_anchor(&self, appchain_id: AppchainId) -> Option<VersionedAppchainAnchor> {
+        self.appchain_basedatas
+            .get(&appchain_id)
+            .map(|appchain_basedata| VersionedAppchainAnchor::V1 {
+                appchain_id,
+                appchain_state: appchain_basedata.state(),
+                anchor: appchain_basedata.anchor(),
+            })
+    }
+}
@@ -0,0 +1,162 @@
+use crate::*;
+use types::{AppchainStateStatistics, RegistryStatistics};
+
+/// All of the states of appchains, in the order of lifecycle
+const APPCHAIN_STATES: [AppchainState; 7] = [
+    AppchainState::Registered,
+    AppchainState::Audited,
+    AppchainState::Voting,
+    AppchainState::Booting,
+    AppchainState::Active,
+    AppchainState::Closing,
+    AppchainState::Closed,
+];
+
+/// The values of an appchain which are summed in the statistics of its state
+pub struct AppchainStatisticsValues {
+    pub appchain_state: AppchainState,
+    pub upvote_deposit: Balance,
+    pub downvote_deposit: Balance,
+    pub total_stake: Balance,
+    pub validator_count: u32,
+}
+
+impl AppchainBasedata {
+    /// Get the values of the appchain which are summed in the statistics of its state
+    pub fn statistics_values(&self) -> AppchainStatisticsValues {
+        AppchainStatisticsValues {
+            appchain_state: self.appchain_state.clone(),
+            upvote_deposit: self.upvote_deposit,
+            downvote_deposit: self.downvote_deposit,
+            total_stake: self.total_stake,
+            validator_count: self.validator_count,
+        }
+    }
+}
+
+impl AppchainStateStatistics {
+    /// Get the empty statistics of the given state
+    fn new(appchain_state: AppchainState) -> Self {
+        Self {
+            appchain_state,
+            appchain_count: 0,
+            upvote_deposit: U128::from(0),
+            downvote_deposit: U128::from(0),
+            total_stake: U128::from(0),
+            validator_count: U64::from(0),
+        }
+    }
+    /// Count an appchain with the given values in the statistics
+    fn add(&mut self, values: &AppchainStatisticsValues) {
+        self.appchain_count += 1;
+        self.upvote_deposit.0 += values.upvote_deposit;
+        self.downvote_deposit.0 += values.downvote_deposit;
+        self.total_stake.0 += values.total_stake;
+        self.validator_count.0 += u64::from(values.validator_count);
+    }
+    /// Remove an appchain with the given values from the statistics
+    fn subtract(&mut self, values: &AppchainStatisticsValues) {
+        self.appchain_count -= 1;
+        self.upvote_deposit.0 -= values.upvote_deposit;
+        self.downvote_deposit.0 -= values.downvote_deposit;
+        self.total_stake.0 -= values.total_stake;
+        self.validator_count.0 -= u64::from(values.validator_count);
+    }
+}
+
+impl AppchainRegistry {
+    /// Move an appchain in the statistics of appchain states from the `previous` values
+    /// to the `current` values. `None` means the appchain is not (or no longer) counted.
+    pub fn internal_update_statistics(
+        &mut self,
+        previous: Option<AppchainStatisticsValues>,
+        current: Option<AppchainStatisticsValues>,
+    ) {
+        if let Some(values) = previous {
+            let mut statistics = self.get_state_statistics(&values.appchain_state);
+            statistics.subtract(&values);
+            self.appchain_state_statistics
+                .insert(&values.appchain_state, &statistics);
+        }
+        if let Some(values) = current {
+            let mut statistics = self.get_state_statistics(&values.appchain_state);
+            statistics.add(&values);
+            self.appchain_state_statistics
+                .insert(&values.appchain_state, &statistics);
+        }
+    }
+    /// Move an appchain from the `previous` owner to the `current` owner,
+    /// and count the distinct owners of appchains.
+    pub fn internal_update_owner_statistics(
+        &mut self,
+        previous: Option<&AccountId>,
+        current: Option<&AccountId>,
+    ) {
+        if let Some(owner) = previous {
+            let count = self.owner_appchain_counts.get(owner).unwrap_or(0);
+            if count <= 1 {
+                self.owner_appchain_counts.remove(owner);
+                self.distinct_owner_count -= 1;
+            } else {
+                self.owner_appchain_counts.insert(owner, &(count - 1));
+            }
+        }
+        if let Some(owner) = current {
+            let count = self.owner_appchain_counts.get(owner).unwrap_or(0);
+            if count == 0 {
+                self.distinct_owner_count += 1;
+            }
+            self.owner_appchain_counts.insert(owner, &(count + 1));
+        }
+    }
+    /// Count or uncount a vote deposit of the `voter`, by whether the voter `had_deposit`
+    /// before the change and `has_deposit` after the change, and count the distinct voters.
+    pub fn internal_update_voter_statistics(
+        &mut self,
+        voter: &AccountId,
+        had_deposit: bool,
+        has_deposit: bool,
+    ) {
+        let count = self.voter_deposit_counts.get(voter).unwrap_or(0);
+        match (had_deposit, has_deposit) {
+            (true, false) => {
+                // The voters with legacy deposits which are not counted in the migration
+                // are not counted in the statistics either.
+                if count == 0 {
+                    return;
+                }
+                if count == 1 {
+                    self.voter_deposit_counts.remove(voter);
+                    self.distinct_voter_count -= 1;
+                } else {
+                    self.voter_deposit_counts.insert(voter, &(count - 1));
+                }
+            }
+            (false, true) => {
+                if count == 0 {
+                    self.distinct_voter_count += 1;
+                }
+                self.voter_deposit_counts.insert(voter, &(count + 1));
+            }
+            _ => (),
+        }
+    }
+    /// Get the statistics of the appchains in the given state
+    pub fn get_state_statistics(&self, appchain_state: &AppchainState) -> AppchainStateStatistics {
+        self.appchain_state_statistics
+            .get(appchain_state)
+            .unwrap_or_else(|| AppchainStateStatistics::new(appchain_state.clone()))
+    }
+    /// Get the statistics of the registry
+    pub fn get_statistics(&self) -> RegistryStatistics {
+        RegistryStatistics {
+            appchain_states: APPCHAIN_STATES
+                .iter()
+                .map(|appchain_state| self.get_state_statistics(appchain_state))
+                .collect(),
+            total_register_deposit: U128::from(self.total_register_deposit),
+            distinct_owner_count: U64::from(self.distinct_owner_count),
+            distinct_voter_count: U64::from(self.distinct_voter_count),
+        }
+    }
+}
@@ -1,10 +1,23 @@
 use crate::{
-    types::{AppchainSortingField, AppchainState, AppchainStatus, SortingOrder},
+    types::{
+        AppchainAuditRecord, AppchainChangeFeed, AppchainFilter, AppchainIdAvailability,
+        AppchainLiveness, AppchainLookupResult, AppchainMetadataChangeRequest,
+        AppchainMetadataRevision, AppchainMetadataSnapshot, AppchainReviewRecord,
+        AppchainSortingSpec, AppchainState, AppchainStatus, AppchainStorageBalance,
+        AppchainSummary, AppchainTagProposal, PagedResult, RegistrationCommitment,
+        RegistryStatistics, ReservedAppchainId,
+    },
     *,
 };
 use near_sdk::json_types::U64;
+use pagination::{assert_valid_batch_size, assert_valid_page_limit};
 
 /// The interface for querying status of appchain registry
+///
+/// All listing views return a `PagedResult`, which contains at most `limit` items
+/// starting at `from_index`, the index to query the next page from and the total count
+/// of the matched items. The `from_index` defaults to `0` and the `limit` defaults to `20`,
+/// which can NOT be greater than `100`.
 pub trait RegistryStatus {
     /// Show the version of current contract.
     fn version(&self) -> String;
@@ -16,30 +29,192 @@ pub trait RegistryStatus {
     fn get_registry_settings(&self) -> RegistrySettings;
     /// Get registry roles
     fn get_registry_roles(&self) -> RegistryRoles;
+    /// Get appchain templates
+    fn get_appchain_templates(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainTemplate>;
+    /// Get appchain template of the given template type
+    fn get_appchain_template_of(
+        &self,
+        template_type: AppchainTemplateType,
+    ) -> Option<AppchainTemplate>;
+    /// Get register deposit vouchers
+    fn get_register_deposit_vouchers(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<RegisterDepositVoucher>;
+    /// Get register deposit voucher of the given voucher id
+    fn get_register_deposit_voucher_of(&self, voucher_id: String)
+        -> Option<RegisterDepositVoucher>;
+    /// Get reserved appchain ids
+    fn get_reserved_appchain_ids(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<ReservedAppchainId>;
+    /// Check whether the given appchain id can be registered (by the given account),
+    /// and the reason if it can NOT
+    fn check_appchain_id_availability(
+        &self,
+        appchain_id: AppchainId,
+        account_id: Option<AccountId>,
+    ) -> AppchainIdAvailability;
+    /// Compute the commitment hash for committing an appchain registration.
+    /// The `voucher_secret` is needed if the registration has one (to redeem a voucher bound
+    /// to a secret hash), the commitment can also be computed off chain to keep the secret private
+    fn compute_registration_commitment(
+        &self,
+        appchain_id: AppchainId,
+        salt: String,
+        account_id: AccountId,
+        voucher_secret: Option<String>,
+    ) -> String;
+    /// Get registration commitments which are not revealed or removed
+    fn get_registration_commitments(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<RegistrationCommitment>;
+    /// Get the registration commitment of the given commitment hash
+    fn get_registration_commitment_of(&self, commitment: String) -> Option<RegistrationCommitment>;
+    /// Get the registration commitment deposit which is failed to be refunded to the given account
+    fn get_unrefunded_commitment_deposit_of(&self, account_id: AccountId) -> U128;
+    /// Get the id of the appchain which uses the given EVM chain id
+    fn get_appchain_id_of_evm_chain_id(&self, evm_chain_id: U64) -> Option<AppchainId>;
+    /// Get the id of the appchain which the given anchor account belongs to
+    fn get_appchain_id_of_anchor(&self, anchor_account: AccountId) -> Option<AppchainId>;
+    /// Get the booting or active appchains whose anchors have not synced within
+    /// `anchor staleness threshold` of registry settings
+    fn get_stale_appchains(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainLiveness>;
     /// Get total stake of all appchains in 'staging', 'booting' and 'active' state
     fn get_total_stake(&self) -> U128;
+    /// Get the statistics of the registry, including the count, the sums of vote deposits,
+    /// total stake and validator count of the appchains in each state
+    fn get_registry_statistics(&self) -> RegistryStatistics;
     /// Get appchain ids
-    fn get_appchain_ids(&self) -> Vec<String>;
+    fn get_appchain_ids(&self, from_index: Option<U64>, limit: Option<u32>) -> PagedResult<String>;
     /// Get appchains whose state is equal to the given AppchainState
     /// If param `appchain_state` is `Option::None`, return all appchains in registry
+    ///
+    /// If param `filter` is given, only return the appchains which meet all of its conditions.
+    ///
+    /// The appchains are sorted by the specs in `sorting` in turn, and the appchains which
+    /// are equal in all of the specs are ordered by appchain id, in the order of the last spec.
+    /// Sorting by `TotalStake` or `VotingScore` only is backed by an ordered index.
     fn get_appchains_with_state_of(
         &self,
         appchain_state: Option<Vec<AppchainState>>,
-        page_number: u16,
-        page_size: u16,
-        sorting_field: AppchainSortingField,
-        sorting_order: SortingOrder,
-    ) -> Vec<AppchainStatus>;
+        filter: Option<AppchainFilter>,
+        sorting: Vec<AppchainSortingSpec>,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainStatus>;
     /// Get appchains count whose state is equal to the given AppchainState
     ///
     /// If param `appchain_state` is `Option::None`, return count of all appchains in registry
     fn get_appchains_count_of(&self, appchain_state: Option<AppchainState>) -> U64;
     /// Get status of an appchain
     fn get_appchain_status_of(&self, appchain_id: AppchainId) -> AppchainStatus;
+    /// Get status of the given appchains (at most `100`), in the order of the given ids.
+    /// The result of an appchain which is not registered contains no item.
+    fn get_appchain_statuses(
+        &self,
+        appchain_ids: Vec<AppchainId>,
+    ) -> Vec<AppchainLookupResult<AppchainStatus>>;
+    /// Get summary (status without metadata) of an appchain
+    fn get_appchain_summary_of(&self, appchain_id: AppchainId) -> AppchainSummary;
+    /// Get summaries of the given appchains (at most `100`), in the order of the given ids.
+    /// The result of an appchain which is not registered contains no item.
+    fn get_appchain_summaries(
+        &self,
+        appchain_ids: Vec<AppchainId>,
+    ) -> Vec<AppchainLookupResult<AppchainSummary>>;
     /// Get upvote deposit of a given account id for a certain appchain
     fn get_upvote_deposit_for(&self, appchain_id: AppchainId, account_id: AccountId) -> U128;
     /// Get downvote deposit of a given account id for a certain appchain
     fn get_downvote_deposit_for(&self, appchain_id: AppchainId, account_id: AccountId) -> U128;
+    /// Get pending change requests of appchain metadata.
+    /// If param `appchain_id` is `Option::None`, return the requests of all appchains
+    fn get_pending_appchain_metadata_change_requests(
+        &self,
+        appchain_id: Option<AppchainId>,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainMetadataChangeRequest>;
+    /// Get the pending change request of appchain metadata of the given request id
+    fn get_appchain_metadata_change_request_of(
+        &self,
+        request_id: U64,
+    ) -> Option<AppchainMetadataChangeRequest>;
+    /// Get the metadata revisions of an appchain, including the revisions of the removed
+    /// appchains with the same id. The item at index `i` is revision `i + 1`,
+    /// as the first revision is `1`
+    fn get_appchain_metadata_revisions(
+        &self,
+        appchain_id: AppchainId,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainMetadataRevision>;
+    /// Get the metadata (and EVM chain id and DAO proposal url) of an appchain as it was
+    /// at the given revision. The appchain as it was registered is the revision `0`, or the
+    /// last revision of the removed appchains with the same id (if any)
+    fn get_appchain_metadata_at_revision(
+        &self,
+        appchain_id: AppchainId,
+        revision: U64,
+    ) -> AppchainMetadataSnapshot;
+    /// Get the metadata (and EVM chain id and DAO proposal url) of an appchain as it would be
+    /// after applying the given patch, without writing it. Panics if the patch is invalid
+    fn preview_appchain_metadata_patch(
+        &self,
+        appchain_id: AppchainId,
+        patch: AppchainMetadataPatch,
+    ) -> AppchainMetadataSnapshot;
+    /// Get the storage used by an appchain and the amount of NEAR staked for it
+    /// by the appchain owner
+    fn get_appchain_storage_balance_of(&self, appchain_id: AppchainId) -> AppchainStorageBalance;
+    /// Get the audit records of an appchain.
+    /// The audit records are kept after the appchain is removed.
+    fn get_appchain_audit_records(
+        &self,
+        appchain_id: AppchainId,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainAuditRecord>;
+    /// Get the review trail (rejections, withdrawals, appeals and reinstatements) of an appchain.
+    /// The review trail is kept after the appchain is removed.
+    fn get_appchain_review_trail(
+        &self,
+        appchain_id: AppchainId,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainReviewRecord>;
+    /// Get the status of the appchains which have the given tag, in no particular order.
+    /// The order may change when the tag is removed from an appchain.
+    fn get_appchains_by_tag(
+        &self,
+        tag: String,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainStatus>;
+    /// Get the pending tag proposals of all appchains
+    fn get_pending_appchain_tag_proposals(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainTagProposal>;
+    /// Get the pending tag proposal of an appchain
+    fn get_appchain_tag_proposal_of(&self, appchain_id: AppchainId) -> Option<AppchainTagProposal>;
+    /// Get at most `limit` changes of appchains after the given sequence number,
+    /// in ascending order of sequence number. Only the latest change of each appchain is kept.
+    fn get_changes_since(&self, sequence: U64, limit: Option<u32>) -> AppchainChangeFeed;
 }
 
 #[near_bindgen]
@@ -65,88 +240,163 @@ impl RegistryStatus for AppchainRegistry {
         self.registry_roles.get().unwrap()
     }
     //
+    fn get_appchain_templates(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainTemplate> {
+        PagedResult::from_iter(
+            self.appchain_templates.values(),
+            self.appchain_templates.len(),
+            from_index,
+            limit,
+        )
+    }
+    //
+    fn get_appchain_template_of(
+        &self,
+        template_type: AppchainTemplateType,
+    ) -> Option<AppchainTemplate> {
+        self.appchain_templates.get(&template_type)
+    }
+    //
+    fn get_register_deposit_vouchers(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<RegisterDepositVoucher> {
+        PagedResult::from_iter(
+            self.register_deposit_vouchers.values(),
+            self.register_deposit_vouchers.len(),
+            from_index,
+            limit,
+        )
+    }
+    //
+    fn get_register_deposit_voucher_of(
+        &self,
+        voucher_id: String,
+    ) -> Option<RegisterDepositVoucher> {
+        self.register_deposit_vouchers.get(&voucher_id)
+    }
+    //
+    fn get_reserved_appchain_ids(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<ReservedAppchainId> {
+        PagedResult::from_iter(
+            self.reserved_appchain_ids.values(),
+            self.reserved_appchain_ids.len(),
+            from_index,
+            limit,
+        )
+    }
+    //
+    fn check_appchain_id_availability(
+        &self,
+        appchain_id: AppchainId,
+        account_id: Option<AccountId>,
+    ) -> AppchainIdAvailability {
+        self.internal_check_appchain_id_availability(&appchain_id, account_id.as_ref())
+    }
+    //
+    fn compute_registration_commitment(
+        &self,
+        appchain_id: AppchainId,
+        salt: String,
+        account_id: AccountId,
+        voucher_secret: Option<String>,
+    ) -> String {
+        registration_commitment::compute_registration_commitment(
+            &appchain_id,
+            &salt,
+            &account_id,
+            &voucher_secret,
+        )
+    }
+    //
+    fn get_registration_commitments(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<RegistrationCommitment> {
+        PagedResult::from_iter(
+            self.registration_commitments.values(),
+            self.registration_commitments.len(),
+            from_index,
+            limit,
+        )
+    }
+    //
+    fn get_registration_commitment_of(&self, commitment: String) -> Option<RegistrationCommitment> {
+        self.registration_commitments.get(&commitment)
+    }
+    //
+    fn get_unrefunded_commitment_deposit_of(&self, account_id: AccountId) -> U128 {
+        U128::from(
+            self.unrefunded_commitment_deposits
+                .get(&account_id)
+                .unwrap_or(0),
+        )
+    }
+    //
+    fn get_appchain_id_of_evm_chain_id(&self, evm_chain_id: U64) -> Option<AppchainId> {
+        self.evm_chain_ids.get(&evm_chain_id.0)
+    }
+    //
+    fn get_appchain_id_of_anchor(&self, anchor_account: AccountId) -> Option<AppchainId> {
+        self.appchain_ids_by_anchor.get(&anchor_account)
+    }
+    //
+    fn get_stale_appchains(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainLiveness> {
+        PagedResult::from_vec(self.get_stale_appchain_ids(), from_index, limit)
+            .map(|appchain_id| self.get_appchain_basedata(&appchain_id).liveness())
+    }
+    //
     fn get_total_stake(&self) -> U128 {
-        let mut total_stake: u128 = 0;
-        self.appchain_ids.to_vec().iter().for_each(|appchain_id| {
-            if let Some(appchain_basedata) = self.appchain_basedatas.get(appchain_id) {
-                total_stake += appchain_basedata.status().total_stake.0;
-            }
-        });
-        U128::from(total_stake)
+        U128::from(
+            self.get_statistics()
+                .appchain_states
+                .iter()
+                .map(|state_statistics| state_statistics.total_stake.0)
+                .sum::<u128>(),
+        )
     }
     //
-    fn get_appchain_ids(&self) -> Vec<String> {
-        self.appchain_ids.to_vec()
+    fn get_registry_statistics(&self) -> RegistryStatistics {
+        self.get_statistics()
+    }
+    //
+    fn get_appchain_ids(&self, from_index: Option<U64>, limit: Option<u32>) -> PagedResult<String> {
+        PagedResult::from_iter(
+            self.appchain_ids.iter(),
+            self.appchain_ids.len(),
+            from_index,
+            limit,
+        )
     }
     //
     fn get_appchains_with_state_of(
         &self,
         appchain_state: Option<Vec<AppchainState>>,
-        page_number: u16,
-        page_size: u16,
-        sorting_field: AppchainSortingField,
-        sorting_order: SortingOrder,
-    ) -> Vec<AppchainStatus> {
-        assert!(page_number > 0, "Invalid page number.");
-        assert!(page_size >= 5 && page_size <= 50, "Invalid page size.");
-        let mut results: Vec<AppchainStatus> = Vec::new();
-        for id in self.appchain_ids.to_vec() {
-            let appchain_basedata = self.get_appchain_basedata(&id);
-            match appchain_state {
-                Some(ref states) => {
-                    for state in states {
-                        if appchain_basedata.state().eq(state) {
-                            results.push(appchain_basedata.status());
-                            break;
-                        }
-                    }
-                }
-                None => results.push(appchain_basedata.status()),
-            }
-        }
-        if results.len() > 0 {
-            match sorting_field {
-                AppchainSortingField::AppchainId => results.sort_by(|a, b| match sorting_order {
-                    SortingOrder::Ascending => a.appchain_id.cmp(&b.appchain_id),
-                    SortingOrder::Descending => b.appchain_id.cmp(&a.appchain_id),
-                }),
-                AppchainSortingField::VotingScore => results.sort_by(|a, b| match sorting_order {
-                    SortingOrder::Ascending => a.voting_score.0.cmp(&b.voting_score.0),
-                    SortingOrder::Descending => b.voting_score.0.cmp(&a.voting_score.0),
-                }),
-                AppchainSortingField::RegisteredTime => {
-                    results.sort_by(|a, b| match sorting_order {
-                        SortingOrder::Ascending => a.registered_time.0.cmp(&b.registered_time.0),
-                        SortingOrder::Descending => b.registered_time.0.cmp(&a.registered_time.0),
-                    })
-                }
-            }
-            let (_, tail) = results.split_at(((page_number - 1) * page_size).into());
-            if tail.len() > page_size.into() {
-                let (page, _) = tail.split_at(page_size.into());
-                page.to_vec()
-            } else {
-                tail.to_vec()
-            }
-        } else {
-            results
-        }
+        filter: Option<AppchainFilter>,
+        sorting: Vec<AppchainSortingSpec>,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainStatus> {
+        self.internal_get_sorted_appchains(appchain_state, filter, sorting, from_index, limit)
     }
     //
     fn get_appchains_count_of(&self, appchain_state: Option<AppchainState>) -> U64 {
-        let mut count: u64 = 0;
-        for id in self.appchain_ids.to_vec() {
-            let appchain_basedata = self.get_appchain_basedata(&id);
-            match appchain_state {
-                Some(ref state) => {
-                    if appchain_basedata.state().eq(state) {
-                        count += 1;
-                    }
-                }
-                None => count += 1,
-            }
+        match appchain_state {
+            Some(state) => U64::from(u64::from(self.get_state_statistics(&state).appchain_count)),
+            None => U64::from(self.appchain_ids.len()),
         }
-        count.into()
     }
     //
     fn get_appchain_status_of(&self, appchain_id: AppchainId) -> AppchainStatus {
@@ -154,6 +404,27 @@ impl RegistryStatus for AppchainRegistry {
         appchain_basedata.status()
     }
     //
+    fn get_appchain_statuses(
+        &self,
+        appchain_ids: Vec<AppchainId>,
+    ) -> Vec<AppchainLookupResult<AppchainStatus>> {
+        self.internal_lookup_appchains(appchain_ids, |appchain_basedata| appchain_basedata.status())
+    }
+    //
+    fn get_appchain_summary_of(&self, appchain_id: AppchainId) -> AppchainSummary {
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        appchain_basedata.summary()
+    }
+    //
+    fn get_appchain_summaries(
+        &self,
+        appchain_ids: Vec<AppchainId>,
+    ) -> Vec<AppchainLookupResult<AppchainSummary>> {
+        self.internal_lookup_appchains(appchain_ids, |appchain_basedata| {
+            appchain_basedata.summary()
+        })
+    }
+    //
     fn get_upvote_deposit_for(&self, appchain_id: AppchainId, account_id: AccountId) -> U128 {
         match self.upvote_deposits.get(&(appchain_id, account_id)) {
             Some(value) => value.into(),
@@ -167,4 +438,157 @@ impl RegistryStatus for AppchainRegistry {
             None => 0.into(),
         }
     }
+    //
+    fn get_pending_appchain_metadata_change_requests(
+        &self,
+        appchain_id: Option<AppchainId>,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainMetadataChangeRequest> {
+        match appchain_id {
+            Some(appchain_id) => PagedResult::from_vec(
+                self.get_metadata_change_request_ids_of(&appchain_id),
+                from_index,
+                limit,
+            )
+            .map(|request_id| self.get_metadata_change_request(request_id)),
+            None => PagedResult::from_iter(
+                self.appchain_metadata_change_requests.values(),
+                self.appchain_metadata_change_requests.len(),
+                from_index,
+                limit,
+            ),
+        }
+    }
+    //
+    fn get_appchain_metadata_change_request_of(
+        &self,
+        request_id: U64,
+    ) -> Option<AppchainMetadataChangeRequest> {
+        self.appchain_metadata_change_requests.get(&request_id.0)
+    }
+    //
+    fn get_appchain_metadata_revisions(
+        &self,
+        appchain_id: AppchainId,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainMetadataRevision> {
+        let revision_count = self.get_metadata_revision_count(&appchain_id);
+        PagedResult::from_iter(1..=revision_count, revision_count, from_index, limit).map(
+            |revision| {
+                self.appchain_metadata_revisions
+                    .get(&(appchain_id.clone(), revision))
+                    .unwrap()
+            },
+        )
+    }
+    //
+    fn get_appchain_metadata_at_revision(
+        &self,
+        appchain_id: AppchainId,
+        revision: U64,
+    ) -> AppchainMetadataSnapshot {
+        self.internal_get_metadata_snapshot_at(&appchain_id, revision.0)
+    }
+    //
+    fn preview_appchain_metadata_patch(
+        &self,
+        appchain_id: AppchainId,
+        patch: AppchainMetadataPatch,
+    ) -> AppchainMetadataSnapshot {
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        self.internal_apply_metadata_patch(&appchain_basedata.metadata_snapshot(), &patch)
+    }
+    //
+    fn get_appchain_storage_balance_of(&self, appchain_id: AppchainId) -> AppchainStorageBalance {
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let storage_used = self.appchain_storage_usages.get(&appchain_id).unwrap_or(0);
+        AppchainStorageBalance {
+            appchain_id,
+            appchain_owner: appchain_basedata.owner(),
+            storage_used: U64::from(storage_used),
+            storage_staked: U128::from(Balance::from(storage_used) * env::storage_byte_cost()),
+        }
+    }
+    //
+    fn get_appchain_audit_records(
+        &self,
+        appchain_id: AppchainId,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainAuditRecord> {
+        PagedResult::from_vec(self.get_audit_records_of(&appchain_id), from_index, limit)
+    }
+    //
+    fn get_appchain_review_trail(
+        &self,
+        appchain_id: AppchainId,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainReviewRecord> {
+        PagedResult::from_vec(self.get_review_trail_of(&appchain_id), from_index, limit)
+    }
+    //
+    fn get_appchains_by_tag(
+        &self,
+        tag: String,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainStatus> {
+        let appchain_ids = self.get_appchain_ids_by_tag(&tag);
+        PagedResult::from_iter(appchain_ids.iter(), appchain_ids.len(), from_index, limit)
+            .map(|appchain_id| self.get_appchain_basedata(&appchain_id).status())
+    }
+    //
+    fn get_pending_appchain_tag_proposals(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<u32>,
+    ) -> PagedResult<AppchainTagProposal> {
+        PagedResult::from_iter(
+            self.appchain_tag_proposals.values(),
+            self.appchain_tag_proposals.len(),
+            from_index,
+            limit,
+        )
+    }
+    //
+    fn get_appchain_tag_proposal_of(&self, appchain_id: AppchainId) -> Option<AppchainTagProposal> {
+        self.appchain_tag_proposals.get(&appchain_id)
+    }
+    //
+    fn get_changes_since(&self, sequence: U64, limit: Option<u32>) -> AppchainChangeFeed {
+        let limit = assert_valid_page_limit(limit);
+        AppchainChangeFeed {
+            changes: self
+                .appchain_changes
+                .iter_from(sequence.0)
+                .take(limit as usize)
+                .map(|(_, change)| change)
+                .collect(),
+            latest_sequence: U64::from(self.latest_appchain_change_sequence),
+        }
+    }
+}
+
+impl AppchainRegistry {
+    /// Look up the given appchains, and project the found ones by `f`.
+    fn internal_lookup_appchains<T, F: Fn(&AppchainBasedata) -> T>(
+        &self,
+        appchain_ids: Vec<AppchainId>,
+        f: F,
+    ) -> Vec<AppchainLookupResult<T>> {
+        assert_valid_batch_size("appchain_ids", appchain_ids.len());
+        appchain_ids
+            .into_iter()
+            .map(|appchain_id| AppchainLookupResult {
+                item: self
+                    .appchain_basedatas
+                    .get(&appchain_id)
+                    .map(|appchain_basedata| f(&appchain_basedata)),
+                appchain_id,
+            })
+            .collect()
+    }
 }
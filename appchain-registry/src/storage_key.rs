@@ -9,14 +9,42 @@ pub enum StorageKey {
     RegistrySettings,
     RegistryRoles,
     RegistryContractWasm,
+    AppchainTemplates,
+    RegisterDepositVouchers,
+    EvmChainIds,
+    ReservedAppchainIds,
+    RegistrationCommitments,
+    StorageBalances,
+    AppchainStorageUsages,
+    AppchainMetadataChangeRequests,
+    AppchainMetadataRevisionCounts,
+    AppchainMetadataRevisions,
+    AppchainAuditRecords,
+    AppchainReviewTrails,
+    AppchainTotalStakeIndex,
+    AppchainVotingScoreIndex,
+    AppchainTagProposals,
+    AppchainIdsByTag,
+    AppchainChanges,
+    AppchainChangeSequences,
+    AppchainStateStatistics,
+    OwnerAppchainCounts,
+    VoterDepositCounts,
+    UnrefundedCommitmentDeposits,
+    AppchainIdsByAnchor,
+    AppchainMetadataInitialRevisions,
+    AppchainMetadataChangeRequestIndex,
+    NormalizedAppchainIds,
     AppchainMetadata(AppchainId),
     AppchainAnchorCode(AppchainId),
     AppchainVotingScore(AppchainId),
+    AppchainTags(AppchainId),
+    AppchainIdsOfTag(String),
 }
 
-impl StorageKey {
-    pub fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for StorageKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = match self {
             StorageKey::AppchainIds => "i".to_string(),
             StorageKey::AppchainBasedatas => "a".to_string(),
             StorageKey::UpvoteDeposits => "u".to_string(),
@@ -24,12 +52,44 @@ impl StorageKey {
             StorageKey::RegistrySettings => "rs".to_string(),
             StorageKey::RegistryRoles => "rr".to_string(),
             StorageKey::RegistryContractWasm => "rcw".to_string(),
+            StorageKey::AppchainTemplates => "t".to_string(),
+            StorageKey::RegisterDepositVouchers => "v".to_string(),
+            StorageKey::EvmChainIds => "e".to_string(),
+            StorageKey::ReservedAppchainIds => "ri".to_string(),
+            StorageKey::RegistrationCommitments => "cm".to_string(),
+            StorageKey::StorageBalances => "sb".to_string(),
+            StorageKey::AppchainStorageUsages => "su".to_string(),
+            StorageKey::AppchainMetadataChangeRequests => "mr".to_string(),
+            StorageKey::AppchainMetadataRevisionCounts => "mc".to_string(),
+            StorageKey::AppchainMetadataRevisions => "mv".to_string(),
+            StorageKey::AppchainAuditRecords => "ar".to_string(),
+            StorageKey::AppchainReviewTrails => "rt".to_string(),
+            StorageKey::AppchainTotalStakeIndex => "tsi".to_string(),
+            StorageKey::AppchainVotingScoreIndex => "vsi".to_string(),
+            StorageKey::AppchainTagProposals => "tp".to_string(),
+            StorageKey::AppchainIdsByTag => "at".to_string(),
+            StorageKey::AppchainChanges => "ch".to_string(),
+            StorageKey::AppchainChangeSequences => "cq".to_string(),
+            StorageKey::AppchainStateStatistics => "ss".to_string(),
+            StorageKey::OwnerAppchainCounts => "oc".to_string(),
+            StorageKey::VoterDepositCounts => "vc".to_string(),
+            StorageKey::UnrefundedCommitmentDeposits => "ud".to_string(),
+            StorageKey::AppchainIdsByAnchor => "an".to_string(),
+            StorageKey::AppchainMetadataInitialRevisions => "mi".to_string(),
+            StorageKey::AppchainMetadataChangeRequestIndex => "mri".to_string(),
+            StorageKey::NormalizedAppchainIds => "ni".to_string(),
             StorageKey::AppchainMetadata(appchain_id) => format!("{}md", appchain_id),
             StorageKey::AppchainAnchorCode(appchain_id) => format!("{}ac", appchain_id),
             StorageKey::AppchainVotingScore(appchain_id) => format!("{}vs", appchain_id),
-        }
+            StorageKey::AppchainTags(appchain_id) => format!("{}tg", appchain_id),
+            StorageKey::AppchainIdsOfTag(tag) => format!("{}at", tag),
+        };
+        write!(f, "{}", key)
     }
-    pub fn into_bytes(&self) -> Vec<u8> {
+}
+
+impl StorageKey {
+    pub fn into_bytes(self) -> Vec<u8> {
         self.to_string().into_bytes()
     }
 }
@@ -0,0 +1,261 @@
+use crate::*;
+use near_contract_standards::storage_management::{
+    StorageBalance, StorageBalanceBounds, StorageManagement,
+};
+use near_sdk::assert_one_yocto;
+
+/// The max bytes of storage used by the storage balance record of an account, which is
+/// `key prefix + account id (with length) + record value + the overhead of a storage record`.
+const ACCOUNT_STORAGE_BALANCE_RECORD_BYTES: StorageUsage = 2 + 4 + 64 + 16 + 8 + 40;
+
+impl AccountStorageBalance {
+    /// The amount of NEAR locked for the storage used by the account and the record itself.
+    pub fn locked_amount(&self) -> Balance {
+        Balance::from(ACCOUNT_STORAGE_BALANCE_RECORD_BYTES + self.used_bytes)
+            * env::storage_byte_cost()
+    }
+    /// The amount of NEAR which can be withdrawn.
+    pub fn available_amount(&self) -> Balance {
+        self.total.saturating_sub(self.locked_amount())
+    }
+    //
+    fn to_storage_balance(&self) -> StorageBalance {
+        StorageBalance {
+            total: U128::from(self.total),
+            available: U128::from(self.available_amount()),
+        }
+    }
+}
+
+impl AppchainRegistry {
+    // Add the given amount to the storage balance of the given account.
+    fn internal_deposit_storage(&mut self, account_id: &AccountId, amount: Balance) {
+        let mut storage_balance = self.storage_balances.get(account_id).unwrap_or_default();
+        storage_balance.total += amount;
+        self.storage_balances.insert(account_id, &storage_balance);
+        log!(
+            "Storage deposit {} of '{}' is received. Total: {}",
+            amount,
+            account_id,
+            storage_balance.total
+        );
+    }
+    /// Add the NEAR attached to the current call to the storage balance of the given account.
+    pub fn internal_deposit_attached_storage(&mut self, account_id: &AccountId) {
+        let amount = env::attached_deposit();
+        if amount > 0 {
+            self.internal_deposit_storage(account_id, amount);
+        }
+    }
+    /// Update the storage usage of an appchain with the storage changes since
+    /// `initial_storage_usage`, and return the previous and current usage of the appchain.
+    pub fn internal_measure_appchain_storage_usage(
+        &mut self,
+        appchain_id: &AppchainId,
+        initial_storage_usage: StorageUsage,
+    ) -> (StorageUsage, StorageUsage) {
+        let previous_usage = self.appchain_storage_usages.get(appchain_id).unwrap_or(0);
+        // Make sure the record exists, so that the storage of itself is also measured.
+        self.appchain_storage_usages
+            .insert(appchain_id, &previous_usage);
+        let current_usage =
+            (previous_usage + env::storage_usage()).saturating_sub(initial_storage_usage);
+        self.appchain_storage_usages
+            .insert(appchain_id, &current_usage);
+        (previous_usage, current_usage)
+    }
+    /// Replace `released_bytes` of the storage used by the given account with `used_bytes`.
+    ///
+    /// If the storage used by the account increases, its storage balance must cover
+    /// all of the storage it uses.
+    pub fn internal_update_storage_used_by(
+        &mut self,
+        account_id: &AccountId,
+        released_bytes: StorageUsage,
+        used_bytes: StorageUsage,
+    ) {
+        if released_bytes == used_bytes {
+            return;
+        }
+        let mut storage_balance = match self.storage_balances.get(account_id) {
+            Some(storage_balance) => storage_balance,
+            None if used_bytes < released_bytes => return,
+            None => AccountStorageBalance::default(),
+        };
+        storage_balance.used_bytes =
+            storage_balance.used_bytes.saturating_sub(released_bytes) + used_bytes;
+        if used_bytes > released_bytes {
+            let locked_amount = storage_balance.locked_amount();
+            ensure!(
+                storage_balance.total >= locked_amount,
+                RegistryError::NotEnoughStorageBalance {
+                    account_id: account_id.clone(),
+                    required_amount: U128::from(locked_amount - storage_balance.total),
+                }
+            );
+        }
+        self.storage_balances.insert(account_id, &storage_balance);
+    }
+    /// Charge the owner of an appchain for the storage changes of the appchain
+    /// since `initial_storage_usage`.
+    pub fn internal_charge_appchain_storage(
+        &mut self,
+        appchain_basedata: &AppchainBasedata,
+        initial_storage_usage: StorageUsage,
+    ) {
+        let (previous_usage, current_usage) = self
+            .internal_measure_appchain_storage_usage(appchain_basedata.id(), initial_storage_usage);
+        self.internal_update_storage_used_by(
+            &appchain_basedata.owner(),
+            previous_usage,
+            current_usage,
+        );
+    }
+    /// Settle the storage changes of an appchain since `initial_storage_usage`, in an action
+    /// which is NOT performed by the appchain owner, or in the withdrawal of the appchain.
+    ///
+    /// The released storage is returned to the owner, while the increased storage is paid
+    /// by this contract, so that the action never fails because of the storage balance
+    /// of the owner.
+    pub fn internal_settle_appchain_storage(
+        &mut self,
+        appchain_basedata: &AppchainBasedata,
+        initial_storage_usage: StorageUsage,
+    ) {
+        let released_bytes = initial_storage_usage.saturating_sub(env::storage_usage());
+        if released_bytes == 0 {
+            return;
+        }
+        if let Some(previous_usage) = self.appchain_storage_usages.get(appchain_basedata.id()) {
+            let current_usage = previous_usage.saturating_sub(released_bytes);
+            self.appchain_storage_usages
+                .insert(appchain_basedata.id(), &current_usage);
+            self.internal_update_storage_used_by(
+                &appchain_basedata.owner(),
+                previous_usage,
+                current_usage,
+            );
+        }
+    }
+    /// Release the storage used by an appchain which is removed from the registry.
+    pub fn internal_release_appchain_storage(
+        &mut self,
+        appchain_id: &AppchainId,
+        appchain_owner: &AccountId,
+    ) {
+        if let Some(storage_usage) = self.appchain_storage_usages.remove(appchain_id) {
+            self.internal_update_storage_used_by(appchain_owner, storage_usage, 0);
+        }
+    }
+}
+
+#[near_bindgen]
+impl StorageManagement for AppchainRegistry {
+    //
+    #[payable]
+    fn storage_deposit(
+        &mut self,
+        account_id: Option<AccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance {
+        let amount = env::attached_deposit();
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let min_balance = self.storage_balance_bounds().min.0;
+        let is_registered = self.storage_balances.get(&account_id).is_some();
+        if registration_only.unwrap_or(false) {
+            if is_registered {
+                Promise::new(env::predecessor_account_id()).transfer(amount);
+            } else {
+                ensure!(
+                    amount >= min_balance,
+                    RegistryError::NotEnoughStorageDeposit {
+                        required_amount: U128::from(min_balance)
+                    }
+                );
+                self.internal_deposit_storage(&account_id, min_balance);
+                if amount > min_balance {
+                    Promise::new(env::predecessor_account_id()).transfer(amount - min_balance);
+                }
+            }
+        } else {
+            ensure!(
+                is_registered || amount >= min_balance,
+                RegistryError::NotEnoughStorageDeposit {
+                    required_amount: U128::from(min_balance)
+                }
+            );
+            self.internal_deposit_storage(&account_id, amount);
+        }
+        self.storage_balance_of(account_id).unwrap()
+    }
+    //
+    #[payable]
+    fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let mut storage_balance = self.storage_balances.get(&account_id).unwrap_or_else(|| {
+            panic_with(RegistryError::AccountNotRegistered {
+                account_id: account_id.clone(),
+            })
+        });
+        let available_amount = storage_balance.available_amount();
+        let amount = amount.map_or(available_amount, |amount| amount.0);
+        ensure!(
+            amount <= available_amount,
+            RegistryError::NotEnoughStorageBalance {
+                account_id: account_id.clone(),
+                required_amount: U128::from(amount - available_amount),
+            }
+        );
+        if amount > 0 {
+            storage_balance.total -= amount;
+            self.storage_balances.insert(&account_id, &storage_balance);
+            Promise::new(account_id).transfer(amount);
+        }
+        storage_balance.to_storage_balance()
+    }
+    /// The `force` option is not supported, as the storage used by appchains can only be
+    /// released by removing the appchains from the registry.
+    #[payable]
+    fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        assert_one_yocto();
+        ensure!(
+            !force.unwrap_or(false),
+            RegistryError::InvalidArgument {
+                name: "force".to_string(),
+                reason: "Force unregistering is not supported.".to_string(),
+            }
+        );
+        let account_id = env::predecessor_account_id();
+        if let Some(storage_balance) = self.storage_balances.get(&account_id) {
+            ensure!(
+                storage_balance.used_bytes == 0,
+                RegistryError::StorageBalanceInUse {
+                    account_id: account_id.clone()
+                }
+            );
+            self.storage_balances.remove(&account_id);
+            if storage_balance.total > 0 {
+                Promise::new(account_id).transfer(storage_balance.total);
+            }
+            true
+        } else {
+            false
+        }
+    }
+    //
+    fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds {
+            min: U128::from(
+                Balance::from(ACCOUNT_STORAGE_BALANCE_RECORD_BYTES) * env::storage_byte_cost(),
+            ),
+            max: None,
+        }
+    }
+    //
+    fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.storage_balances
+            .get(&account_id)
+            .map(|storage_balance| storage_balance.to_storage_balance())
+    }
+}
@@ -1,7 +1,77 @@
 use crate::*;
+use appchain_id_rules::normalize_appchain_id;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LazyOption, LookupMap};
+use near_sdk::collections::{LazyOption, LookupMap, TreeMap};
 use near_sdk::{env, near_bindgen, AccountId, Balance, Duration, PublicKey, Timestamp};
+use std::collections::HashSet;
+
+/// The optional arguments of function `migrate_state`
+#[derive(Deserialize, Default)]
+#[serde(crate = "near_sdk::serde")]
+struct MigrationArgs {
+    /// The pairs of appchain id and voter account id of the legacy vote deposits,
+    /// which are counted in the statistics of distinct voters
+    #[serde(default)]
+    legacy_voters: Vec<(AppchainId, AccountId)>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldRegistrySettings {
+    /// The minimum deposit amount for registering an appchain.
+    pub minimum_register_deposit: U128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldRegistryRoles {
+    /// The account that manages the lifecycle of appchains.
+    pub appchain_lifecycle_manager: AccountId,
+    /// The account that manages the settings of appchain registry.
+    pub registry_settings_manager: AccountId,
+    /// The account of octopus council (DAO contract)
+    pub octopus_council: Option<AccountId>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub enum OldAppchainTemplateType {
+    Barnacle,
+    BarnacleEvm,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldAppchainMetadata {
+    pub description: String,
+    pub template_type: OldAppchainTemplateType,
+    pub website_url: String,
+    pub function_spec_url: String,
+    pub github_address: String,
+    pub github_release: String,
+    pub contact_email: String,
+    pub premined_wrapped_appchain_token_beneficiary: Option<AccountId>,
+    pub premined_wrapped_appchain_token: U128,
+    pub initial_supply_of_wrapped_appchain_token: U128,
+    pub ido_amount_of_wrapped_appchain_token: U128,
+    pub initial_era_reward: U128,
+    pub fungible_token_metadata: FungibleTokenMetadata,
+    pub custom_metadata: HashMap<String, String>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldAppchainBasedata {
+    pub appchain_id: AppchainId,
+    pub evm_chain_id: Option<U64>,
+    pub appchain_metadata: LazyOption<AppchainMetadata>,
+    pub appchain_anchor: Option<AccountId>,
+    pub appchain_owner: AccountId,
+    pub register_deposit: Balance,
+    pub appchain_state: AppchainState,
+    pub upvote_deposit: Balance,
+    pub downvote_deposit: Balance,
+    pub registered_time: Timestamp,
+    pub go_live_time: Timestamp,
+    pub validator_count: u32,
+    pub total_stake: Balance,
+    pub dao_proposal_url: Option<String>,
+}
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize)]
@@ -17,23 +87,19 @@ pub struct OldAppchainRegistry {
     /// The account of OCT token contract
     oct_token: AccountId,
     /// The settings of appchain registry
-    registry_settings: LazyOption<RegistrySettings>,
+    registry_settings: LazyOption<OldRegistrySettings>,
     /// The set of all appchain ids
     appchain_ids: UnorderedSet<AppchainId>,
     /// The map from appchain id to their basedata
-    appchain_basedatas: LookupMap<AppchainId, AppchainBasedata>,
+    appchain_basedatas: LookupMap<AppchainId, OldAppchainBasedata>,
     /// The map from pair (appchain id, account id) to their upvote deposit
     upvote_deposits: LookupMap<(AppchainId, AccountId), Balance>,
     /// The map from pair (appchain id, account id) to their downvote deposit
     downvote_deposits: LookupMap<(AppchainId, AccountId), Balance>,
-    /// The appchain id with the highest voting score at a certain time
-    top_appchain_id_in_queue: AppchainId,
     /// The total stake of OCT token in all appchains
     total_stake: Balance,
-    /// The time of the last calling of function `count_voting_score`
-    time_of_last_count_voting_score: Timestamp,
     /// The roles of appchain registry
-    registry_roles: LazyOption<RegistryRoles>,
+    registry_roles: LazyOption<OldRegistryRoles>,
     /// Whether the asset transfer is paused
     asset_transfer_is_paused: bool,
 }
@@ -43,35 +109,287 @@ impl AppchainRegistry {
     #[init(ignore_state)]
     pub fn migrate_state() -> Self {
         // Deserialize the state using the old contract structure.
-        let old_contract: OldAppchainRegistry = env::state_read().expect("Old state doesn't exist");
+        let old_contract: OldAppchainRegistry =
+            env::state_read().unwrap_or_else(|| panic_with(RegistryError::ContractNotInitialized));
         //
         assert_self();
+        // The arguments are parsed manually, as the older versions of function `update_self`
+        // call this function without arguments.
+        let migration_args: MigrationArgs = env::input()
+            .filter(|input| !input.is_empty())
+            .map(|input| {
+                serde_json::from_slice(&input).unwrap_or_else(|_| {
+                    panic_with(RegistryError::InvalidArgument {
+                        name: "legacy_voters".to_string(),
+                        reason: "Should be a list of pairs of appchain id and account id."
+                            .to_string(),
+                    })
+                })
+            })
+            .unwrap_or_default();
+        //
+        let old_registry_settings = old_contract.registry_settings.get().unwrap();
+        let old_registry_roles = old_contract.registry_roles.get().unwrap();
+        let mut appchain_templates = UnorderedMap::new(StorageKey::AppchainTemplates.into_bytes());
+        for template in builtin_appchain_templates(old_registry_settings.minimum_register_deposit) {
+            appchain_templates.insert(&template.name, &template);
+        }
         //
         // Create the new contract using the data from the old contract.
-        let new_appchain_registry = AppchainRegistry {
+        let mut new_appchain_registry = AppchainRegistry {
             owner: old_contract.owner,
             owner_pk: old_contract.owner_pk,
             contract_code_staging_timestamp: old_contract.contract_code_staging_timestamp,
             contract_code_staging_duration: old_contract.contract_code_staging_duration,
             oct_token: old_contract.oct_token,
-            registry_settings: old_contract.registry_settings,
+            registry_settings: LazyOption::new(
+                StorageKey::RegistrySettings.into_bytes(),
+                Some(&RegistrySettings::from_old_version(old_registry_settings)),
+            ),
             appchain_ids: old_contract.appchain_ids,
-            appchain_basedatas: old_contract.appchain_basedatas,
+            appchain_basedatas: LookupMap::new(StorageKey::AppchainBasedatas.into_bytes()),
             upvote_deposits: old_contract.upvote_deposits,
             downvote_deposits: old_contract.downvote_deposits,
             total_stake: old_contract.total_stake,
-            registry_roles: old_contract.registry_roles,
+            registry_roles: LazyOption::new(
+                StorageKey::RegistryRoles.into_bytes(),
+                Some(&RegistryRoles::from_old_version(old_registry_roles)),
+            ),
             asset_transfer_is_paused: old_contract.asset_transfer_is_paused,
+            appchain_templates,
+            register_deposit_vouchers: UnorderedMap::new(
+                StorageKey::RegisterDepositVouchers.into_bytes(),
+            ),
+            evm_chain_ids: LookupMap::new(StorageKey::EvmChainIds.into_bytes()),
+            reserved_appchain_ids: UnorderedMap::new(StorageKey::ReservedAppchainIds.into_bytes()),
+            registration_commitments: UnorderedMap::new(
+                StorageKey::RegistrationCommitments.into_bytes(),
+            ),
+            storage_balances: LookupMap::new(StorageKey::StorageBalances.into_bytes()),
+            appchain_storage_usages: LookupMap::new(StorageKey::AppchainStorageUsages.into_bytes()),
+            appchain_metadata_change_requests: UnorderedMap::new(
+                StorageKey::AppchainMetadataChangeRequests.into_bytes(),
+            ),
+            latest_metadata_change_request_id: 0,
+            appchain_metadata_revision_counts: LookupMap::new(
+                StorageKey::AppchainMetadataRevisionCounts.into_bytes(),
+            ),
+            appchain_metadata_revisions: LookupMap::new(
+                StorageKey::AppchainMetadataRevisions.into_bytes(),
+            ),
+            appchain_audit_records: LookupMap::new(StorageKey::AppchainAuditRecords.into_bytes()),
+            appchain_review_trails: LookupMap::new(StorageKey::AppchainReviewTrails.into_bytes()),
+            appchain_total_stake_index: TreeMap::new(
+                StorageKey::AppchainTotalStakeIndex.into_bytes(),
+            ),
+            appchain_voting_score_index: TreeMap::new(
+                StorageKey::AppchainVotingScoreIndex.into_bytes(),
+            ),
+            appchain_tag_proposals: UnorderedMap::new(
+                StorageKey::AppchainTagProposals.into_bytes(),
+            ),
+            appchain_ids_by_tag: LookupMap::new(StorageKey::AppchainIdsByTag.into_bytes()),
+            latest_appchain_change_sequence: 0,
+            appchain_changes: TreeMap::new(StorageKey::AppchainChanges.into_bytes()),
+            appchain_change_sequences: LookupMap::new(
+                StorageKey::AppchainChangeSequences.into_bytes(),
+            ),
+            appchain_state_statistics: LookupMap::new(
+                StorageKey::AppchainStateStatistics.into_bytes(),
+            ),
+            total_register_deposit: 0,
+            owner_appchain_counts: LookupMap::new(StorageKey::OwnerAppchainCounts.into_bytes()),
+            distinct_owner_count: 0,
+            voter_deposit_counts: LookupMap::new(StorageKey::VoterDepositCounts.into_bytes()),
+            distinct_voter_count: 0,
+            unrefunded_commitment_deposits: LookupMap::new(
+                StorageKey::UnrefundedCommitmentDeposits.into_bytes(),
+            ),
+            appchain_ids_by_anchor: LookupMap::new(StorageKey::AppchainIdsByAnchor.into_bytes()),
+            appchain_metadata_initial_revisions: LookupMap::new(
+                StorageKey::AppchainMetadataInitialRevisions.into_bytes(),
+            ),
+            appchain_metadata_change_request_index: TreeMap::new(
+                StorageKey::AppchainMetadataChangeRequestIndex.into_bytes(),
+            ),
+            normalized_appchain_ids: LookupMap::new(StorageKey::NormalizedAppchainIds.into_bytes()),
         };
+        for appchain_id in DEFAULT_RESERVED_APPCHAIN_IDS {
+            new_appchain_registry.internal_reserve_appchain_id(&appchain_id.to_string(), None);
+        }
         //
+        for appchain_id in new_appchain_registry.appchain_ids.to_vec() {
+            let old_appchain_basedata = old_contract.appchain_basedatas.get(&appchain_id).unwrap();
+            new_appchain_registry.appchain_basedatas.insert(
+                &appchain_id,
+                &AppchainBasedata::from_old_version(old_appchain_basedata),
+            );
+            migrate_appchain_metadata(&appchain_id);
+            let appchain_basedata = new_appchain_registry.get_appchain_basedata(&appchain_id);
+            new_appchain_registry.internal_update_sorting_indexes(
+                &appchain_id,
+                None,
+                Some(appchain_basedata.sorting_keys()),
+            );
+            new_appchain_registry
+                .internal_update_statistics(None, Some(appchain_basedata.statistics_values()));
+            new_appchain_registry
+                .internal_update_owner_statistics(None, Some(&appchain_basedata.owner()));
+            new_appchain_registry.total_register_deposit += appchain_basedata.register_deposit();
+            new_appchain_registry
+                .normalized_appchain_ids
+                .insert(&normalize_appchain_id(&appchain_id), &appchain_id);
+            if let Some(anchor) = appchain_basedata.anchor() {
+                new_appchain_registry
+                    .appchain_ids_by_anchor
+                    .insert(&anchor, &appchain_id);
+            }
+            if let Some(evm_chain_id) = appchain_basedata.evm_chain_id {
+                match new_appchain_registry.evm_chain_ids.get(&evm_chain_id.0) {
+                    Some(existing_id) => log!(
+                        "EVM chain id {} of appchain '{}' is already used by appchain '{}'.",
+                        evm_chain_id.0,
+                        appchain_id,
+                        existing_id
+                    ),
+                    None => {
+                        new_appchain_registry
+                            .evm_chain_ids
+                            .insert(&evm_chain_id.0, &appchain_id);
+                    }
+                }
+            }
+        }
+        // The vote deposits can not be enumerated in storage, so the voters are counted
+        // by the given pairs, and the pairs without deposit are ignored.
+        let mut counted_voters = HashSet::new();
+        for (appchain_id, voter) in migration_args.legacy_voters {
+            let deposit_key = (appchain_id, voter);
+            if !counted_voters.insert(deposit_key.clone()) {
+                continue;
+            }
+            let deposit_count = [
+                &new_appchain_registry.upvote_deposits,
+                &new_appchain_registry.downvote_deposits,
+            ]
+            .iter()
+            .filter(|deposits| deposits.get(&deposit_key).unwrap_or(0) > 0)
+            .count();
+            for _ in 0..deposit_count {
+                new_appchain_registry.internal_update_voter_statistics(&deposit_key.1, false, true);
+            }
+        }
         //
         new_appchain_registry
     }
 }
 
+impl RegistrySettings {
+    //
+    pub fn from_old_version(_old_version: OldRegistrySettings) -> Self {
+        Self::default()
+    }
+}
+
+impl RegistryRoles {
+    //
+    pub fn from_old_version(old_version: OldRegistryRoles) -> Self {
+        Self {
+            appchain_lifecycle_manager: old_version.appchain_lifecycle_manager,
+            registry_settings_manager: old_version.registry_settings_manager,
+            octopus_council: old_version.octopus_council,
+            auditors: Vec::new(),
+        }
+    }
+}
+
+impl AppchainBasedata {
+    //
+    pub fn from_old_version(old_version: OldAppchainBasedata) -> Self {
+        // The anchors of live appchains are regarded as alive at the time of migration,
+        // otherwise all of them would be stale right after the upgrade.
+        let alive_time = match old_version.appchain_state {
+            AppchainState::Booting | AppchainState::Active => env::block_timestamp(),
+            _ => 0,
+        };
+        Self {
+            appchain_id: old_version.appchain_id,
+            evm_chain_id: old_version.evm_chain_id,
+            appchain_metadata: old_version.appchain_metadata,
+            appchain_anchor: old_version.appchain_anchor,
+            appchain_owner: old_version.appchain_owner,
+            register_deposit: old_version.register_deposit,
+            appchain_state: old_version.appchain_state,
+            upvote_deposit: old_version.upvote_deposit,
+            downvote_deposit: old_version.downvote_deposit,
+            registered_time: old_version.registered_time,
+            go_live_time: old_version.go_live_time,
+            validator_count: old_version.validator_count,
+            total_stake: old_version.total_stake,
+            dao_proposal_url: old_version.dao_proposal_url,
+            sync_metrics: None,
+            last_sync_time: alive_time,
+            booting_time: alive_time,
+            health: AppchainHealth::Healthy,
+        }
+    }
+}
+
+impl OldAppchainTemplateType {
+    //
+    pub fn name(&self) -> AppchainTemplateType {
+        match self {
+            OldAppchainTemplateType::Barnacle => "Barnacle".to_string(),
+            OldAppchainTemplateType::BarnacleEvm => "BarnacleEvm".to_string(),
+        }
+    }
+}
+
+impl AppchainMetadata {
+    //
+    pub fn from_old_version(old_version: OldAppchainMetadata) -> Self {
+        Self {
+            description: old_version.description,
+            template_type: old_version.template_type.name(),
+            website_url: old_version.website_url,
+            function_spec_url: old_version.function_spec_url,
+            github_address: old_version.github_address,
+            github_release: old_version.github_release,
+            contact_email: old_version.contact_email,
+            premined_wrapped_appchain_token_beneficiary: old_version
+                .premined_wrapped_appchain_token_beneficiary,
+            premined_wrapped_appchain_token: old_version.premined_wrapped_appchain_token,
+            initial_supply_of_wrapped_appchain_token: old_version
+                .initial_supply_of_wrapped_appchain_token,
+            ido_amount_of_wrapped_appchain_token: old_version.ido_amount_of_wrapped_appchain_token,
+            initial_era_reward: old_version.initial_era_reward,
+            fungible_token_metadata: old_version.fungible_token_metadata,
+            custom_metadata: old_version.custom_metadata,
+        }
+    }
+}
+
+// Rewrite the metadata of an appchain, which is stored in a `LazyOption`, with the new layout.
+fn migrate_appchain_metadata(appchain_id: &AppchainId) {
+    let storage_key = StorageKey::AppchainMetadata(appchain_id.clone()).into_bytes();
+    if let Some(bytes) = env::storage_read(&storage_key) {
+        let old_metadata = OldAppchainMetadata::try_from_slice(&bytes).unwrap_or_else(|_| {
+            panic_with(RegistryError::InvalidStorageData {
+                key: format!("metadata of appchain {}", appchain_id),
+            })
+        });
+        env::storage_write(
+            &storage_key,
+            &AppchainMetadata::from_old_version(old_metadata)
+                .try_to_vec()
+                .unwrap(),
+        );
+    }
+}
+
 pub fn get_storage_key_in_lookup_array<T: BorshSerialize>(
     prefix: &StorageKey,
     index: &T,
 ) -> Vec<u8> {
-    [prefix.into_bytes(), index.try_to_vec().unwrap()].concat()
+    [prefix.to_string().into_bytes(), index.try_to_vec().unwrap()].concat()
 }
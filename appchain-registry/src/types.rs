@@ -1,6 +1,6 @@
 use std::{collections::HashMap, fmt::Display};
 
-use near_sdk::json_types::{I128, U64};
+use near_sdk::json_types::{Base58CryptoHash, I128, U64};
 
 use crate::*;
 
@@ -9,8 +9,39 @@ pub type AppchainId = String;
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct RegistrySettings {
-    /// The minimum deposit amount for registering an appchain.
-    pub minimum_register_deposit: U128,
+    /// The gas (in T) for calling `ft_transfer` of OCT token contract.
+    pub t_gas_for_ft_transfer: u64,
+    /// The gas (in T) for resolver functions of this contract.
+    pub t_gas_for_resolver_function: u64,
+    /// The gas (in T) for calling functions of appchain anchor contract.
+    pub t_gas_for_calling_anchor_function: u64,
+    /// The EVM chain ids of well-known public chains, which can NOT be used by appchains.
+    pub reserved_evm_chain_ids: Vec<U64>,
+    /// The patterns (with `*` as wildcard) of appchain ids which can NOT be registered.
+    /// The patterns are matched against the normalized appchain id.
+    pub appchain_id_deny_patterns: Vec<String>,
+    /// Whether an appchain must be registered by revealing a registration commitment.
+    pub registration_commitment_required: bool,
+    /// The deposit of OCT token for committing an appchain registration,
+    /// which will be credited against the register deposit when revealing.
+    pub registration_commitment_deposit: U128,
+    /// The time range (in seconds) after committing, in which the registration
+    /// can be revealed.
+    pub registration_reveal_window_in_secs: u64,
+    /// The fields of appchain metadata which can be updated by appchain owners directly.
+    /// The economic fields can only be changed by approved change requests.
+    pub owner_updatable_metadata_fields: Vec<AppchainMetadataField>,
+    /// The number of approving audits (by current auditors) which an appchain needs
+    /// before it can pass auditing.
+    pub required_approving_audits: u32,
+    /// The time range (in seconds) after the rejection of an appchain, in which
+    /// the appchain owner can appeal the rejection.
+    pub appeal_window_in_secs: u64,
+    /// The vocabulary of categories, which can be used as the tags of appchains.
+    pub appchain_categories: Vec<String>,
+    /// The time range (in seconds) in which the anchor of a booting or active appchain
+    /// should sync, otherwise the appchain can be flagged as stale.
+    pub anchor_staleness_threshold_in_secs: u64,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -22,13 +53,123 @@ pub struct RegistryRoles {
     pub registry_settings_manager: AccountId,
     /// The account of octopus council (DAO contract)
     pub octopus_council: Option<AccountId>,
+    /// The accounts which can submit audit records of appchains.
+    pub auditors: Vec<AccountId>,
 }
 
+/// The name of an appchain template, e.g. `Barnacle` or `BarnacleEvm`.
+pub type AppchainTemplateType = String;
+
+/// The template that an appchain is built on, managed by registry settings manager
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainTemplate {
+    /// The name of the template, which is used as `template_type` of appchains.
+    pub name: AppchainTemplateType,
+    /// The description of the template.
+    pub description: String,
+    /// Whether the appchains of this template must have an EVM chain id.
+    pub requires_evm_chain_id: bool,
+    /// The hash of the wasm code of appchain anchor for this template.
+    pub anchor_wasm_hash: Option<Base58CryptoHash>,
+    /// The initial balance of appchain anchor account.
+    pub anchor_init_balance: U128,
+    /// The deposit amount for registering an appchain of this template.
+    pub register_deposit: U128,
+    /// Whether new appchains can be registered with this template.
+    pub is_active: bool,
+}
+
+/// The holder who can redeem a register deposit voucher
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
-pub enum AppchainTemplateType {
-    Barnacle,
-    BarnacleEvm,
+pub enum RegisterDepositVoucherHolder {
+    /// Only the given account can redeem the voucher.
+    Account(AccountId),
+    /// Anyone who knows the secret whose sha256 hash (in hex) is the given value
+    /// can redeem the voucher.
+    SecretHash(String),
+}
+
+/// The voucher for a discount of (or a full waiver of) register deposit,
+/// issued by registry settings manager
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RegisterDepositVoucher {
+    pub voucher_id: String,
+    pub holder: RegisterDepositVoucherHolder,
+    /// The percent of register deposit to be waived, `100` for a full waiver.
+    pub discount_percent: u16,
+    /// The max times that the voucher can be redeemed.
+    pub max_uses: u32,
+    /// The times that the voucher has been redeemed.
+    pub used_count: u32,
+}
+
+/// An appchain id which can only be registered by the assigned account (if any)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ReservedAppchainId {
+    pub appchain_id: AppchainId,
+    /// The account which can register the appchain id,
+    /// `None` means the id can NOT be registered by anyone.
+    pub assigned_to: Option<AccountId>,
+}
+
+/// The commitment of an appchain registration, which hides the appchain id
+/// until the registration is revealed
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RegistrationCommitment {
+    /// The sha256 hash (in hex) of `{appchain_id}:{salt}:{account_id}`.
+    pub commitment: String,
+    pub account_id: AccountId,
+    pub deposit: U128,
+    pub committed_block_height: U64,
+    pub committed_timestamp: U64,
+}
+
+/// The storage balance of an account, which funds the storage of the appchains it owns
+#[derive(BorshDeserialize, BorshSerialize, Clone, Default)]
+pub struct AccountStorageBalance {
+    /// The total amount of NEAR deposited for storage.
+    pub total: Balance,
+    /// The bytes of storage used by the appchains owned by the account.
+    pub used_bytes: StorageUsage,
+}
+
+/// The storage usage of an appchain, and the amount of NEAR staked for it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainStorageBalance {
+    pub appchain_id: AppchainId,
+    pub appchain_owner: AccountId,
+    pub storage_used: U64,
+    pub storage_staked: U128,
+}
+
+/// The result of checking the availability of an appchain id
+///
+/// This enum should NOT be used in storage on chain
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AppchainIdAvailability {
+    Available,
+    /// The appchain id does NOT follow the naming rules.
+    Invalid {
+        reason: String,
+    },
+    /// The appchain id is already registered.
+    Registered,
+    /// The appchain id (after normalization) is reserved.
+    Reserved {
+        reserved_id: AppchainId,
+        assigned_to: Option<AccountId>,
+    },
+    /// The appchain id (after normalization) matches a deny pattern.
+    Denied {
+        pattern: String,
+    },
 }
 
 /// Appchain metadata
@@ -51,8 +192,307 @@ pub struct AppchainMetadata {
     pub custom_metadata: HashMap<String, String>,
 }
 
+/// The fields of appchain metadata (and appchain basedata) which can be updated
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AppchainMetadataField {
+    Description,
+    TemplateType,
+    EvmChainId,
+    DaoProposalUrl,
+    WebsiteUrl,
+    FunctionSpecUrl,
+    GithubAddress,
+    GithubRelease,
+    ContactEmail,
+    PreminedWrappedAppchainTokenBeneficiary,
+    PreminedWrappedAppchainToken,
+    InitialSupplyOfWrappedAppchainToken,
+    IdoAmountOfWrappedAppchainToken,
+    InitialEraReward,
+    FungibleTokenMetadata,
+    CustomMetadata,
+}
+
+/// The patch of a field, which sets the field to a new value, clears the field,
+/// or leaves the field unchanged
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, Default, PartialEq,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum FieldPatch<T> {
+    #[default]
+    Unchanged,
+    Set(T),
+    Clear,
+}
+
+/// The patch of the metadata (and EVM chain id and DAO proposal url) of an appchain.
+/// The fields which are not provided are unchanged.
+///
+/// Only the optional fields (`description`, `evm_chain_id`, `dao_proposal_url`,
+/// `function_spec_url`, `github_release`, `premined_wrapped_appchain_token_beneficiary`
+/// and `custom_metadata`) can be cleared.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Default)]
+#[serde(crate = "near_sdk::serde", default)]
+pub struct AppchainMetadataPatch {
+    pub description: FieldPatch<String>,
+    pub template_type: FieldPatch<AppchainTemplateType>,
+    pub evm_chain_id: FieldPatch<U64>,
+    pub dao_proposal_url: FieldPatch<String>,
+    pub website_url: FieldPatch<String>,
+    pub function_spec_url: FieldPatch<String>,
+    pub github_address: FieldPatch<String>,
+    pub github_release: FieldPatch<String>,
+    pub contact_email: FieldPatch<String>,
+    pub premined_wrapped_appchain_token_beneficiary: FieldPatch<AccountId>,
+    pub premined_wrapped_appchain_token: FieldPatch<U128>,
+    pub initial_supply_of_wrapped_appchain_token: FieldPatch<U128>,
+    pub ido_amount_of_wrapped_appchain_token: FieldPatch<U128>,
+    pub initial_era_reward: FieldPatch<U128>,
+    pub fungible_token_metadata: FieldPatch<FungibleTokenMetadata>,
+    /// The patch of the whole custom metadata, which is applied before `custom_metadata_entries`.
+    pub custom_metadata: FieldPatch<HashMap<String, String>>,
+    /// The patches of single entries of custom metadata. Clearing an entry removes the key.
+    pub custom_metadata_entries: HashMap<String, FieldPatch<String>>,
+}
+
+/// The request of an appchain owner for changing the metadata of the appchain,
+/// which needs to be approved by appchain lifecycle manager
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainMetadataChangeRequest {
+    pub request_id: U64,
+    pub appchain_id: AppchainId,
+    pub requested_by: AccountId,
+    pub patch: AppchainMetadataPatch,
+    pub requested_timestamp: U64,
+}
+
+/// The values of the updatable fields of an appchain
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainMetadataSnapshot {
+    pub metadata: AppchainMetadata,
+    pub evm_chain_id: Option<U64>,
+    pub dao_proposal_url: Option<String>,
+}
+
+/// The change of a field in a revision of appchain metadata
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainMetadataFieldChange {
+    pub field: AppchainMetadataField,
+    /// The value (in JSON) of the field before the change.
+    pub old_value: String,
+    /// The value (in JSON) of the field after the change.
+    pub new_value: String,
+}
+
+/// A revision of the metadata of an appchain, which records all of the fields changed at once
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainMetadataRevision {
+    pub appchain_id: AppchainId,
+    /// The number of the revision, starting from 1 for each appchain.
+    pub revision: U64,
+    pub changes: Vec<AppchainMetadataFieldChange>,
+    /// The account which made the changes.
+    pub actor: AccountId,
+    /// The id of the approved change request which the changes come from (if any).
+    pub change_request_id: Option<U64>,
+    pub block_height: U64,
+    pub timestamp: U64,
+}
+
+/// The verdict of an audit of an appchain
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AuditVerdict {
+    Approved,
+    Rejected,
+}
+
+/// The record of an audit of an appchain, submitted by an auditor
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainAuditRecord {
+    pub appchain_id: AppchainId,
+    pub auditor: AccountId,
+    pub report_url: String,
+    /// The sha256 hash (in lowercase hex) of the audit report.
+    pub report_sha256: String,
+    pub verdict: AuditVerdict,
+    pub submitted_timestamp: U64,
+}
+
+/// The actions in the review trail of an appchain
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AppchainReviewAction {
+    Rejected,
+    Withdrawn,
+    AppealFiled,
+    AppealDismissed,
+    Reinstated,
+    Removed,
+}
+
+/// A record in the review trail of an appchain
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainReviewRecord {
+    pub appchain_id: AppchainId,
+    pub action: AppchainReviewAction,
+    /// The account which performed the action.
+    pub actor: AccountId,
+    /// The reason of the action, or the statement of an appeal.
+    pub reason: Option<String>,
+    /// The state of the appchain before the action.
+    pub previous_state: AppchainState,
+    pub timestamp: U64,
+}
+
+/// The metrics of an appchain, which are synced by appchain anchor
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainSyncMetrics {
+    /// The latest era number of the appchain
+    pub era_number: U64,
+    /// The height of the latest finalized block of the appchain
+    pub finalized_block_height: U64,
+    /// The total rewards distributed by the appchain
+    pub total_rewards_distributed: U128,
+    /// The total supply of wrapped appchain token
+    pub wrapped_token_supply: U128,
+    /// The version of anchor contract
+    pub anchor_version: String,
+}
+
+/// The health of an appchain, which is judged by the liveness of its anchor
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AppchainHealth {
+    Healthy,
+    /// The anchor of the appchain has not synced within the staleness threshold
+    Stale,
+}
+
+/// The liveness of the anchor of an appchain
+///
+/// This struct should NOT be used in storage on chain
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainLiveness {
+    pub appchain_id: AppchainId,
+    pub appchain_state: AppchainState,
+    pub appchain_anchor: Option<AccountId>,
+    /// The time since which the anchor should sync, which is the later one of
+    /// the latest sync and the start of booting
+    pub last_alive_time: U64,
+    pub health: AppchainHealth,
+}
+
+/// The statistics of the appchains in a certain state
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainStateStatistics {
+    pub appchain_state: AppchainState,
+    pub appchain_count: u32,
+    /// The sum of upvote deposits of the appchains
+    pub upvote_deposit: U128,
+    /// The sum of downvote deposits of the appchains
+    pub downvote_deposit: U128,
+    /// The sum of total stake of the appchains
+    pub total_stake: U128,
+    /// The sum of validator count of the appchains
+    pub validator_count: U64,
+}
+
+/// The statistics of the registry
+///
+/// This struct should NOT be used in storage on chain
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RegistryStatistics {
+    /// The statistics of each appchain state, in the order of lifecycle
+    pub appchain_states: Vec<AppchainStateStatistics>,
+    /// The sum of register deposits collected in all registrations
+    pub total_register_deposit: U128,
+    /// The number of the accounts which own appchains
+    pub distinct_owner_count: U64,
+    /// The number of the accounts which have upvote or downvote deposits
+    pub distinct_voter_count: U64,
+}
+
+/// The kinds of the changes of appchains in the change feed
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AppchainChangeKind {
+    Registered,
+    /// The metadata, EVM chain id or DAO proposal url is changed
+    MetadataUpdated,
+    OwnerChanged,
+    StateChanged,
+    /// The upvote deposit or downvote deposit is changed
+    VoteDepositChanged,
+    /// The state, staking status and metrics are synced by appchain anchor
+    AnchorSynced,
+    AnchorChanged,
+    TagsChanged,
+    /// The appchain is flagged as stale, or the flag is cleared by a sync of appchain anchor
+    HealthChanged,
+    Removed,
+}
+
+/// A change of an appchain in the change feed
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainChange {
+    /// The global sequence number of the change
+    pub sequence: U64,
+    pub appchain_id: AppchainId,
+    pub kind: AppchainChangeKind,
+    pub timestamp: U64,
+}
+
+/// The changes of appchains after a certain sequence number
+///
+/// This struct should NOT be used in storage on chain
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainChangeFeed {
+    /// The changes in ascending order of sequence number
+    pub changes: Vec<AppchainChange>,
+    /// The sequence number of the latest change in the registry
+    pub latest_sequence: U64,
+}
+
+/// A proposal of the tags of an appchain by the appchain owner,
+/// which needs to be approved by appchain lifecycle manager
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainTagProposal {
+    pub appchain_id: AppchainId,
+    /// The full list of proposed tags, which will replace the current tags of the appchain
+    pub tags: Vec<String>,
+    pub proposer: AccountId,
+    pub proposed_timestamp: U64,
+}
+
 /// The state of an appchain
-#[derive(Clone, Serialize, Deserialize, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+#[derive(
+    Clone,
+    Serialize,
+    Deserialize,
+    BorshDeserialize,
+    BorshSerialize,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
 #[serde(crate = "near_sdk::serde")]
 pub enum AppchainState {
     Registered,
@@ -85,23 +525,137 @@ pub struct AppchainStatus {
     pub validator_count: u32,
     pub total_stake: U128,
     pub dao_proposal_url: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The metrics synced by appchain anchor, `None` if they have never been synced
+    #[serde(default)]
+    pub sync_metrics: Option<AppchainSyncMetrics>,
+    /// The time of the latest sync from appchain anchor, `0` if it has never synced
+    pub last_sync_time: U64,
+    pub health: AppchainHealth,
 }
 
+/// A lightweight projection of appchain status, which doesn't contain the metadata
+///
+/// This struct should NOT be used in storage on chain
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainSummary {
+    pub appchain_id: AppchainId,
+    pub appchain_owner: AccountId,
+    pub appchain_state: AppchainState,
+    pub voting_score: I128,
+    pub registered_time: U64,
+    pub go_live_time: U64,
+    pub validator_count: u32,
+    pub total_stake: U128,
+}
+
+/// The result of looking up an appchain in a batch view
+///
+/// This struct should NOT be used in storage on chain
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
+pub struct AppchainLookupResult<T> {
+    pub appchain_id: AppchainId,
+    /// The found item, `None` if the appchain is not registered
+    pub item: Option<T>,
+}
+
+/// The owner of an appchain, for the queries from other contracts
+///
+/// A new version is added as a new variant, so that the existing callers can keep
+/// deserializing the versions they know. This enum should NOT be used in storage on chain
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "version")]
+pub enum VersionedAppchainOwner {
+    V1 {
+        appchain_id: AppchainId,
+        owner: AccountId,
+    },
+}
+
+/// The anchor of an appchain, for the queries from other contracts
+///
+/// A new version is added as a new variant, so that the existing callers can keep
+/// deserializing the versions they know. This enum should NOT be used in storage on chain
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "version")]
+pub enum VersionedAppchainAnchor {
+    V1 {
+        appchain_id: AppchainId,
+        appchain_state: AppchainState,
+        /// The account of anchor, `None` if the appchain has not started booting
+        anchor: Option<AccountId>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
 pub enum AppchainSortingField {
     AppchainId,
     VotingScore,
     RegisteredTime,
+    TotalStake,
+    ValidatorCount,
+    GoLiveTime,
+    UpvoteDeposit,
+    DownvoteDeposit,
+    /// The appchains are ordered by their states in lifecycle, from `Registered` to `Closed`
+    AppchainState,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
 pub enum SortingOrder {
     Ascending,
     Descending,
 }
 
+/// A key for sorting appchains
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainSortingSpec {
+    pub field: AppchainSortingField,
+    pub order: SortingOrder,
+}
+
+/// The conditions for filtering appchains, an appchain should meet all of the given conditions
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AppchainFilter {
+    /// The template type of the appchain should be one of these
+    pub template_types: Option<Vec<AppchainTemplateType>>,
+    /// The owner of the appchain
+    pub owner: Option<AccountId>,
+    /// Whether the appchain has an EVM chain id
+    pub has_evm_chain_id: Option<bool>,
+    /// The appchain should be registered at or after this time
+    pub registered_time_from: Option<U64>,
+    /// The appchain should be registered before this time
+    pub registered_time_to: Option<U64>,
+    /// The custom metadata of the appchain should contain all of these keys with equal values
+    pub custom_metadata: Option<HashMap<String, String>>,
+    /// The appchain should have all of these tags
+    pub tags: Option<Vec<String>>,
+}
+
+/// A page of the results of a listing view
+///
+/// This struct should NOT be used in storage on chain
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PagedResult<T> {
+    /// The items in this page
+    pub items: Vec<T>,
+    /// The index to query the next page from, `None` if this is the last page
+    pub next_index: Option<U64>,
+    /// The total count of the items matching the query
+    pub total_count: U64,
+}
+
 impl AppchainState {
     /// Get whether the state is managed by appchain anchor
     pub fn is_managed_by_anchor(&self) -> bool {
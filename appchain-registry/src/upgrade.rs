@@ -9,17 +9,23 @@ const GAS_FOR_UPGRADE_SELF_DEPLOY: Gas = Gas(15_000_000_000_000);
 #[no_mangle]
 pub extern "C" fn store_wasm_of_self() {
     env::setup_panic_hook();
-    let contract: AppchainRegistry = env::state_read().expect("ERR_CONTRACT_IS_NOT_INITIALIZED");
+    let contract: AppchainRegistry =
+        env::state_read().unwrap_or_else(|| panic_with(RegistryError::ContractNotInitialized));
     contract.assert_owner();
-    let input = env::input().expect("ERR_NO_INPUT");
+    let input = env::input().unwrap_or_else(|| {
+        panic_with(RegistryError::MissingField {
+            field: "input".to_string(),
+        })
+    });
     let sha256_hash = env::sha256(&input);
 
     let blob_len = input.len();
     let storage_cost = ((blob_len + 32) as u128) * env::storage_byte_cost();
-    assert!(
+    ensure!(
         env::attached_deposit() >= storage_cost,
-        "ERR_NOT_ENOUGH_DEPOSIT:{}",
-        storage_cost
+        RegistryError::NotEnoughStorageDeposit {
+            required_amount: U128::from(storage_cost)
+        }
     );
 
     env::storage_write(&StorageKey::RegistryContractWasm.into_bytes(), &input);
@@ -35,17 +41,22 @@ pub extern "C" fn store_wasm_of_self() {
 #[no_mangle]
 pub fn update_self() {
     env::setup_panic_hook();
-    let contract: AppchainRegistry = env::state_read().expect("ERR_CONTRACT_IS_NOT_INITIALIZED");
+    let contract: AppchainRegistry =
+        env::state_read().unwrap_or_else(|| panic_with(RegistryError::ContractNotInitialized));
     contract.assert_owner();
     let current_id = env::current_account_id();
     let input = env::storage_read(&StorageKey::RegistryContractWasm.into_bytes())
-        .expect("Wasm file for deployment is not staged yet.");
+        .unwrap_or_else(|| panic_with(RegistryError::ContractCodeNotStaged));
+    // The arguments of this function are passed to function `migrate_state`.
+    let migration_args = env::input()
+        .filter(|args| !args.is_empty())
+        .unwrap_or_else(|| b"{}".to_vec());
     let promise_id = env::promise_batch_create(&current_id);
     env::promise_batch_action_deploy_contract(promise_id, &input);
     env::promise_batch_action_function_call(
         promise_id,
         "migrate_state",
-        &[],
+        &migration_args,
         0,
         env::prepaid_gas() - env::used_gas() - GAS_FOR_UPGRADE_SELF_DEPLOY,
     );
@@ -1,32 +1,24 @@
 use crate::{types::AppchainId, *};
+use appchain_reviews::assert_valid_review_reason;
 use near_sdk::AccountId;
+use types::AppchainReviewAction;
 
 pub trait AppchainLifecycleManager {
-    /// Update metadata of an appchain
-    fn update_appchain_metadata(
-        &mut self,
-        appchain_id: AppchainId,
-        description: Option<String>,
-        template_type: Option<AppchainTemplateType>,
-        evm_chain_id: Option<U64>,
-        dao_proposal_url: Option<String>,
-        website_url: Option<String>,
-        function_spec_url: Option<String>,
-        github_address: Option<String>,
-        github_release: Option<String>,
-        contact_email: Option<String>,
-        premined_wrapped_appchain_token_beneficiary: Option<AccountId>,
-        premined_wrapped_appchain_token: Option<U128>,
-        initial_supply_of_wrapped_appchain_token: Option<U128>,
-        ido_amount_of_wrapped_appchain_token: Option<U128>,
-        initial_era_reward: Option<U128>,
-        fungible_token_metadata: Option<FungibleTokenMetadata>,
-        custom_metadata: Option<HashMap<String, String>>,
-    );
-    /// Pass auditing of an appchain
+    /// Update metadata of an appchain by a patch.
+    fn update_appchain_metadata(&mut self, appchain_id: AppchainId, patch: AppchainMetadataPatch);
+    /// Approve a pending change request of appchain metadata, and apply the patch of it
+    fn approve_appchain_metadata_change_request(&mut self, request_id: U64);
+    /// Reject a pending change request of appchain metadata
+    fn reject_appchain_metadata_change_request(&mut self, request_id: U64);
+    /// Pass auditing of an appchain, which needs enough approving audits of auditors
     fn pass_auditing_appchain(&mut self, appchain_id: AppchainId);
-    /// Reject an appchain
-    fn reject_appchain(&mut self, appchain_id: AppchainId);
+    /// Reject an appchain with the given reason.
+    /// The appchain owner can appeal the rejection within `appeal window` of registry settings.
+    fn reject_appchain(&mut self, appchain_id: AppchainId, reason: String);
+    /// Reinstate an appchain with a pending appeal to the state before its rejection
+    fn reinstate_appchain(&mut self, appchain_id: AppchainId, reason: Option<String>);
+    /// Dismiss the pending appeal of an appchain, the appchain stays closed
+    fn dismiss_appchain_appeal(&mut self, appchain_id: AppchainId, reason: String);
     /// Start voting of an appchain
     fn start_voting_appchain(&mut self, appchain_id: AppchainId, dao_proposal_url: String);
     /// Change the state of a given appchain to 'booting',
@@ -34,163 +26,23 @@ pub trait AppchainLifecycleManager {
     fn start_booting_appchain(&mut self, appchain_id: AppchainId);
     /// Remove an appchain from registry
     fn remove_appchain(&mut self, appchain_id: AppchainId);
+    /// Approve the pending tag proposal of an appchain, and apply the tags of it
+    fn approve_appchain_tag_proposal(&mut self, appchain_id: AppchainId);
+    /// Reject the pending tag proposal of an appchain
+    fn reject_appchain_tag_proposal(&mut self, appchain_id: AppchainId);
+    /// Set the tags of an appchain directly.
+    /// The tags should be in `appchain categories` of registry settings.
+    fn set_appchain_tags(&mut self, appchain_id: AppchainId, tags: Vec<String>);
 }
 
 #[near_bindgen]
 impl AppchainLifecycleManager for AppchainRegistry {
     //
-    fn update_appchain_metadata(
-        &mut self,
-        appchain_id: AppchainId,
-        description: Option<String>,
-        template_type: Option<AppchainTemplateType>,
-        evm_chain_id: Option<U64>,
-        dao_proposal_url: Option<String>,
-        website_url: Option<String>,
-        function_spec_url: Option<String>,
-        github_address: Option<String>,
-        github_release: Option<String>,
-        contact_email: Option<String>,
-        premined_wrapped_appchain_token_beneficiary: Option<AccountId>,
-        premined_wrapped_appchain_token: Option<U128>,
-        initial_supply_of_wrapped_appchain_token: Option<U128>,
-        ido_amount_of_wrapped_appchain_token: Option<U128>,
-        initial_era_reward: Option<U128>,
-        fungible_token_metadata: Option<FungibleTokenMetadata>,
-        custom_metadata: Option<HashMap<String, String>>,
-    ) {
+    fn update_appchain_metadata(&mut self, appchain_id: AppchainId, patch: AppchainMetadataPatch) {
         self.assert_appchain_lifecycle_manager();
-        let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
-        let mut metadata = appchain_basedata.metadata();
-        if let Some(description) = description {
-            assert!(
-                !metadata.description.eq(&description),
-                "The description is not changed."
-            );
-            metadata.description = description;
-        }
-        if let Some(template_type) = template_type {
-            assert!(
-                !metadata.template_type.eq(&template_type),
-                "The template type is not changed."
-            );
-            metadata.template_type = template_type;
-        }
-        if let Some(evm_chain_id) = evm_chain_id {
-            assert!(
-                !appchain_basedata.evm_chain_id.unwrap_or(U64::from(0)).0 == evm_chain_id.0,
-                "The evm chain id is not changed."
-            );
-            appchain_basedata.evm_chain_id = Some(evm_chain_id);
-        }
-        if let Some(dao_proposal_url) = dao_proposal_url {
-            assert!(
-                !appchain_basedata
-                    .dao_proposal_url
-                    .unwrap_or(String::new())
-                    .eq(&dao_proposal_url),
-                "The website url is not changed."
-            );
-            appchain_basedata.dao_proposal_url = Some(dao_proposal_url);
-        }
-        if let Some(website_url) = website_url {
-            assert!(
-                !metadata.website_url.eq(&website_url),
-                "The website url is not changed."
-            );
-            metadata.website_url = website_url;
-        }
-        if let Some(function_spec_url) = function_spec_url {
-            assert!(
-                !metadata.function_spec_url.eq(&function_spec_url),
-                "The function spec url is not changed."
-            );
-            metadata.function_spec_url = function_spec_url;
-        }
-        if let Some(github_address) = github_address {
-            assert!(
-                !metadata.github_address.eq(&github_address),
-                "The github address is not changed."
-            );
-            metadata.github_address = github_address;
-        }
-        if let Some(github_release) = github_release {
-            assert!(
-                !metadata.github_release.eq(&github_release),
-                "The github release is not changed."
-            );
-            metadata.github_release = github_release;
-        }
-        if let Some(contact_email) = contact_email {
-            assert!(
-                !metadata.contact_email.eq(&contact_email),
-                "The contact email is not changed."
-            );
-            metadata.contact_email = contact_email;
-        }
-        if let Some(premined_wrapped_appchain_token_beneficiary) =
-            premined_wrapped_appchain_token_beneficiary
-        {
-            assert!(
-                !metadata
-                    .premined_wrapped_appchain_token_beneficiary
-                    .map_or(AccountId::new_unchecked("".to_string()), |f| f)
-                    .eq(&premined_wrapped_appchain_token_beneficiary),
-                "The premined wrapped appchain token beneficiary is not changed."
-            );
-            metadata.premined_wrapped_appchain_token_beneficiary =
-                Some(premined_wrapped_appchain_token_beneficiary);
-        }
-        if let Some(premined_wrapped_appchain_token) = premined_wrapped_appchain_token {
-            assert!(
-                !metadata
-                    .premined_wrapped_appchain_token
-                    .eq(&premined_wrapped_appchain_token),
-                "The premined wrapped appchain token is not changed."
-            );
-            metadata.premined_wrapped_appchain_token = premined_wrapped_appchain_token;
-        }
-        if let Some(initial_supply_of_wrapped_appchain_token) =
-            initial_supply_of_wrapped_appchain_token
-        {
-            assert!(
-                !metadata
-                    .initial_supply_of_wrapped_appchain_token
-                    .eq(&initial_supply_of_wrapped_appchain_token),
-                "The initial supply of wrapped appchain token is not changed."
-            );
-            assert!(
-                initial_supply_of_wrapped_appchain_token.0 >= metadata.premined_wrapped_appchain_token.0,
-                "The initial supply of wrapped appchain token should not be less than the premined amount."
-            );
-            metadata.initial_supply_of_wrapped_appchain_token =
-                initial_supply_of_wrapped_appchain_token;
-        }
-        if let Some(ido_amount_of_wrapped_appchain_token) = ido_amount_of_wrapped_appchain_token {
-            assert!(
-                !metadata
-                    .ido_amount_of_wrapped_appchain_token
-                    .eq(&ido_amount_of_wrapped_appchain_token),
-                "The ido amount of wrapped appchain token is not changed."
-            );
-            metadata.ido_amount_of_wrapped_appchain_token = ido_amount_of_wrapped_appchain_token;
-        }
-        if let Some(initial_era_reward) = initial_era_reward {
-            assert!(
-                !metadata.initial_era_reward.eq(&initial_era_reward),
-                "The initial era reward is not changed."
-            );
-            metadata.initial_era_reward = initial_era_reward;
-        }
-        if let Some(fungible_token_metadata) = fungible_token_metadata {
-            metadata.fungible_token_metadata = fungible_token_metadata;
-        }
-        if let Some(custom_metadata) = custom_metadata {
-            metadata.custom_metadata = custom_metadata;
-        }
-        appchain_basedata.set_metadata(metadata);
-        self.appchain_basedatas
-            .insert(&appchain_id, &appchain_basedata);
+        let initial_storage_usage = env::storage_usage();
+        let appchain_basedata = self.internal_update_appchain_metadata(&appchain_id, patch, None);
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
         log!(
             "The metadata of appchain '{}' is updated by '{}'.",
             appchain_basedata.id(),
@@ -198,17 +50,58 @@ impl AppchainLifecycleManager for AppchainRegistry {
         );
     }
     //
+    fn approve_appchain_metadata_change_request(&mut self, request_id: U64) {
+        self.assert_appchain_lifecycle_manager();
+        let request = self.get_metadata_change_request(request_id.0);
+        let initial_storage_usage = env::storage_usage();
+        self.internal_remove_metadata_change_request(&request);
+        let appchain_basedata = self.internal_update_appchain_metadata(
+            &request.appchain_id,
+            request.patch,
+            Some(request_id.0),
+        );
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Metadata change request '{}' of appchain '{}' is approved by '{}'.",
+            request_id.0,
+            request.appchain_id,
+            env::predecessor_account_id()
+        );
+    }
+    //
+    fn reject_appchain_metadata_change_request(&mut self, request_id: U64) {
+        self.assert_appchain_lifecycle_manager();
+        let request = self.get_metadata_change_request(request_id.0);
+        let initial_storage_usage = env::storage_usage();
+        self.internal_remove_metadata_change_request(&request);
+        let appchain_basedata = self.get_appchain_basedata(&request.appchain_id);
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Metadata change request '{}' of appchain '{}' is rejected by '{}'.",
+            request_id.0,
+            request.appchain_id,
+            env::predecessor_account_id()
+        );
+    }
+    //
     fn pass_auditing_appchain(&mut self, appchain_id: AppchainId) {
         self.assert_appchain_lifecycle_manager();
         self.assert_appchain_state(&appchain_id, [AppchainState::Registered].to_vec());
+        self.assert_enough_approving_audits(&appchain_id);
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.set_state(AppchainState::Audited);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::StateChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
         log_appchain_state(&appchain_basedata);
     }
     //
-    fn reject_appchain(&mut self, appchain_id: AppchainId) {
+    fn reject_appchain(&mut self, appchain_id: AppchainId, reason: String) {
         self.assert_appchain_lifecycle_manager();
         self.assert_appchain_state(
             &appchain_id,
@@ -219,25 +112,111 @@ impl AppchainLifecycleManager for AppchainRegistry {
             ]
             .to_vec(),
         );
+        assert_valid_review_reason("reason", &reason);
+        let initial_storage_usage = env::storage_usage();
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_state = appchain_basedata.state();
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.set_state(AppchainState::Closed);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::StateChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
+        self.internal_record_review(
+            &appchain_id,
+            AppchainReviewAction::Rejected,
+            Some(reason),
+            previous_state,
+        );
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log_appchain_state(&appchain_basedata);
+    }
+    //
+    fn reinstate_appchain(&mut self, appchain_id: AppchainId, reason: Option<String>) {
+        self.assert_appchain_lifecycle_manager();
+        if let Some(reason) = &reason {
+            assert_valid_review_reason("reason", reason);
+        }
+        let rejection = self.get_appealed_rejection(&appchain_id);
+        let initial_storage_usage = env::storage_usage();
+        let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_state = appchain_basedata.state();
+        let previous_statistics_values = appchain_basedata.statistics_values();
+        appchain_basedata.set_state(rejection.previous_state);
+        self.appchain_basedatas
+            .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::StateChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
+        self.internal_record_review(
+            &appchain_id,
+            AppchainReviewAction::Reinstated,
+            reason,
+            previous_state,
+        );
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
         log_appchain_state(&appchain_basedata);
     }
     //
+    fn dismiss_appchain_appeal(&mut self, appchain_id: AppchainId, reason: String) {
+        self.assert_appchain_lifecycle_manager();
+        assert_valid_review_reason("reason", &reason);
+        ensure!(
+            self.has_pending_appeal(&appchain_id),
+            RegistryError::AppchainAppealNotFound {
+                appchain_id: appchain_id.clone()
+            }
+        );
+        let initial_storage_usage = env::storage_usage();
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        self.internal_record_review(
+            &appchain_id,
+            AppchainReviewAction::AppealDismissed,
+            Some(reason),
+            appchain_basedata.state(),
+        );
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "The appeal of appchain '{}' is dismissed by '{}'.",
+            appchain_id,
+            env::predecessor_account_id()
+        );
+    }
+    //
     fn start_voting_appchain(&mut self, appchain_id: AppchainId, dao_proposal_url: String) {
         self.assert_appchain_lifecycle_manager();
         self.assert_appchain_state(&appchain_id, [AppchainState::Audited].to_vec());
-        assert!(
+        ensure!(
             !dao_proposal_url.trim().is_empty(),
-            "The DAO proposal url can not be blank."
+            RegistryError::MissingField {
+                field: "dao_proposal_url".to_string()
+            }
         );
+        let initial_storage_usage = env::storage_usage();
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_snapshot = appchain_basedata.metadata_snapshot();
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.set_state(AppchainState::Voting);
         appchain_basedata.dao_proposal_url = Some(dao_proposal_url);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::StateChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
+        self.internal_record_metadata_revision(
+            &appchain_id,
+            &previous_snapshot,
+            &appchain_basedata.metadata_snapshot(),
+            None,
+        );
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
         log_appchain_state(&appchain_basedata);
     }
     //
@@ -248,30 +227,61 @@ impl AppchainLifecycleManager for AppchainRegistry {
         let sub_account_id =
             AccountId::try_from(format!("{}.{}", &appchain_id, env::current_account_id())).unwrap();
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.set_state(AppchainState::Booting);
+        self.internal_set_appchain_anchor(&mut appchain_basedata, sub_account_id.clone());
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::StateChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
         log_appchain_state(&appchain_basedata);
         //
+        let anchor_init_balance = self
+            .get_appchain_template(&appchain_basedata.metadata().template_type)
+            .anchor_init_balance
+            .0;
         Promise::new(sub_account_id.clone())
             .create_account()
-            .transfer(APPCHAIN_ANCHOR_INIT_BALANCE)
+            .transfer(anchor_init_balance)
             .add_full_access_key(self.owner_pk.clone());
     }
     //
     fn remove_appchain(&mut self, appchain_id: AppchainId) {
         self.assert_appchain_lifecycle_manager();
         self.assert_appchain_state(&appchain_id, [AppchainState::Closed].to_vec());
+        ensure!(
+            !self.has_pending_appeal(&appchain_id),
+            RegistryError::AppchainAppealPending {
+                appchain_id: appchain_id.clone()
+            }
+        );
+        // The owner of a rejected appchain should have the chance to appeal the rejection.
+        if let Some(appeal_deadline) = self.get_appeal_deadline(&appchain_id) {
+            ensure!(
+                env::block_timestamp() > appeal_deadline,
+                RegistryError::AppealWindowNotExpired {
+                    appchain_id: appchain_id.clone(),
+                    appeal_deadline: appeal_deadline.into(),
+                }
+            );
+        }
         let appchain_basedata = self.get_appchain_basedata(&appchain_id);
-        assert!(
+        ensure!(
             appchain_basedata.upvote_deposit() == 0,
-            "The appchain still has upvote deposit(s)."
+            RegistryError::AppchainHasUpvoteDeposit {
+                appchain_id: appchain_id.clone()
+            }
         );
-        assert!(
+        ensure!(
             appchain_basedata.downvote_deposit() == 0,
-            "The appchain still has downvote deposit(s)."
+            RegistryError::AppchainHasDownvoteDeposit {
+                appchain_id: appchain_id.clone()
+            }
         );
-        if !appchain_basedata.anchor().is_none() {
+        if appchain_basedata.anchor().is_some() {
             let anchor_account_id = format!("{}.{}", &appchain_id, env::current_account_id());
             log!(
                 "The anchor contract '{}' of appchain '{}' needs to be removed manually.",
@@ -282,6 +292,59 @@ impl AppchainLifecycleManager for AppchainRegistry {
         self.internal_remove_appchain(&appchain_id);
         log!("Appchain '{}' is removed from registry.", &appchain_id);
     }
+    //
+    fn approve_appchain_tag_proposal(&mut self, appchain_id: AppchainId) {
+        self.assert_appchain_lifecycle_manager();
+        let proposal = self.get_appchain_tag_proposal(&appchain_id);
+        // The categories may be changed after the proposal is made.
+        self.assert_valid_appchain_tags(&proposal.tags);
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let initial_storage_usage = env::storage_usage();
+        self.appchain_tag_proposals.remove(&appchain_id);
+        self.internal_set_appchain_tags(&appchain_basedata, proposal.tags);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::TagsChanged);
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Tag proposal of appchain '{}' is approved by '{}'.",
+            appchain_id,
+            env::predecessor_account_id()
+        );
+    }
+    //
+    fn reject_appchain_tag_proposal(&mut self, appchain_id: AppchainId) {
+        self.assert_appchain_lifecycle_manager();
+        self.get_appchain_tag_proposal(&appchain_id);
+        let initial_storage_usage = env::storage_usage();
+        self.appchain_tag_proposals.remove(&appchain_id);
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Tag proposal of appchain '{}' is rejected by '{}'.",
+            appchain_id,
+            env::predecessor_account_id()
+        );
+    }
+    //
+    fn set_appchain_tags(&mut self, appchain_id: AppchainId, tags: Vec<String>) {
+        self.assert_appchain_lifecycle_manager();
+        self.assert_valid_appchain_tags(&tags);
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        ensure!(
+            !appchain_basedata.tags().eq(&tags),
+            RegistryError::FieldNotChanged {
+                field: "tags".to_string()
+            }
+        );
+        let initial_storage_usage = env::storage_usage();
+        self.internal_set_appchain_tags(&appchain_basedata, tags);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::TagsChanged);
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Tags of appchain '{}' are set by '{}'.",
+            appchain_id,
+            env::predecessor_account_id()
+        );
+    }
 }
 
 fn log_appchain_state(appchain_basedata: &AppchainBasedata) {
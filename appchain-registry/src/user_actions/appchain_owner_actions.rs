@@ -1,5 +1,7 @@
 use crate::*;
+use appchain_reviews::assert_valid_review_reason;
 use near_sdk::{near_bindgen, AccountId};
+use types::{AppchainMetadataPatch, AppchainReviewAction, AppchainTagProposal};
 
 /// The actions which the owner of an appchain can perform
 pub trait AppchainOwnerActions {
@@ -8,7 +10,36 @@ pub trait AppchainOwnerActions {
     /// Withdraw the go live request of an appchain.
     /// Can be called by the appchain owner while the appchain state is 'registered' or 'audited'.
     /// After the withdrawal, the appchain's state will change to 'Closed'.
-    fn withdraw_appchain(&mut self, appchain_id: AppchainId);
+    fn withdraw_appchain(&mut self, appchain_id: AppchainId, reason: Option<String>);
+    /// Appeal the rejection of an appchain with a statement, within `appeal window`
+    /// of registry settings after the rejection.
+    /// The attached NEAR (if any) is added to the storage balance of the appchain owner.
+    fn appeal_appchain_rejection(&mut self, appchain_id: AppchainId, statement: String);
+    /// Update the metadata of an appchain directly by a patch.
+    /// Only the fields in `owner updatable metadata fields` of registry settings can be patched.
+    /// The attached NEAR (if any) is added to the storage balance of the appchain owner.
+    fn update_appchain_metadata_by_owner(
+        &mut self,
+        appchain_id: AppchainId,
+        patch: AppchainMetadataPatch,
+    );
+    /// Request to patch the metadata of an appchain, which needs to be approved by
+    /// appchain lifecycle manager. Return the id of the request.
+    /// The attached NEAR (if any) is added to the storage balance of the appchain owner.
+    fn request_appchain_metadata_change(
+        &mut self,
+        appchain_id: AppchainId,
+        patch: AppchainMetadataPatch,
+    ) -> U64;
+    /// Cancel a pending change request of appchain metadata.
+    fn cancel_appchain_metadata_change_request(&mut self, request_id: U64);
+    /// Propose the tags of an appchain, which needs to be approved by appchain lifecycle manager.
+    /// The tags should be in `appchain categories` of registry settings.
+    /// A new proposal replaces the pending one (if any) of the appchain.
+    /// The attached NEAR (if any) is added to the storage balance of the appchain owner.
+    fn propose_appchain_tags(&mut self, appchain_id: AppchainId, tags: Vec<String>);
+    /// Cancel the pending tag proposal of an appchain.
+    fn cancel_appchain_tag_proposal(&mut self, appchain_id: AppchainId);
 }
 
 #[near_bindgen]
@@ -16,10 +47,22 @@ impl AppchainOwnerActions for AppchainRegistry {
     //
     fn transfer_appchain_ownership(&mut self, appchain_id: AppchainId, new_owner: AccountId) {
         self.assert_appchain_owner(&appchain_id);
+        let initial_storage_usage = env::storage_usage();
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_owner = appchain_basedata.owner();
         appchain_basedata.change_owner(new_owner);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::OwnerChanged);
+        self.internal_update_owner_statistics(
+            Some(&previous_owner),
+            Some(&appchain_basedata.owner()),
+        );
+        // The storage used by the appchain is moved to the new owner.
+        let (previous_usage, current_usage) =
+            self.internal_measure_appchain_storage_usage(&appchain_id, initial_storage_usage);
+        self.internal_update_storage_used_by(&previous_owner, previous_usage, 0);
+        self.internal_update_storage_used_by(&appchain_basedata.owner(), 0, current_usage);
         log!(
             "The ownership of appchain '{}' is transfered to '{}'.",
             appchain_basedata.id(),
@@ -27,23 +70,166 @@ impl AppchainOwnerActions for AppchainRegistry {
         );
     }
     //
-    fn withdraw_appchain(&mut self, appchain_id: AppchainId) {
+    fn withdraw_appchain(&mut self, appchain_id: AppchainId, reason: Option<String>) {
         self.assert_appchain_owner(&appchain_id);
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
-        assert!(
+        ensure!(
             appchain_basedata.state().eq(&AppchainState::Registered)
                 || appchain_basedata.state().eq(&AppchainState::Audited),
-            "Appchain state must be '{}' or '{}'.",
-            AppchainState::Registered,
-            AppchainState::Audited,
+            RegistryError::InvalidAppchainState {
+                appchain_id: appchain_id.clone(),
+                state: appchain_basedata.state()
+            }
         );
+        if let Some(reason) = &reason {
+            assert_valid_review_reason("reason", reason);
+        }
+        let initial_storage_usage = env::storage_usage();
+        let previous_state = appchain_basedata.state();
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.appchain_state = AppchainState::Closed;
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::StateChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
+        self.internal_record_review(
+            &appchain_id,
+            AppchainReviewAction::Withdrawn,
+            reason,
+            previous_state,
+        );
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
         log!(
             "Go live request of appchain '{}' is withdrawn by '{}'.",
             appchain_basedata.id(),
             appchain_basedata.owner()
         );
     }
+    //
+    #[payable]
+    fn appeal_appchain_rejection(&mut self, appchain_id: AppchainId, statement: String) {
+        self.assert_appchain_owner(&appchain_id);
+        self.assert_appchain_appealable(&appchain_id);
+        assert_valid_review_reason("statement", &statement);
+        self.internal_deposit_attached_storage(&env::predecessor_account_id());
+        let initial_storage_usage = env::storage_usage();
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        self.internal_record_review(
+            &appchain_id,
+            AppchainReviewAction::AppealFiled,
+            Some(statement),
+            appchain_basedata.state(),
+        );
+        self.internal_charge_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "The rejection of appchain '{}' is appealed by '{}'.",
+            appchain_id,
+            appchain_basedata.owner()
+        );
+    }
+    //
+    #[payable]
+    fn update_appchain_metadata_by_owner(
+        &mut self,
+        appchain_id: AppchainId,
+        patch: AppchainMetadataPatch,
+    ) {
+        self.assert_appchain_owner(&appchain_id);
+        let registry_settings = self.registry_settings.get().unwrap();
+        for field in patch.patched_fields() {
+            ensure!(
+                registry_settings
+                    .owner_updatable_metadata_fields
+                    .contains(&field),
+                RegistryError::MetadataFieldNotUpdatableByOwner { field }
+            );
+        }
+        self.internal_deposit_attached_storage(&env::predecessor_account_id());
+        let initial_storage_usage = env::storage_usage();
+        let appchain_basedata = self.internal_update_appchain_metadata(&appchain_id, patch, None);
+        self.internal_charge_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "The metadata of appchain '{}' is updated by its owner '{}'.",
+            appchain_basedata.id(),
+            appchain_basedata.owner()
+        );
+    }
+    //
+    #[payable]
+    fn request_appchain_metadata_change(
+        &mut self,
+        appchain_id: AppchainId,
+        patch: AppchainMetadataPatch,
+    ) -> U64 {
+        self.assert_appchain_owner(&appchain_id);
+        self.internal_deposit_attached_storage(&env::predecessor_account_id());
+        let initial_storage_usage = env::storage_usage();
+        let request_id = self.internal_add_metadata_change_request(&appchain_id, patch);
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        self.internal_charge_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Metadata change request '{}' of appchain '{}' is submitted by '{}'.",
+            request_id,
+            appchain_id,
+            appchain_basedata.owner()
+        );
+        U64::from(request_id)
+    }
+    //
+    fn cancel_appchain_metadata_change_request(&mut self, request_id: U64) {
+        let request = self.get_metadata_change_request(request_id.0);
+        self.assert_appchain_owner(&request.appchain_id);
+        let initial_storage_usage = env::storage_usage();
+        self.internal_remove_metadata_change_request(&request);
+        let appchain_basedata = self.get_appchain_basedata(&request.appchain_id);
+        self.internal_charge_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Metadata change request '{}' of appchain '{}' is cancelled.",
+            request_id.0,
+            request.appchain_id
+        );
+    }
+    //
+    #[payable]
+    fn propose_appchain_tags(&mut self, appchain_id: AppchainId, tags: Vec<String>) {
+        self.assert_appchain_owner(&appchain_id);
+        self.assert_valid_appchain_tags(&tags);
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        ensure!(
+            !appchain_basedata.tags().eq(&tags),
+            RegistryError::FieldNotChanged {
+                field: "tags".to_string()
+            }
+        );
+        self.internal_deposit_attached_storage(&env::predecessor_account_id());
+        let initial_storage_usage = env::storage_usage();
+        self.appchain_tag_proposals.insert(
+            &appchain_id,
+            &AppchainTagProposal {
+                appchain_id: appchain_id.clone(),
+                tags,
+                proposer: env::predecessor_account_id(),
+                proposed_timestamp: env::block_timestamp().into(),
+            },
+        );
+        self.internal_charge_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Tags of appchain '{}' are proposed by '{}'.",
+            appchain_id,
+            appchain_basedata.owner()
+        );
+    }
+    //
+    fn cancel_appchain_tag_proposal(&mut self, appchain_id: AppchainId) {
+        self.assert_appchain_owner(&appchain_id);
+        self.get_appchain_tag_proposal(&appchain_id);
+        let initial_storage_usage = env::storage_usage();
+        self.appchain_tag_proposals.remove(&appchain_id);
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        self.internal_charge_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!("Tag proposal of appchain '{}' is cancelled.", appchain_id);
+    }
 }
@@ -0,0 +1,42 @@
+use crate::*;
+use types::AuditVerdict;
+
+/// The actions which the auditors of appchain registry can perform
+pub trait AuditorActions {
+    /// Submit the audit record of an appchain in state 'registered', with the url and
+    /// sha256 hash (in hex) of the audit report. A previous record of the same auditor
+    /// for the appchain is replaced.
+    fn submit_appchain_audit(
+        &mut self,
+        appchain_id: AppchainId,
+        report_url: String,
+        report_sha256: String,
+        verdict: AuditVerdict,
+    );
+}
+
+#[near_bindgen]
+impl AuditorActions for AppchainRegistry {
+    //
+    fn submit_appchain_audit(
+        &mut self,
+        appchain_id: AppchainId,
+        report_url: String,
+        report_sha256: String,
+        verdict: AuditVerdict,
+    ) {
+        self.assert_auditor();
+        self.assert_appchain_state(&appchain_id, [AppchainState::Registered].to_vec());
+        let initial_storage_usage = env::storage_usage();
+        let record =
+            self.internal_add_audit_record(&appchain_id, report_url, report_sha256, verdict);
+        let appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        self.internal_settle_appchain_storage(&appchain_basedata, initial_storage_usage);
+        log!(
+            "Appchain '{}' is audited by '{}' with verdict '{:?}'.",
+            appchain_id,
+            record.auditor,
+            record.verdict
+        );
+    }
+}
@@ -1,6 +1,9 @@
-mod appchain_lifecycle;
-mod appchain_owner_actions;
-mod registry_roles;
-mod registry_settings;
-mod sudo_actions;
-mod voter_actions;
+pub mod appchain_lifecycle;
+pub mod appchain_owner_actions;
+pub mod auditor_actions;
+pub mod registry_roles;
+pub mod registry_settings;
+pub mod sudo_actions;
+pub mod voter_actions;
+
+pub use registry_settings::builtin_appchain_templates;
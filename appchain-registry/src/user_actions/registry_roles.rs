@@ -6,19 +6,21 @@ impl Default for RegistryRoles {
             appchain_lifecycle_manager: env::signer_account_id(),
             registry_settings_manager: env::signer_account_id(),
             octopus_council: None,
+            auditors: Vec::new(),
         }
     }
 }
 
 impl RegistryRoles {
-    ///
+    //
     pub fn has_role(&self, account: &AccountId) -> bool {
         account.eq(&self.appchain_lifecycle_manager)
             || account.eq(&self.registry_settings_manager)
-            || account.eq(&self
+            || account.eq(self
                 .octopus_council
                 .as_ref()
                 .unwrap_or(&AccountId::new_unchecked(String::new())))
+            || self.auditors.contains(account)
     }
 }
 
@@ -28,9 +30,9 @@ impl AppchainRegistry {
     pub fn change_appchain_lifecycle_manager(&mut self, account: AccountId) {
         let caller = env::predecessor_account_id();
         let mut registry_roles = self.registry_roles.get().unwrap();
-        assert!(
+        ensure!(
             caller.eq(&registry_roles.appchain_lifecycle_manager) || caller.eq(&self.owner),
-            "This function can only be called by appchain lifecycle manager or the contract owner."
+            RegistryError::CallerIsNotAppchainLifecycleManager
         );
         self.assert_account_has_no_role(&account);
         registry_roles.appchain_lifecycle_manager = account;
@@ -40,9 +42,9 @@ impl AppchainRegistry {
     pub fn change_registry_settings_manager(&mut self, account: AccountId) {
         let caller = env::predecessor_account_id();
         let mut registry_roles = self.registry_roles.get().unwrap();
-        assert!(
+        ensure!(
             caller.eq(&registry_roles.registry_settings_manager) || caller.eq(&self.owner),
-            "This function can only be called by registry settings manager or the contract owner."
+            RegistryError::CallerIsNotRegistrySettingsManager
         );
         self.assert_account_has_no_role(&account);
         registry_roles.registry_settings_manager = account;
@@ -56,4 +58,27 @@ impl AppchainRegistry {
         registry_roles.octopus_council = Some(account);
         self.registry_roles.set(&registry_roles);
     }
+    //
+    pub fn add_auditor(&mut self, account: AccountId) {
+        self.assert_owner();
+        self.assert_account_has_no_role(&account);
+        let mut registry_roles = self.registry_roles.get().unwrap();
+        registry_roles.auditors.push(account);
+        self.registry_roles.set(&registry_roles);
+    }
+    //
+    pub fn remove_auditor(&mut self, account: AccountId) {
+        self.assert_owner();
+        let mut registry_roles = self.registry_roles.get().unwrap();
+        ensure!(
+            registry_roles.auditors.contains(&account),
+            RegistryError::AccountIsNotAuditor {
+                account_id: account.clone()
+            }
+        );
+        registry_roles
+            .auditors
+            .retain(|auditor| !auditor.eq(&account));
+        self.registry_roles.set(&registry_roles);
+    }
 }
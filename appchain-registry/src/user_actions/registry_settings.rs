@@ -1,26 +1,638 @@
 use crate::*;
+use appchain_id_rules::{normalize_appchain_id, normalize_deny_pattern};
+use appchain_tags::assert_valid_appchain_category;
+use near_sdk::json_types::Base58CryptoHash;
+use types::{AppchainMetadataField, RegisterDepositVoucherHolder};
+
+/// The max length of the name of an appchain template
+const MAX_APPCHAIN_TEMPLATE_NAME_LENGTH: usize = 32;
 
 pub trait RegistrySettingsManager {
-    /// Change the value of minimum register deposit
+    /// Change the gas (in T) for calling `ft_transfer` of OCT token contract
+    fn change_t_gas_for_ft_transfer(&mut self, value: u64);
+    /// Change the gas (in T) for resolver functions of this contract
+    fn change_t_gas_for_resolver_function(&mut self, value: u64);
+    /// Change the gas (in T) for calling functions of appchain anchor contract
+    fn change_t_gas_for_calling_anchor_function(&mut self, value: u64);
+    /// Add an EVM chain id to the reserved list, which can NOT be used by appchains
+    fn add_reserved_evm_chain_id(&mut self, evm_chain_id: U64);
+    /// Remove an EVM chain id from the reserved list
+    fn remove_reserved_evm_chain_id(&mut self, evm_chain_id: U64);
+    /// Change whether an appchain must be registered by revealing a registration commitment
+    fn change_registration_commitment_required(&mut self, value: bool);
+    /// Change the deposit for committing an appchain registration
+    fn change_registration_commitment_deposit(&mut self, value: U128);
+    /// Change the time range (in seconds) for revealing an appchain registration
+    fn change_registration_reveal_window_in_secs(&mut self, value: u64);
+    /// Change the fields of appchain metadata which can be updated by appchain owners directly
+    fn change_owner_updatable_metadata_fields(&mut self, fields: Vec<AppchainMetadataField>);
+    /// Change the number of approving audits which an appchain needs before passing auditing
+    fn change_required_approving_audits(&mut self, value: u32);
+    /// Change the time range (in seconds) for appealing the rejection of an appchain
+    fn change_appeal_window_in_secs(&mut self, value: u64);
+    /// Change the time range (in seconds) in which the anchor of a booting or active appchain
+    /// should sync, otherwise the appchain can be flagged as stale
+    fn change_anchor_staleness_threshold_in_secs(&mut self, value: u64);
+    /// Reserve an appchain id, which can only be registered by the assigned account (if any)
+    fn reserve_appchain_id(&mut self, appchain_id: AppchainId, assigned_to: Option<AccountId>);
+    /// Remove an appchain id from the reserved list
+    fn unreserve_appchain_id(&mut self, appchain_id: AppchainId);
+    /// Add a pattern (with `*` as wildcard) of appchain ids which can NOT be registered
+    fn add_appchain_id_deny_pattern(&mut self, pattern: String);
+    /// Remove a pattern of appchain ids from the deny list
+    fn remove_appchain_id_deny_pattern(&mut self, pattern: String);
+    /// Add a category to the vocabulary which can be used as tags of appchains
+    fn add_appchain_category(&mut self, category: String);
+    /// Remove a category from the vocabulary, which should NOT be used by any appchain
+    fn remove_appchain_category(&mut self, category: String);
+    /// Add a new appchain template
+    fn add_appchain_template(&mut self, template: AppchainTemplate);
+    /// Change the description of an appchain template
+    fn change_appchain_template_description(
+        &mut self,
+        template_type: AppchainTemplateType,
+        description: String,
+    );
+    /// Change the hash of the anchor wasm code of an appchain template
+    fn change_appchain_template_anchor_wasm_hash(
+        &mut self,
+        template_type: AppchainTemplateType,
+        anchor_wasm_hash: Option<Base58CryptoHash>,
+    );
+    /// Change the initial balance of anchor account for the given appchain template type
+    fn change_appchain_anchor_init_balance(
+        &mut self,
+        template_type: AppchainTemplateType,
+        value: U128,
+    );
+    /// Change the register deposit for the given appchain template type
+    fn change_appchain_template_register_deposit(
+        &mut self,
+        template_type: AppchainTemplateType,
+        value: U128,
+    );
+    /// Change the register deposit of the built-in appchain templates.
+    ///
+    /// Deprecated, use `change_appchain_template_register_deposit` instead.
     fn change_minimum_register_deposit(&mut self, value: U128);
+    /// Allow new appchains to be registered with an appchain template
+    fn activate_appchain_template(&mut self, template_type: AppchainTemplateType);
+    /// Stop new appchains from being registered with an appchain template
+    fn deactivate_appchain_template(&mut self, template_type: AppchainTemplateType);
+    /// Issue a voucher for a discount of (or a full waiver of) register deposit
+    fn issue_register_deposit_voucher(
+        &mut self,
+        voucher_id: String,
+        holder: RegisterDepositVoucherHolder,
+        discount_percent: u16,
+        max_uses: u32,
+    );
+    /// Revoke a register deposit voucher
+    fn revoke_register_deposit_voucher(&mut self, voucher_id: String);
 }
 
 impl Default for RegistrySettings {
     fn default() -> Self {
         Self {
-            minimum_register_deposit: U128::from(DEFAULT_REGISTER_DEPOSIT * OCT_DECIMALS_BASE),
+            t_gas_for_ft_transfer: DEFAULT_T_GAS_FOR_FT_TRANSFER,
+            t_gas_for_resolver_function: DEFAULT_T_GAS_FOR_RESOLVER_FUNCTION,
+            t_gas_for_calling_anchor_function: DEFAULT_T_GAS_FOR_CALLING_ANCHOR_FUNCTION,
+            reserved_evm_chain_ids: DEFAULT_RESERVED_EVM_CHAIN_IDS
+                .iter()
+                .map(|evm_chain_id| U64::from(*evm_chain_id))
+                .collect(),
+            appchain_id_deny_patterns: Vec::new(),
+            registration_commitment_required: true,
+            registration_commitment_deposit: U128::from(
+                DEFAULT_REGISTRATION_COMMITMENT_DEPOSIT * OCT_DECIMALS_BASE,
+            ),
+            registration_reveal_window_in_secs: DEFAULT_REGISTRATION_REVEAL_WINDOW_IN_SECS,
+            owner_updatable_metadata_fields: AppchainMetadataField::default_owner_updatable_fields(
+            ),
+            required_approving_audits: DEFAULT_REQUIRED_APPROVING_AUDITS,
+            appeal_window_in_secs: DEFAULT_APPEAL_WINDOW_IN_SECS,
+            appchain_categories: DEFAULT_APPCHAIN_CATEGORIES
+                .iter()
+                .map(|category| category.to_string())
+                .collect(),
+            anchor_staleness_threshold_in_secs: DEFAULT_ANCHOR_STALENESS_THRESHOLD_IN_SECS,
         }
     }
 }
 
+/// Get the appchain templates which were hard-coded in this contract before
+/// the templates became registry data.
+pub fn builtin_appchain_templates(register_deposit: U128) -> Vec<AppchainTemplate> {
+    vec![
+        AppchainTemplate {
+            name: "Barnacle".to_string(),
+            description: "Substrate based appchain template.".to_string(),
+            requires_evm_chain_id: false,
+            anchor_wasm_hash: None,
+            anchor_init_balance: U128::from(DEFAULT_APPCHAIN_ANCHOR_INIT_BALANCE),
+            register_deposit,
+            is_active: true,
+        },
+        AppchainTemplate {
+            name: "BarnacleEvm".to_string(),
+            description: "Substrate based appchain template with EVM support.".to_string(),
+            requires_evm_chain_id: true,
+            anchor_wasm_hash: None,
+            anchor_init_balance: U128::from(DEFAULT_APPCHAIN_ANCHOR_INIT_BALANCE),
+            register_deposit,
+            is_active: true,
+        },
+    ]
+}
+
+impl RegisterDepositVoucher {
+    /// Whether the voucher can be redeemed by the given account with the given secret
+    pub fn can_be_redeemed_by(&self, account_id: &AccountId, secret: &Option<String>) -> bool {
+        match &self.holder {
+            RegisterDepositVoucherHolder::Account(holder) => holder.eq(account_id),
+            RegisterDepositVoucherHolder::SecretHash(secret_hash) => {
+                secret.as_ref().map_or(false, |secret| {
+                    hex::encode(env::sha256(secret.as_bytes())).eq(secret_hash)
+                })
+            }
+        }
+    }
+    /// Get the amount of the given register deposit after the discount of this voucher
+    pub fn discounted_amount_of(&self, register_deposit: Balance) -> Balance {
+        register_deposit - register_deposit * self.discount_percent as u128 / 100
+    }
+}
+
 #[near_bindgen]
 impl RegistrySettingsManager for AppchainRegistry {
     //
-    fn change_minimum_register_deposit(&mut self, value: U128) {
+    fn change_t_gas_for_ft_transfer(&mut self, value: u64) {
+        self.assert_registry_settings_manager();
+        assert_t_gas_in_range(value, T_GAS_RANGE_FOR_FT_TRANSFER);
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        registry_settings.t_gas_for_ft_transfer = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_t_gas_for_resolver_function(&mut self, value: u64) {
+        self.assert_registry_settings_manager();
+        assert_t_gas_in_range(value, T_GAS_RANGE_FOR_RESOLVER_FUNCTION);
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        registry_settings.t_gas_for_resolver_function = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_t_gas_for_calling_anchor_function(&mut self, value: u64) {
+        self.assert_registry_settings_manager();
+        assert_t_gas_in_range(value, T_GAS_RANGE_FOR_CALLING_ANCHOR_FUNCTION);
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        registry_settings.t_gas_for_calling_anchor_function = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn add_reserved_evm_chain_id(&mut self, evm_chain_id: U64) {
+        self.assert_registry_settings_manager();
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            !registry_settings
+                .reserved_evm_chain_ids
+                .contains(&evm_chain_id),
+            RegistryError::EvmChainIdReserved { evm_chain_id }
+        );
+        if let Some(appchain_id) = self.evm_chain_ids.get(&evm_chain_id.0) {
+            panic_with(RegistryError::EvmChainIdAlreadyUsed {
+                evm_chain_id,
+                appchain_id,
+            });
+        }
+        registry_settings.reserved_evm_chain_ids.push(evm_chain_id);
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn remove_reserved_evm_chain_id(&mut self, evm_chain_id: U64) {
+        self.assert_registry_settings_manager();
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            registry_settings
+                .reserved_evm_chain_ids
+                .contains(&evm_chain_id),
+            RegistryError::EvmChainIdNotReserved { evm_chain_id }
+        );
+        registry_settings
+            .reserved_evm_chain_ids
+            .retain(|reserved_id| !reserved_id.eq(&evm_chain_id));
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_registration_commitment_required(&mut self, value: bool) {
+        self.assert_registry_settings_manager();
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            registry_settings.registration_commitment_required != value,
+            RegistryError::FieldNotChanged {
+                field: "registration_commitment_required".to_string()
+            }
+        );
+        registry_settings.registration_commitment_required = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_registration_commitment_deposit(&mut self, value: U128) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            value.0 > 0,
+            RegistryError::InvalidArgument {
+                name: "value".to_string(),
+                reason: "The registration commitment deposit should NOT be 0.".to_string(),
+            }
+        );
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        registry_settings.registration_commitment_deposit = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_registration_reveal_window_in_secs(&mut self, value: u64) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            value > 0,
+            RegistryError::InvalidArgument {
+                name: "value".to_string(),
+                reason: "The registration reveal window should NOT be 0.".to_string(),
+            }
+        );
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        registry_settings.registration_reveal_window_in_secs = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_owner_updatable_metadata_fields(&mut self, fields: Vec<AppchainMetadataField>) {
+        self.assert_registry_settings_manager();
+        for field in &fields {
+            ensure!(
+                field.can_be_updated_by_owner(),
+                RegistryError::InvalidArgument {
+                    name: "fields".to_string(),
+                    reason: format!(
+                        "The field '{:?}' can only be changed by change requests.",
+                        field
+                    ),
+                }
+            );
+        }
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        registry_settings.owner_updatable_metadata_fields = fields;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_required_approving_audits(&mut self, value: u32) {
+        self.assert_registry_settings_manager();
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            registry_settings.required_approving_audits != value,
+            RegistryError::FieldNotChanged {
+                field: "required_approving_audits".to_string()
+            }
+        );
+        registry_settings.required_approving_audits = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_appeal_window_in_secs(&mut self, value: u64) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            value > 0,
+            RegistryError::InvalidArgument {
+                name: "value".to_string(),
+                reason: "The appeal window should NOT be 0.".to_string(),
+            }
+        );
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        registry_settings.appeal_window_in_secs = value;
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn change_anchor_staleness_threshold_in_secs(&mut self, value: u64) {
         self.assert_registry_settings_manager();
-        assert!(value.0 > 0, "The minimum register deposit should NOT be 0.");
+        ensure!(
+            value > 0,
+            RegistryError::InvalidArgument {
+                name: "value".to_string(),
+                reason: "The staleness threshold should NOT be 0.".to_string(),
+            }
+        );
         let mut registry_settings = self.registry_settings.get().unwrap();
-        registry_settings.minimum_register_deposit = value;
+        registry_settings.anchor_staleness_threshold_in_secs = value;
         self.registry_settings.set(&registry_settings);
     }
+    //
+    fn reserve_appchain_id(&mut self, appchain_id: AppchainId, assigned_to: Option<AccountId>) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            !self
+                .normalized_appchain_ids
+                .contains_key(&normalize_appchain_id(&appchain_id)),
+            RegistryError::AppchainIdNotAvailable {
+                appchain_id: appchain_id.clone(),
+                availability: AppchainIdAvailability::Registered,
+            }
+        );
+        self.internal_reserve_appchain_id(&appchain_id, assigned_to);
+        log!("Appchain id '{}' is reserved.", appchain_id);
+    }
+    //
+    fn unreserve_appchain_id(&mut self, appchain_id: AppchainId) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            self.reserved_appchain_ids
+                .remove(&normalize_appchain_id(&appchain_id))
+                .is_some(),
+            RegistryError::AppchainIdNotReserved {
+                appchain_id: appchain_id.clone()
+            }
+        );
+        log!("Appchain id '{}' is no longer reserved.", appchain_id);
+    }
+    //
+    fn add_appchain_id_deny_pattern(&mut self, pattern: String) {
+        self.assert_registry_settings_manager();
+        let pattern = normalize_deny_pattern(&pattern);
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            !registry_settings
+                .appchain_id_deny_patterns
+                .contains(&pattern),
+            RegistryError::DenyPatternAlreadyExists {
+                pattern: pattern.clone()
+            }
+        );
+        registry_settings.appchain_id_deny_patterns.push(pattern);
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn remove_appchain_id_deny_pattern(&mut self, pattern: String) {
+        self.assert_registry_settings_manager();
+        let pattern = normalize_appchain_id(&pattern);
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            registry_settings
+                .appchain_id_deny_patterns
+                .contains(&pattern),
+            RegistryError::DenyPatternNotFound {
+                pattern: pattern.clone()
+            }
+        );
+        registry_settings
+            .appchain_id_deny_patterns
+            .retain(|existing_pattern| !existing_pattern.eq(&pattern));
+        self.registry_settings.set(&registry_settings);
+    }
+    //
+    fn add_appchain_category(&mut self, category: String) {
+        self.assert_registry_settings_manager();
+        assert_valid_appchain_category(&category);
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            !registry_settings.appchain_categories.contains(&category),
+            RegistryError::AppchainCategoryAlreadyExists {
+                category: category.clone()
+            }
+        );
+        registry_settings.appchain_categories.push(category.clone());
+        self.registry_settings.set(&registry_settings);
+        log!("Appchain category '{}' is added.", category);
+    }
+    //
+    fn remove_appchain_category(&mut self, category: String) {
+        self.assert_registry_settings_manager();
+        let mut registry_settings = self.registry_settings.get().unwrap();
+        ensure!(
+            registry_settings.appchain_categories.contains(&category),
+            RegistryError::AppchainCategoryNotFound {
+                category: category.clone()
+            }
+        );
+        ensure!(
+            self.get_appchain_ids_by_tag(&category).is_empty(),
+            RegistryError::AppchainCategoryInUse {
+                category: category.clone()
+            }
+        );
+        registry_settings
+            .appchain_categories
+            .retain(|existing_category| !existing_category.eq(&category));
+        self.registry_settings.set(&registry_settings);
+        log!("Appchain category '{}' is removed.", category);
+    }
+    //
+    fn add_appchain_template(&mut self, template: AppchainTemplate) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            !template.name.trim().is_empty()
+                && template.name.len() <= MAX_APPCHAIN_TEMPLATE_NAME_LENGTH,
+            RegistryError::InvalidArgument {
+                name: "template.name".to_string(),
+                reason: format!(
+                    "The name of appchain template should NOT be blank or longer than {}.",
+                    MAX_APPCHAIN_TEMPLATE_NAME_LENGTH
+                ),
+            }
+        );
+        ensure!(
+            self.appchain_templates.get(&template.name).is_none(),
+            RegistryError::AppchainTemplateAlreadyExists {
+                template_type: template.name.clone()
+            }
+        );
+        assert_anchor_init_balance_in_range(template.anchor_init_balance.0);
+        ensure!(
+            template.register_deposit.0 > 0,
+            RegistryError::InvalidArgument {
+                name: "template.register_deposit".to_string(),
+                reason: "The register deposit should NOT be 0.".to_string(),
+            }
+        );
+        self.appchain_templates.insert(&template.name, &template);
+        log!("Appchain template '{}' is added.", template.name);
+    }
+    //
+    fn change_appchain_template_description(
+        &mut self,
+        template_type: AppchainTemplateType,
+        description: String,
+    ) {
+        self.assert_registry_settings_manager();
+        let mut template = self.get_appchain_template(&template_type);
+        template.description = description;
+        self.appchain_templates.insert(&template_type, &template);
+    }
+    //
+    fn change_appchain_template_anchor_wasm_hash(
+        &mut self,
+        template_type: AppchainTemplateType,
+        anchor_wasm_hash: Option<Base58CryptoHash>,
+    ) {
+        self.assert_registry_settings_manager();
+        let mut template = self.get_appchain_template(&template_type);
+        template.anchor_wasm_hash = anchor_wasm_hash;
+        self.appchain_templates.insert(&template_type, &template);
+    }
+    //
+    fn change_appchain_anchor_init_balance(
+        &mut self,
+        template_type: AppchainTemplateType,
+        value: U128,
+    ) {
+        self.assert_registry_settings_manager();
+        assert_anchor_init_balance_in_range(value.0);
+        let mut template = self.get_appchain_template(&template_type);
+        template.anchor_init_balance = value;
+        self.appchain_templates.insert(&template_type, &template);
+    }
+    //
+    fn change_appchain_template_register_deposit(
+        &mut self,
+        template_type: AppchainTemplateType,
+        value: U128,
+    ) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            value.0 > 0,
+            RegistryError::InvalidArgument {
+                name: "value".to_string(),
+                reason: "The register deposit should NOT be 0.".to_string(),
+            }
+        );
+        let mut template = self.get_appchain_template(&template_type);
+        template.register_deposit = value;
+        self.appchain_templates.insert(&template_type, &template);
+    }
+    //
+    fn change_minimum_register_deposit(&mut self, value: U128) {
+        self.assert_registry_settings_manager();
+        for template in builtin_appchain_templates(value) {
+            if self.appchain_templates.get(&template.name).is_some() {
+                self.change_appchain_template_register_deposit(template.name, value);
+            }
+        }
+    }
+    //
+    fn activate_appchain_template(&mut self, template_type: AppchainTemplateType) {
+        self.assert_registry_settings_manager();
+        let mut template = self.get_appchain_template(&template_type);
+        ensure!(
+            !template.is_active,
+            RegistryError::AppchainTemplateAlreadyActive {
+                template_type: template_type.clone()
+            }
+        );
+        template.is_active = true;
+        self.appchain_templates.insert(&template_type, &template);
+    }
+    //
+    fn deactivate_appchain_template(&mut self, template_type: AppchainTemplateType) {
+        self.assert_registry_settings_manager();
+        let mut template = self.get_appchain_template(&template_type);
+        ensure!(
+            template.is_active,
+            RegistryError::AppchainTemplateAlreadyInactive {
+                template_type: template_type.clone()
+            }
+        );
+        template.is_active = false;
+        self.appchain_templates.insert(&template_type, &template);
+    }
+    //
+    fn issue_register_deposit_voucher(
+        &mut self,
+        voucher_id: String,
+        holder: RegisterDepositVoucherHolder,
+        discount_percent: u16,
+        max_uses: u32,
+    ) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            !voucher_id.trim().is_empty(),
+            RegistryError::MissingField {
+                field: "voucher_id".to_string()
+            }
+        );
+        ensure!(
+            self.register_deposit_vouchers.get(&voucher_id).is_none(),
+            RegistryError::RegisterDepositVoucherAlreadyExists {
+                voucher_id: voucher_id.clone()
+            }
+        );
+        if let RegisterDepositVoucherHolder::SecretHash(secret_hash) = &holder {
+            ensure!(
+                hex::decode(secret_hash).map_or(false, |bytes| bytes.len() == 32)
+                    && secret_hash.eq(&secret_hash.to_lowercase()),
+                RegistryError::InvalidArgument {
+                    name: "holder".to_string(),
+                    reason: "The secret hash should be a sha256 hash in lowercase hex.".to_string(),
+                }
+            );
+        }
+        ensure!(
+            discount_percent > 0 && discount_percent <= 100,
+            RegistryError::InvalidArgument {
+                name: "discount_percent".to_string(),
+                reason: "The discount percent should be in range (0, 100].".to_string(),
+            }
+        );
+        ensure!(
+            max_uses > 0,
+            RegistryError::InvalidArgument {
+                name: "max_uses".to_string(),
+                reason: "The max uses of voucher should NOT be 0.".to_string(),
+            }
+        );
+        self.register_deposit_vouchers.insert(
+            &voucher_id,
+            &RegisterDepositVoucher {
+                voucher_id: voucher_id.clone(),
+                holder,
+                discount_percent,
+                max_uses,
+                used_count: 0,
+            },
+        );
+        log!("Register deposit voucher '{}' is issued.", voucher_id);
+    }
+    //
+    fn revoke_register_deposit_voucher(&mut self, voucher_id: String) {
+        self.assert_registry_settings_manager();
+        ensure!(
+            self.register_deposit_vouchers.remove(&voucher_id).is_some(),
+            RegistryError::RegisterDepositVoucherNotFound {
+                voucher_id: voucher_id.clone()
+            }
+        );
+        log!("Register deposit voucher '{}' is revoked.", voucher_id);
+    }
+}
+
+fn assert_t_gas_in_range(value: u64, range: (u64, u64)) {
+    ensure!(
+        value >= range.0 && value <= range.1,
+        RegistryError::InvalidArgument {
+            name: "value".to_string(),
+            reason: format!(
+                "The gas value should be in range [{}, {}] (in T).",
+                range.0, range.1
+            ),
+        }
+    );
+}
+
+fn assert_anchor_init_balance_in_range(value: Balance) {
+    ensure!(
+        value > 0 && value <= MAXIMUM_APPCHAIN_ANCHOR_INIT_BALANCE,
+        RegistryError::InvalidArgument {
+            name: "anchor_init_balance".to_string(),
+            reason: format!(
+                "The initial balance of anchor account should be in range (0, {}].",
+                MAXIMUM_APPCHAIN_ANCHOR_INIT_BALANCE
+            ),
+        }
+    );
 }
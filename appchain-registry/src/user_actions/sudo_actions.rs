@@ -4,7 +4,7 @@ use std::{convert::TryFrom, str::FromStr};
 pub trait SudoActions {
     /// Set public key of owner.
     fn set_owner_pk(&mut self, public_key: String);
-    /// Create subaccount for a specific appchain.
+    /// Create subaccount for a specific appchain, and set it as the anchor of the appchain.
     fn create_anchor_account(&mut self, appchain_id: AppchainId);
     /// Force change state of an appchain.
     fn force_change_appchain_state(&mut self, appchain_id: AppchainId, state: AppchainState);
@@ -21,37 +21,62 @@ impl SudoActions for AppchainRegistry {
     //
     fn set_owner_pk(&mut self, public_key: String) {
         self.assert_owner();
-        let parse_result = PublicKey::from_str(public_key.as_str());
-        assert!(parse_result.is_ok(), "Invalid public key.");
-        self.owner_pk = parse_result.unwrap();
+        self.owner_pk = PublicKey::from_str(public_key.as_str()).unwrap_or_else(|_| {
+            panic_with(RegistryError::InvalidArgument {
+                name: "public_key".to_string(),
+                reason: "Invalid public key.".to_string(),
+            })
+        });
     }
     //
     fn create_anchor_account(&mut self, appchain_id: AppchainId) {
         self.assert_owner();
         let sub_account_id =
             AccountId::try_from(format!("{}.{}", &appchain_id, env::current_account_id()));
-        assert!(
+        ensure!(
             sub_account_id.is_ok(),
-            "Invalid sub account id for appchain '{}'.",
-            appchain_id
+            RegistryError::InvalidArgument {
+                name: "appchain_id".to_string(),
+                reason: format!("Invalid sub account id for appchain '{}'.", appchain_id),
+            }
         );
-        Promise::new(sub_account_id.unwrap())
+        let sub_account_id = sub_account_id.unwrap();
+        let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        if !appchain_basedata.anchor().eq(&Some(sub_account_id.clone())) {
+            self.internal_set_appchain_anchor(&mut appchain_basedata, sub_account_id.clone());
+            self.appchain_basedatas
+                .insert(&appchain_id, &appchain_basedata);
+            self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::AnchorChanged);
+        }
+        let anchor_init_balance = self
+            .get_appchain_template(&appchain_basedata.metadata().template_type)
+            .anchor_init_balance
+            .0;
+        Promise::new(sub_account_id)
             .create_account()
-            .transfer(APPCHAIN_ANCHOR_INIT_BALANCE)
+            .transfer(anchor_init_balance)
             .add_full_access_key(self.owner_pk.clone());
     }
     //
     fn force_change_appchain_state(&mut self, appchain_id: AppchainId, new_state: AppchainState) {
         self.assert_owner();
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
-        assert!(
+        ensure!(
             !appchain_basedata.state().eq(&new_state),
-            "Appchain is already in state '{}'",
-            &new_state
+            RegistryError::AppchainAlreadyInState {
+                appchain_id: appchain_id.clone(),
+                state: new_state
+            }
         );
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.set_state(new_state);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::StateChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
     }
     //
     fn pause_asset_transfer(&mut self) {
@@ -68,7 +93,7 @@ impl SudoActions for AppchainRegistry {
         self.assert_owner();
         self.assert_appchain_state(&appchain_id, [AppchainState::Closed].to_vec());
         let appchain_basedata = self.get_appchain_basedata(&appchain_id);
-        if !appchain_basedata.anchor().is_none() {
+        if appchain_basedata.anchor().is_some() {
             let anchor_account_id = format!("{}.{}", &appchain_id, env::current_account_id());
             log!(
                 "The anchor contract '{}' of appchain '{}' needs to be removed manually.",
@@ -16,37 +16,55 @@ impl VoterActions for AppchainRegistry {
     //
     fn withdraw_upvote_deposit_of(&mut self, appchain_id: AppchainId, amount: U128) {
         self.assert_asset_transfer_is_not_paused();
-        assert!(amount.0 > 0, "Withdraw amount is zero.");
+        ensure!(
+            amount.0 > 0,
+            RegistryError::InvalidArgument {
+                name: "amount".to_string(),
+                reason: "The withdraw amount should not be zero.".to_string(),
+            }
+        );
         let voter = env::predecessor_account_id();
         let voter_upvote = self
             .upvote_deposits
             .get(&(appchain_id.clone(), voter.clone()))
             .unwrap_or_default();
-        assert!(
+        ensure!(
             voter_upvote >= amount.0,
-            "Not enough upvote deposit to withdraw."
+            RegistryError::NotEnoughUpvoteDeposit {
+                deposit: U128::from(voter_upvote)
+            }
         );
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.decrease_upvote_deposit(amount.0);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::VoteDepositChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
         if amount.0 == voter_upvote {
             self.upvote_deposits
                 .remove(&(appchain_id.clone(), voter.clone()));
+            self.internal_update_voter_statistics(&voter, true, false);
         } else {
             self.upvote_deposits.insert(
                 &(appchain_id.clone(), voter.clone()),
                 &(voter_upvote - amount.0),
             );
         }
+        let registry_settings = self.registry_settings.get().unwrap();
         ext_ft_core::ext(self.oct_token.clone())
             .with_attached_deposit(1)
-            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+            .with_static_gas(Gas::ONE_TERA.mul(registry_settings.t_gas_for_ft_transfer))
             .with_unused_gas_weight(0)
-            .ft_transfer(voter.clone(), amount.into(), None)
+            .ft_transfer(voter.clone(), amount, None)
             .then(
                 ext_self::ext(env::current_account_id())
-                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_static_gas(
+                        Gas::ONE_TERA.mul(registry_settings.t_gas_for_resolver_function),
+                    )
                     .with_unused_gas_weight(0)
                     .resolve_withdraw_upvote_deposit(appchain_id.clone(), voter.clone(), amount),
             );
@@ -54,37 +72,55 @@ impl VoterActions for AppchainRegistry {
     //
     fn withdraw_downvote_deposit_of(&mut self, appchain_id: AppchainId, amount: U128) {
         self.assert_asset_transfer_is_not_paused();
-        assert!(amount.0 > 0, "Withdraw amount is zero.");
+        ensure!(
+            amount.0 > 0,
+            RegistryError::InvalidArgument {
+                name: "amount".to_string(),
+                reason: "The withdraw amount should not be zero.".to_string(),
+            }
+        );
         let voter = env::predecessor_account_id();
         let voter_downvote = self
             .downvote_deposits
             .get(&(appchain_id.clone(), voter.clone()))
             .unwrap_or_default();
-        assert!(
+        ensure!(
             voter_downvote >= amount.0,
-            "Not enough downvote deposit to withdraw."
+            RegistryError::NotEnoughDownvoteDeposit {
+                deposit: U128::from(voter_downvote)
+            }
         );
         let mut appchain_basedata = self.get_appchain_basedata(&appchain_id);
+        let previous_statistics_values = appchain_basedata.statistics_values();
         appchain_basedata.decrease_downvote_deposit(amount.0);
         self.appchain_basedatas
             .insert(&appchain_id, &appchain_basedata);
+        self.internal_record_appchain_change(&appchain_id, AppchainChangeKind::VoteDepositChanged);
+        self.internal_update_statistics(
+            Some(previous_statistics_values),
+            Some(appchain_basedata.statistics_values()),
+        );
         if amount.0 == voter_downvote {
             self.downvote_deposits
                 .remove(&(appchain_id.clone(), voter.clone()));
+            self.internal_update_voter_statistics(&voter, true, false);
         } else {
             self.downvote_deposits.insert(
                 &(appchain_id.clone(), voter.clone()),
                 &(voter_downvote - amount.0),
             );
         }
+        let registry_settings = self.registry_settings.get().unwrap();
         ext_ft_core::ext(self.oct_token.clone())
             .with_attached_deposit(1)
-            .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_FT_TRANSFER))
+            .with_static_gas(Gas::ONE_TERA.mul(registry_settings.t_gas_for_ft_transfer))
             .with_unused_gas_weight(0)
-            .ft_transfer(voter.clone(), amount.into(), None)
+            .ft_transfer(voter.clone(), amount, None)
             .then(
                 ext_self::ext(env::current_account_id())
-                    .with_static_gas(Gas::ONE_TERA.mul(T_GAS_FOR_RESOLVER_FUNCTION))
+                    .with_static_gas(
+                        Gas::ONE_TERA.mul(registry_settings.t_gas_for_resolver_function),
+                    )
                     .with_unused_gas_weight(0)
                     .resolve_withdraw_upvote_deposit(appchain_id.clone(), voter.clone(), amount),
             );
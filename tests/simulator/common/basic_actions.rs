@@ -1,10 +1,17 @@
+use crate::contract_interfaces::{
+    appchain_lifecycle_manager, appchain_owner_actions, auditor_actions, registry_roles,
+    registry_settings,
+};
+use appchain_registry::types::{AppchainState, AppchainSyncMetrics, AuditVerdict};
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
 use near_sdk::{
     json_types::{Base64VecU8, U128},
     serde_json::json,
+    AccountId,
 };
 use near_units::parse_near;
-use workspaces::{network::Sandbox, Account, Contract, Worker};
+use std::{collections::HashMap, str::FromStr};
+use workspaces::{network::Sandbox, result::ExecutionFinalResult, Account, Contract, Worker};
 
 pub async fn initialize_contracts_and_users(
     worker: &Worker<Sandbox>,
@@ -59,7 +66,7 @@ pub async fn initialize_contracts_and_users(
     let appchain_registry = match with_old_anchor {
         true => appchain_registry
             .deploy(&std::fs::read(format!(
-                "res/appchain_registry_v3.0.0.wasm"
+                "res/appchain_registry_v3.1.0.wasm"
             ))?)
             .await?
             .unwrap(),
@@ -78,6 +85,17 @@ pub async fn initialize_contracts_and_users(
         .await
         .expect("Failed to call function 'new' of registry contract.")
         .is_success());
+    // The registration commitment is required by default, while most of the test cases
+    // register appchains in one step. The commit-reveal flow is tested in test case 8.
+    if !with_old_anchor {
+        assert!(registry_settings::change_registration_commitment_required(
+            &root,
+            &appchain_registry,
+            false
+        )
+        .await?
+        .is_success());
+    }
     //
     // register appchain registry contract to OCT token
     //
@@ -143,6 +161,13 @@ pub async fn initialize_contracts_and_users(
         .await?
         .unwrap();
     users.push(eve);
+    // Deposit storage balance of users to appchain registry,
+    // for covering the storage used by their appchains
+    if !with_old_anchor {
+        for user in &users {
+            deposit_storage_to_registry(user, &appchain_registry, parse_near!("1 N")).await?;
+        }
+    }
     // council
     let council = root
         .create_subaccount("council")
@@ -173,3 +198,180 @@ pub async fn register_user_to_ft_contract(
         .is_success());
     Ok(())
 }
+
+// Deposit the given amount of NEAR to the storage balance of the `account` in appchain registry
+pub async fn deposit_storage_to_registry(
+    account: &Account,
+    appchain_registry: &Contract,
+    amount: u128,
+) -> anyhow::Result<()> {
+    assert!(account
+        .call(appchain_registry.id(), "storage_deposit")
+        .args_json(json!({
+            "account_id": Option::<String>::None,
+            "registration_only": Option::<bool>::None,
+        }))
+        .gas(20_000_000_000_000)
+        .deposit(amount)
+        .transact()
+        .await
+        .expect("Failed to call 'storage_deposit' of appchain registry.")
+        .is_success());
+    Ok(())
+}
+
+// Create an account with the given `name` and add it to the auditors of appchain registry
+pub async fn create_auditor(
+    root: &Account,
+    appchain_registry: &Contract,
+    name: &str,
+) -> anyhow::Result<Account> {
+    let auditor = root
+        .create_subaccount(name)
+        .initial_balance(parse_near!("10 N"))
+        .transact()
+        .await?
+        .unwrap();
+    assert!(
+        registry_roles::add_auditor(root, appchain_registry, &auditor)
+            .await?
+            .is_success()
+    );
+    Ok(auditor)
+}
+
+// Submit an approving audit of the given appchain by the `auditor`
+pub async fn approve_appchain_audit(
+    auditor: &Account,
+    appchain_registry: &Contract,
+    appchain_id: &String,
+) -> anyhow::Result<()> {
+    assert!(auditor_actions::submit_appchain_audit(
+        auditor,
+        appchain_registry,
+        appchain_id,
+        "https://audits.oct.network/report.pdf",
+        "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+        AuditVerdict::Approved,
+    )
+    .await?
+    .is_success());
+    Ok(())
+}
+
+// Register an appchain with the default metadata for testing by the `signer`
+pub async fn register_appchain(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &String,
+) -> anyhow::Result<ExecutionFinalResult> {
+    Ok(appchain_owner_actions::register_appchain(
+        signer,
+        oct_token,
+        registry,
+        appchain_id,
+        Some("appchain description".to_string()),
+        Some("Barnacle".to_string()),
+        Some("https://octopus.network".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
+        Some("joe@oct.network".to_string()),
+        Some(AccountId::from_str(signer.id().as_str()).unwrap()),
+        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
+        Some(U128::from(1000000)),
+        Some(U128::from(100)),
+        Some(FungibleTokenMetadata {
+            spec: FT_METADATA_SPEC.to_string(),
+            name: "joeToken".to_string(),
+            symbol: "JOT".to_string(),
+            icon: Option::None,
+            reference: Option::None,
+            reference_hash: Option::None,
+            decimals: 18,
+        }),
+        Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
+        crate::common::to_oct_amount(1000),
+    )
+    .await?)
+}
+
+// Pass the auditing of the given registered appchain, start voting and start booting it,
+// and return the account of its anchor (which shares the key of `root` in sandbox)
+pub async fn boot_appchain(
+    worker: &Worker<Sandbox>,
+    root: &Account,
+    council: &Account,
+    auditor: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+) -> anyhow::Result<Account> {
+    approve_appchain_audit(auditor, registry, appchain_id).await?;
+    assert!(
+        appchain_lifecycle_manager::pass_auditing_appchain(root, registry, appchain_id)
+            .await?
+            .is_success()
+    );
+    assert!(
+        appchain_lifecycle_manager::start_voting_appchain(root, registry, appchain_id)
+            .await?
+            .is_success()
+    );
+    assert!(
+        registry_roles::change_octopus_council(root, registry, council)
+            .await?
+            .is_success()
+    );
+    assert!(
+        appchain_lifecycle_manager::start_booting_appchain(council, registry, appchain_id)
+            .await?
+            .is_success()
+    );
+    let anchor_account_id =
+        workspaces::AccountId::try_from(format!("{}.{}", appchain_id, registry.id())).unwrap();
+    Ok(Account::from_secret_key(
+        anchor_account_id,
+        root.secret_key().clone(),
+        worker,
+    ))
+}
+
+// Sync the state of the given appchain to registry by the `anchor`
+pub async fn sync_state_of(
+    anchor: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+) -> anyhow::Result<ExecutionFinalResult> {
+    Ok(anchor
+        .call(registry.id(), "sync_state_of")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "appchain_state": AppchainState::Active,
+            "validator_count": 2,
+            "total_stake": U128::from(1000),
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await?)
+}
+
+// Sync the state and the metrics of the given appchain to registry by the `anchor`
+pub async fn sync_state_and_metrics_of(
+    anchor: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    metrics: &AppchainSyncMetrics,
+) -> anyhow::Result<ExecutionFinalResult> {
+    Ok(anchor
+        .call(registry.id(), "sync_state_and_metrics_of")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "appchain_state": AppchainState::Active,
+            "validator_count": 2,
+            "total_stake": U128::from(1000),
+            "metrics": metrics,
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await?)
+}
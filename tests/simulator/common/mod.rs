@@ -61,3 +61,31 @@ pub fn to_oct_amount(amount: u128) -> u128 {
     let bt_decimals_base = (10 as u128).pow(18);
     amount * bt_decimals_base
 }
+
+/// Get the code of the first registry error raised in the given execution.
+pub fn get_error_code(result: &ExecutionFinalResult) -> Option<String> {
+    result.failures().iter().find_map(|outcome| {
+        let text = format!("{:?}", outcome).replace('\\', "");
+        text.find("\"code\":\"").map(|start| {
+            let rest = &text[start + "\"code\":\"".len()..];
+            rest[..rest.find('"').unwrap_or(rest.len())].to_string()
+        })
+    })
+}
+
+pub fn assert_error_code(result: &ExecutionFinalResult, code: &str) {
+    assert_eq!(
+        get_error_code(result).as_deref(),
+        Some(code),
+        "Unexpected result: {:?}",
+        result.failures()
+    );
+}
+
+pub fn assert_no_failure(result: &ExecutionFinalResult) {
+    assert!(
+        result.failures().is_empty(),
+        "Unexpected failures: {:?}",
+        result.failures()
+    );
+}
@@ -1,41 +1,18 @@
-use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
-use near_sdk::{json_types::U128, serde_json::json, AccountId};
-use std::collections::HashMap;
+use appchain_registry::types::AppchainMetadataPatch;
+use near_sdk::{json_types::U64, serde_json::json};
 use workspaces::{result::ExecutionFinalResult, Account, Contract};
 
 pub async fn update_appchain_metadata(
     signer: &Account,
     registry: &Contract,
     appchain_id: &String,
-    website_url: Option<String>,
-    function_spec_url: Option<String>,
-    github_address: Option<String>,
-    github_release: Option<String>,
-    contact_email: Option<String>,
-    premined_wrapped_appchain_token_beneficiary: Option<AccountId>,
-    premined_wrapped_appchain_token: Option<U128>,
-    initial_supply_of_wrapped_appchain_token: Option<U128>,
-    ido_amount_of_wrapped_appchain_token: Option<U128>,
-    initial_era_reward: Option<U128>,
-    fungible_token_metadata: Option<FungibleTokenMetadata>,
-    custom_metadata: Option<HashMap<String, String>>,
+    patch: &AppchainMetadataPatch,
 ) -> Result<ExecutionFinalResult, workspaces::error::Error> {
     signer
         .call(registry.id(), "update_appchain_metadata")
         .args_json(json!({
             "appchain_id": appchain_id,
-            "website_url": website_url,
-            "function_spec_url": function_spec_url,
-            "github_address": github_address,
-            "github_release": github_release,
-            "contact_email": contact_email,
-            "premined_wrapped_appchain_token_beneficiary": premined_wrapped_appchain_token_beneficiary,
-            "premined_wrapped_appchain_token": premined_wrapped_appchain_token,
-            "initial_supply_of_wrapped_appchain_token": initial_supply_of_wrapped_appchain_token,
-            "ido_amount_of_wrapped_appchain_token": ido_amount_of_wrapped_appchain_token,
-            "initial_era_reward": initial_era_reward,
-            "fungible_token_metadata": fungible_token_metadata,
-            "custom_metadata": custom_metadata,
+            "patch": patch,
         }))
         .gas(200_000_000_000_000)
         .transact()
@@ -75,10 +52,48 @@ pub async fn reject_appchain(
     signer: &Account,
     registry: &Contract,
     appchain_id: &String,
+    reason: &str,
 ) -> Result<ExecutionFinalResult, workspaces::error::Error> {
     signer
         .call(registry.id(), "reject_appchain")
-        .args_json(json!({ "appchain_id": appchain_id }))
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "reason": reason,
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn reinstate_appchain(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    reason: Option<&str>,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "reinstate_appchain")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "reason": reason,
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn dismiss_appchain_appeal(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    reason: &str,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "dismiss_appchain_appeal")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "reason": reason,
+        }))
         .gas(200_000_000_000_000)
         .transact()
         .await
@@ -112,3 +127,72 @@ pub async fn remove_appchain(
         .transact()
         .await
 }
+
+pub async fn approve_appchain_metadata_change_request(
+    signer: &Account,
+    registry: &Contract,
+    request_id: u64,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "approve_appchain_metadata_change_request")
+        .args_json(json!({ "request_id": U64::from(request_id) }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn reject_appchain_metadata_change_request(
+    signer: &Account,
+    registry: &Contract,
+    request_id: u64,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "reject_appchain_metadata_change_request")
+        .args_json(json!({ "request_id": U64::from(request_id) }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn approve_appchain_tag_proposal(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "approve_appchain_tag_proposal")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn reject_appchain_tag_proposal(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "reject_appchain_tag_proposal")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn set_appchain_tags(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    tags: Vec<&str>,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "set_appchain_tags")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "tags": tags
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
@@ -1,8 +1,8 @@
-use crate::common;
-use appchain_registry::types::AppchainTemplateType;
+use crate::{common, contract_interfaces::registry_viewer};
+use appchain_registry::types::{AppchainMetadataPatch, AppchainTemplateType};
 use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
 use near_sdk::{
-    json_types::U128,
+    json_types::{U128, U64},
     serde::{Deserialize, Serialize},
     serde_json::json,
     AccountId,
@@ -17,6 +17,58 @@ struct ParamOfUpdateAppchainCustomMetadata {
     custom_metadata: HashMap<String, String>,
 }
 
+/// The salt used for committing appchain registrations in tests
+pub const REGISTRATION_SALT: &str = "registration-salt";
+
+/// Commit the registration of an appchain with `REGISTRATION_SALT`,
+/// if the commitment does not exist yet.
+pub async fn commit_appchain_registration(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+) -> anyhow::Result<()> {
+    commit_appchain_registration_with_voucher_secret(signer, oct_token, registry, appchain_id, None)
+        .await
+}
+
+/// Commit the registration of an appchain with `REGISTRATION_SALT` and the given
+/// voucher secret, if the commitment does not exist yet.
+pub async fn commit_appchain_registration_with_voucher_secret(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+    voucher_secret: Option<&str>,
+) -> anyhow::Result<()> {
+    let commitment = registry_viewer::compute_registration_commitment_with_voucher_secret(
+        registry,
+        appchain_id,
+        REGISTRATION_SALT,
+        signer,
+        voucher_secret,
+    )
+    .await?;
+    let existing_commitment =
+        registry_viewer::get_registration_commitment_of(registry, &commitment).await?;
+    if existing_commitment.is_none() {
+        common::call_ft_transfer_call(
+            signer,
+            &registry.as_account(),
+            common::to_oct_amount(10),
+            json!({
+                "CommitAppchainRegistration":{
+                    "commitment": commitment
+                }
+            })
+            .to_string(),
+            oct_token,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
 pub async fn register_appchain(
     signer: &Account,
     oct_token: &Contract,
@@ -62,6 +114,43 @@ pub async fn register_appchain(
     ).await
 }
 
+pub async fn remove_expired_registration_commitment(
+    signer: &Account,
+    registry: &Contract,
+    commitment: &String,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "remove_expired_registration_commitment")
+        .args_json(json!({ "commitment": commitment }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn withdraw_unrefunded_commitment_deposit(
+    signer: &Account,
+    registry: &Contract,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "withdraw_unrefunded_commitment_deposit")
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn flag_stale_appchain(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "flag_stale_appchain")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
 pub async fn transfer_appchain_ownership(
     signer: &Account,
     registry: &Contract,
@@ -78,3 +167,128 @@ pub async fn transfer_appchain_ownership(
         .transact()
         .await
 }
+
+pub async fn withdraw_appchain(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    reason: Option<&str>,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "withdraw_appchain")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "reason": reason,
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn appeal_appchain_rejection(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    statement: &str,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "appeal_appchain_rejection")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "statement": statement,
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn storage_withdraw(
+    signer: &Account,
+    registry: &Contract,
+    amount: Option<U128>,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "storage_withdraw")
+        .args_json(json!({ "amount": amount }))
+        .gas(200_000_000_000_000)
+        .deposit(1)
+        .transact()
+        .await
+}
+
+pub async fn update_appchain_metadata_by_owner(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    patch: &AppchainMetadataPatch,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "update_appchain_metadata_by_owner")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "patch": patch
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn request_appchain_metadata_change(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    patch: &AppchainMetadataPatch,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "request_appchain_metadata_change")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "patch": patch
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn cancel_appchain_metadata_change_request(
+    signer: &Account,
+    registry: &Contract,
+    request_id: u64,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "cancel_appchain_metadata_change_request")
+        .args_json(json!({ "request_id": U64::from(request_id) }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn propose_appchain_tags(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    tags: Vec<&str>,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "propose_appchain_tags")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "tags": tags
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn cancel_appchain_tag_proposal(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "cancel_appchain_tag_proposal")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
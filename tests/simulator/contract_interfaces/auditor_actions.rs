@@ -0,0 +1,24 @@
+use appchain_registry::types::AuditVerdict;
+use near_sdk::serde_json::json;
+use workspaces::{result::ExecutionFinalResult, Account, Contract};
+
+pub async fn submit_appchain_audit(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &String,
+    report_url: &str,
+    report_sha256: &str,
+    verdict: AuditVerdict,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "submit_appchain_audit")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "report_url": report_url,
+            "report_sha256": report_sha256,
+            "verdict": verdict,
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
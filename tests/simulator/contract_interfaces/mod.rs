@@ -1,5 +1,6 @@
 pub mod appchain_lifecycle_manager;
 pub mod appchain_owner_actions;
+pub mod auditor_actions;
 pub mod registry_roles;
 pub mod registry_settings;
 pub mod registry_viewer;
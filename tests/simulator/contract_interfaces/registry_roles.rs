@@ -39,3 +39,29 @@ pub async fn change_octopus_council(
         .transact()
         .await
 }
+
+pub async fn add_auditor(
+    signer: &Account,
+    registry: &Contract,
+    account: &Account,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "add_auditor")
+        .args_json(json!({ "account": account.id() }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn remove_auditor(
+    signer: &Account,
+    registry: &Contract,
+    account: &Account,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "remove_auditor")
+        .args_json(json!({ "account": account.id() }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
@@ -1,6 +1,45 @@
-use near_sdk::{json_types::U128, serde_json::json};
+use appchain_registry::types::{AppchainTemplateType, RegisterDepositVoucherHolder};
+use near_sdk::{
+    json_types::{Base58CryptoHash, U128},
+    serde_json::json,
+    AccountId,
+};
 use workspaces::{result::ExecutionFinalResult, Account, Contract};
 
+pub async fn change_appchain_template_register_deposit(
+    signer: &Account,
+    registry: &Contract,
+    template_type: AppchainTemplateType,
+    value: u128,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_appchain_template_register_deposit")
+        .args_json(json!({
+            "template_type": template_type,
+            "value": U128::from(value)
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_appchain_template_anchor_wasm_hash(
+    signer: &Account,
+    registry: &Contract,
+    template_type: AppchainTemplateType,
+    anchor_wasm_hash: Option<Base58CryptoHash>,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_appchain_template_anchor_wasm_hash")
+        .args_json(json!({
+            "template_type": template_type,
+            "anchor_wasm_hash": anchor_wasm_hash
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
 pub async fn change_minimum_register_deposit(
     signer: &Account,
     registry: &Contract,
@@ -13,3 +52,175 @@ pub async fn change_minimum_register_deposit(
         .transact()
         .await
 }
+
+pub async fn change_t_gas_for_ft_transfer(
+    signer: &Account,
+    registry: &Contract,
+    value: u64,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_t_gas_for_ft_transfer")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_appchain_anchor_init_balance(
+    signer: &Account,
+    registry: &Contract,
+    template_type: AppchainTemplateType,
+    value: u128,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_appchain_anchor_init_balance")
+        .args_json(json!({
+            "template_type": template_type,
+            "value": U128::from(value)
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn issue_register_deposit_voucher(
+    signer: &Account,
+    registry: &Contract,
+    voucher_id: &str,
+    holder: RegisterDepositVoucherHolder,
+    discount_percent: u16,
+    max_uses: u32,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "issue_register_deposit_voucher")
+        .args_json(json!({
+            "voucher_id": voucher_id,
+            "holder": holder,
+            "discount_percent": discount_percent,
+            "max_uses": max_uses
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn reserve_appchain_id(
+    signer: &Account,
+    registry: &Contract,
+    appchain_id: &str,
+    assigned_to: Option<AccountId>,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "reserve_appchain_id")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "assigned_to": assigned_to
+        }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn add_appchain_id_deny_pattern(
+    signer: &Account,
+    registry: &Contract,
+    pattern: &str,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "add_appchain_id_deny_pattern")
+        .args_json(json!({ "pattern": pattern }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_registration_commitment_required(
+    signer: &Account,
+    registry: &Contract,
+    value: bool,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_registration_commitment_required")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_registration_reveal_window_in_secs(
+    signer: &Account,
+    registry: &Contract,
+    value: u64,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_registration_reveal_window_in_secs")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_appeal_window_in_secs(
+    signer: &Account,
+    registry: &Contract,
+    value: u64,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_appeal_window_in_secs")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_anchor_staleness_threshold_in_secs(
+    signer: &Account,
+    registry: &Contract,
+    value: u64,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_anchor_staleness_threshold_in_secs")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn change_required_approving_audits(
+    signer: &Account,
+    registry: &Contract,
+    value: u32,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "change_required_approving_audits")
+        .args_json(json!({ "value": value }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn add_appchain_category(
+    signer: &Account,
+    registry: &Contract,
+    category: &str,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "add_appchain_category")
+        .args_json(json!({ "category": category }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
+
+pub async fn remove_appchain_category(
+    signer: &Account,
+    registry: &Contract,
+    category: &str,
+) -> Result<ExecutionFinalResult, workspaces::error::Error> {
+    signer
+        .call(registry.id(), "remove_appchain_category")
+        .args_json(json!({ "category": category }))
+        .gas(200_000_000_000_000)
+        .transact()
+        .await
+}
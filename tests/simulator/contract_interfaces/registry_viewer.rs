@@ -1,9 +1,18 @@
 use appchain_registry::types::{
-    AppchainSortingField, AppchainState, AppchainStatus, RegistryRoles, RegistrySettings,
-    SortingOrder,
+    AppchainAuditRecord, AppchainChangeFeed, AppchainFilter, AppchainIdAvailability,
+    AppchainLiveness, AppchainLookupResult, AppchainMetadataChangeRequest, AppchainMetadataPatch,
+    AppchainMetadataRevision, AppchainMetadataSnapshot, AppchainReviewRecord, AppchainSortingField,
+    AppchainSortingSpec, AppchainState, AppchainStatus, AppchainStorageBalance, AppchainSummary,
+    AppchainTagProposal, AppchainTemplate, PagedResult, RegistrationCommitment, RegistryRoles,
+    RegistrySettings, RegistryStatistics, ReservedAppchainId, SortingOrder,
+    VersionedAppchainAnchor, VersionedAppchainOwner,
 };
-use near_sdk::serde_json::json;
-use workspaces::Contract;
+use near_contract_standards::storage_management::StorageBalance;
+use near_sdk::{
+    json_types::{U128, U64},
+    serde_json::json,
+};
+use workspaces::{Account, Contract};
 
 pub async fn get_registry_settings(
     registry: &Contract,
@@ -27,35 +36,215 @@ pub async fn get_registry_roles(
         .json::<RegistryRoles>()
 }
 
+pub async fn get_appchain_template_of(
+    registry: &Contract,
+    template_type: &String,
+) -> Result<Option<AppchainTemplate>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_template_of")
+        .args_json(json!({ "template_type": template_type }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_template_of'")
+        .json::<Option<AppchainTemplate>>()
+}
+
+pub async fn get_appchain_id_of_evm_chain_id(
+    registry: &Contract,
+    evm_chain_id: u64,
+) -> Result<Option<String>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_id_of_evm_chain_id")
+        .args_json(json!({ "evm_chain_id": U64::from(evm_chain_id) }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_id_of_evm_chain_id'")
+        .json::<Option<String>>()
+}
+
+pub async fn get_appchain_id_of_anchor(
+    registry: &Contract,
+    anchor_account: &str,
+) -> Result<Option<String>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_id_of_anchor")
+        .args_json(json!({ "anchor_account": anchor_account }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_id_of_anchor'")
+        .json::<Option<String>>()
+}
+
+pub async fn get_stale_appchains(
+    registry: &Contract,
+) -> Result<PagedResult<AppchainLiveness>, workspaces::error::Error> {
+    registry
+        .call("get_stale_appchains")
+        .args_json(json!({}))
+        .view()
+        .await
+        .expect("Failed in calling 'get_stale_appchains'")
+        .json::<PagedResult<AppchainLiveness>>()
+}
+
+pub async fn check_appchain_id_availability(
+    registry: &Contract,
+    appchain_id: &str,
+    account_id: Option<&str>,
+) -> Result<AppchainIdAvailability, workspaces::error::Error> {
+    registry
+        .call("check_appchain_id_availability")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "account_id": account_id
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'check_appchain_id_availability'")
+        .json::<AppchainIdAvailability>()
+}
+
+pub async fn compute_registration_commitment(
+    registry: &Contract,
+    appchain_id: &str,
+    salt: &str,
+    account: &Account,
+) -> Result<String, workspaces::error::Error> {
+    compute_registration_commitment_with_voucher_secret(registry, appchain_id, salt, account, None)
+        .await
+}
+
+pub async fn compute_registration_commitment_with_voucher_secret(
+    registry: &Contract,
+    appchain_id: &str,
+    salt: &str,
+    account: &Account,
+    voucher_secret: Option<&str>,
+) -> Result<String, workspaces::error::Error> {
+    registry
+        .call("compute_registration_commitment")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "salt": salt,
+            "account_id": account.id(),
+            "voucher_secret": voucher_secret,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'compute_registration_commitment'")
+        .json::<String>()
+}
+
+pub async fn get_registration_commitment_of(
+    registry: &Contract,
+    commitment: &String,
+) -> Result<Option<RegistrationCommitment>, workspaces::error::Error> {
+    registry
+        .call("get_registration_commitment_of")
+        .args_json(json!({ "commitment": commitment }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_registration_commitment_of'")
+        .json::<Option<RegistrationCommitment>>()
+}
+
+pub async fn get_unrefunded_commitment_deposit_of(
+    registry: &Contract,
+    account: &Account,
+) -> Result<U128, workspaces::error::Error> {
+    registry
+        .call("get_unrefunded_commitment_deposit_of")
+        .args_json(json!({ "account_id": account.id() }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_unrefunded_commitment_deposit_of'")
+        .json::<U128>()
+}
+
 pub async fn print_appchains(
     registry: &Contract,
     appchain_state: Option<Vec<AppchainState>>,
-    page_number: u16,
-    page_size: u16,
+    from_index: u64,
+    limit: u32,
     sorting_field: AppchainSortingField,
     sorting_order: SortingOrder,
 ) -> anyhow::Result<usize> {
-    let result = registry
+    let result = get_appchains_with_state_of(
+        registry,
+        appchain_state,
+        None,
+        vec![AppchainSortingSpec {
+            field: sorting_field,
+            order: sorting_order,
+        }],
+        Some(from_index),
+        Some(limit),
+    )
+    .await?;
+    result.items.iter().for_each(|appchain_status| {
+        println!(
+            "Appchain: {}",
+            near_sdk::serde_json::ser::to_string(appchain_status).unwrap()
+        );
+    });
+    Ok(result.items.len())
+}
+
+pub async fn get_appchains_with_state_of(
+    registry: &Contract,
+    appchain_state: Option<Vec<AppchainState>>,
+    filter: Option<AppchainFilter>,
+    sorting: Vec<AppchainSortingSpec>,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<AppchainStatus>, workspaces::error::Error> {
+    registry
         .call("get_appchains_with_state_of")
         .args_json(json!({
             "appchain_state": appchain_state,
-            "page_number": page_number,
-            "page_size": page_size,
-            "sorting_field": sorting_field,
-            "sorting_order": sorting_order,
+            "filter": filter,
+            "sorting": sorting,
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
         }))
         .view()
         .await
         .expect("Failed in calling 'get_appchains_with_state_of'")
-        .json::<Vec<AppchainStatus>>()
-        .expect("Failed in calling 'get_appchains_with_state_of'");
-    result.iter().for_each(|appchain_status| {
-        println!(
-            "Appchain: {}",
-            near_sdk::serde_json::ser::to_string(appchain_status).unwrap()
-        );
-    });
-    Ok(result.len())
+        .json::<PagedResult<AppchainStatus>>()
+}
+
+pub async fn get_appchain_ids(
+    registry: &Contract,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<String>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_ids")
+        .args_json(json!({
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_ids'")
+        .json::<PagedResult<String>>()
+}
+
+pub async fn get_reserved_appchain_ids(
+    registry: &Contract,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<ReservedAppchainId>, workspaces::error::Error> {
+    registry
+        .call("get_reserved_appchain_ids")
+        .args_json(json!({
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_reserved_appchain_ids'")
+        .json::<PagedResult<ReservedAppchainId>>()
 }
 
 pub async fn get_appchain_status_of(
@@ -76,3 +265,272 @@ pub async fn get_appchain_status_of(
     );
     Ok(result)
 }
+
+pub async fn get_appchain_storage_balance_of(
+    registry: &Contract,
+    appchain_id: &String,
+) -> Result<AppchainStorageBalance, workspaces::error::Error> {
+    registry
+        .call("get_appchain_storage_balance_of")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_storage_balance_of'")
+        .json::<AppchainStorageBalance>()
+}
+
+pub async fn storage_balance_of(
+    registry: &Contract,
+    account: &Account,
+) -> Result<Option<StorageBalance>, workspaces::error::Error> {
+    registry
+        .call("storage_balance_of")
+        .args_json(json!({ "account_id": account.id() }))
+        .view()
+        .await
+        .expect("Failed in calling 'storage_balance_of'")
+        .json::<Option<StorageBalance>>()
+}
+
+pub async fn get_pending_appchain_metadata_change_requests(
+    registry: &Contract,
+    appchain_id: Option<String>,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<AppchainMetadataChangeRequest>, workspaces::error::Error> {
+    registry
+        .call("get_pending_appchain_metadata_change_requests")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_pending_appchain_metadata_change_requests'")
+        .json::<PagedResult<AppchainMetadataChangeRequest>>()
+}
+
+pub async fn get_appchain_metadata_revisions(
+    registry: &Contract,
+    appchain_id: &String,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<AppchainMetadataRevision>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_metadata_revisions")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_metadata_revisions'")
+        .json::<PagedResult<AppchainMetadataRevision>>()
+}
+
+pub async fn get_appchain_metadata_at_revision(
+    registry: &Contract,
+    appchain_id: &String,
+    revision: u64,
+) -> Result<AppchainMetadataSnapshot, workspaces::error::Error> {
+    registry
+        .call("get_appchain_metadata_at_revision")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "revision": U64::from(revision),
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_metadata_at_revision'")
+        .json::<AppchainMetadataSnapshot>()
+}
+
+pub async fn preview_appchain_metadata_patch(
+    registry: &Contract,
+    appchain_id: &String,
+    patch: &AppchainMetadataPatch,
+) -> Result<AppchainMetadataSnapshot, workspaces::error::Error> {
+    registry
+        .call("preview_appchain_metadata_patch")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "patch": patch,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'preview_appchain_metadata_patch'")
+        .json::<AppchainMetadataSnapshot>()
+}
+
+pub async fn get_appchain_audit_records(
+    registry: &Contract,
+    appchain_id: &String,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<AppchainAuditRecord>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_audit_records")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_audit_records'")
+        .json::<PagedResult<AppchainAuditRecord>>()
+}
+
+pub async fn get_appchain_review_trail(
+    registry: &Contract,
+    appchain_id: &String,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<AppchainReviewRecord>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_review_trail")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_review_trail'")
+        .json::<PagedResult<AppchainReviewRecord>>()
+}
+
+pub async fn get_appchains_by_tag(
+    registry: &Contract,
+    tag: &str,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<AppchainStatus>, workspaces::error::Error> {
+    registry
+        .call("get_appchains_by_tag")
+        .args_json(json!({
+            "tag": tag,
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchains_by_tag'")
+        .json::<PagedResult<AppchainStatus>>()
+}
+
+pub async fn get_pending_appchain_tag_proposals(
+    registry: &Contract,
+    from_index: Option<u64>,
+    limit: Option<u32>,
+) -> Result<PagedResult<AppchainTagProposal>, workspaces::error::Error> {
+    registry
+        .call("get_pending_appchain_tag_proposals")
+        .args_json(json!({
+            "from_index": from_index.map(U64::from),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_pending_appchain_tag_proposals'")
+        .json::<PagedResult<AppchainTagProposal>>()
+}
+
+pub async fn get_appchain_statuses(
+    registry: &Contract,
+    appchain_ids: &[&str],
+) -> Result<Vec<AppchainLookupResult<AppchainStatus>>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_statuses")
+        .args_json(json!({ "appchain_ids": appchain_ids }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_statuses'")
+        .json::<Vec<AppchainLookupResult<AppchainStatus>>>()
+}
+
+pub async fn get_appchain_summaries(
+    registry: &Contract,
+    appchain_ids: &[&str],
+) -> Result<Vec<AppchainLookupResult<AppchainSummary>>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_summaries")
+        .args_json(json!({ "appchain_ids": appchain_ids }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_summaries'")
+        .json::<Vec<AppchainLookupResult<AppchainSummary>>>()
+}
+
+pub async fn is_appchain_in_state(
+    registry: &Contract,
+    appchain_id: &str,
+    appchain_state: AppchainState,
+) -> Result<bool, workspaces::error::Error> {
+    registry
+        .call("is_appchain_in_state")
+        .args_json(json!({
+            "appchain_id": appchain_id,
+            "appchain_state": appchain_state,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'is_appchain_in_state'")
+        .json::<bool>()
+}
+
+pub async fn get_appchain_owner(
+    registry: &Contract,
+    appchain_id: &str,
+) -> Result<Option<VersionedAppchainOwner>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_owner")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_owner'")
+        .json::<Option<VersionedAppchainOwner>>()
+}
+
+pub async fn get_appchain_anchor(
+    registry: &Contract,
+    appchain_id: &str,
+) -> Result<Option<VersionedAppchainAnchor>, workspaces::error::Error> {
+    registry
+        .call("get_appchain_anchor")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_appchain_anchor'")
+        .json::<Option<VersionedAppchainAnchor>>()
+}
+
+pub async fn get_changes_since(
+    registry: &Contract,
+    sequence: u64,
+    limit: Option<u32>,
+) -> Result<AppchainChangeFeed, workspaces::error::Error> {
+    registry
+        .call("get_changes_since")
+        .args_json(json!({
+            "sequence": U64::from(sequence),
+            "limit": limit,
+        }))
+        .view()
+        .await
+        .expect("Failed in calling 'get_changes_since'")
+        .json::<AppchainChangeFeed>()
+}
+
+pub async fn get_registry_statistics(
+    registry: &Contract,
+) -> Result<RegistryStatistics, workspaces::error::Error> {
+    registry
+        .call("get_registry_statistics")
+        .view()
+        .await
+        .expect("Failed in calling 'get_registry_statistics'")
+        .json::<RegistryStatistics>()
+}
@@ -2,7 +2,35 @@ mod common;
 mod contract_interfaces;
 
 mod test_case1;
+mod test_case10;
+mod test_case11;
+mod test_case12;
+mod test_case13;
+mod test_case14;
+mod test_case15;
+mod test_case16;
+mod test_case17;
+mod test_case18;
+mod test_case19;
+mod test_case20;
+mod test_case21;
+mod test_case22;
+mod test_case23;
+mod test_case24;
+mod test_case25;
+mod test_case26;
+mod test_case27;
+mod test_case28;
+mod test_case29;
+mod test_case30;
+mod test_case31;
+mod test_case32;
+mod test_case33;
 mod test_case2;
 mod test_case3;
 mod test_case4;
+mod test_case5;
+mod test_case6;
+mod test_case7;
+mod test_case8;
 mod test_case9;
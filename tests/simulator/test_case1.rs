@@ -6,7 +6,7 @@ use crate::{
     },
 };
 use appchain_registry::types::{
-    AppchainSortingField, AppchainState, AppchainTemplateType, SortingOrder,
+    AppchainMetadataPatch, AppchainSortingField, AppchainState, FieldPatch, SortingOrder,
 };
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
 use near_sdk::{json_types::U128, AccountId};
@@ -23,18 +23,17 @@ async fn test_case1() -> anyhow::Result<()> {
         common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
     //
     assert_eq!(
-        registry_viewer::get_registry_settings(&registry)
+        registry_viewer::get_appchain_template_of(&registry, &"Barnacle".to_string())
             .await?
-            .minimum_register_deposit
+            .unwrap()
+            .register_deposit
             .0,
         common::to_oct_amount(1000)
     );
     let amount = common::to_oct_amount(1200);
-    assert!(
-        registry_settings::change_minimum_register_deposit(&users[0], &registry, amount)
-            .await
-            .unwrap()
-            .is_failure()
+    common::assert_error_code(
+        &registry_settings::change_minimum_register_deposit(&users[0], &registry, amount).await?,
+        "CALLER_IS_NOT_REGISTRY_SETTINGS_MANAGER",
     );
     assert!(
         registry_settings::change_minimum_register_deposit(&root, &registry, amount)
@@ -43,9 +42,10 @@ async fn test_case1() -> anyhow::Result<()> {
             .is_success()
     );
     assert_eq!(
-        registry_viewer::get_registry_settings(&registry)
+        registry_viewer::get_appchain_template_of(&registry, &"Barnacle".to_string())
             .await?
-            .minimum_register_deposit
+            .unwrap()
+            .register_deposit
             .0,
         common::to_oct_amount(1200)
     );
@@ -64,19 +64,19 @@ async fn test_case1() -> anyhow::Result<()> {
     //
     let appchain_id = String::from("test_appchain");
     let amount = common::to_oct_amount(1000);
-    assert!(appchain_owner_actions::register_appchain(
+    let result = appchain_owner_actions::register_appchain(
         &users[0],
         &oct_token,
         &registry,
         &appchain_id,
         Some("appchain1 description".to_string()),
-        Some(AppchainTemplateType::Barnacle),
+        Some("Barnacle".to_string()),
         Some("http://ddfs.dsdfs".to_string()),
-        Some("https://jldfs.yoasdfasd".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
         Some("joe@lksdf.com".to_string()),
         Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
         Some(U128::from(10000000)),
-        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
         Some(U128::from(1000000)),
         Some(U128::from(100)),
         Some(FungibleTokenMetadata {
@@ -91,14 +91,13 @@ async fn test_case1() -> anyhow::Result<()> {
         Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
         amount,
     )
-    .await
-    .unwrap()
-    .is_success());
+    .await?;
+    common::assert_error_code(&result, "NOT_ENOUGH_REGISTER_DEPOSIT");
     assert_eq!(
         registry_viewer::print_appchains(
             &registry,
             Option::None,
-            1,
+            0,
             5,
             AppchainSortingField::AppchainId,
             SortingOrder::Ascending
@@ -116,19 +115,19 @@ async fn test_case1() -> anyhow::Result<()> {
         .await
         .unwrap()
         .is_success());
-    assert!(appchain_owner_actions::register_appchain(
+    let result = appchain_owner_actions::register_appchain(
         &users[0],
         &oct_token,
         &registry,
         &appchain_id,
         Some("appchain1 description".to_string()),
-        Some(AppchainTemplateType::Barnacle),
+        Some("Barnacle".to_string()),
         Some("http://ddfs.dsdfs".to_string()),
-        Some("https://jldfs.yoasdfasd".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
         Some("joe@lksdf.com".to_string()),
         Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
         Some(U128::from(10000000)),
-        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
         Some(U128::from(1000000)),
         Some(U128::from(100)),
         Some(FungibleTokenMetadata {
@@ -143,14 +142,13 @@ async fn test_case1() -> anyhow::Result<()> {
         Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
         amount,
     )
-    .await
-    .unwrap()
-    .is_success());
+    .await?;
+    common::assert_error_code(&result, "ASSET_TRANSFER_IS_PAUSED");
     assert_eq!(
         registry_viewer::print_appchains(
             &registry,
             Option::None,
-            1,
+            0,
             5,
             AppchainSortingField::AppchainId,
             SortingOrder::Ascending
@@ -167,19 +165,19 @@ async fn test_case1() -> anyhow::Result<()> {
         .await
         .unwrap()
         .is_success());
-    assert!(appchain_owner_actions::register_appchain(
+    let result = appchain_owner_actions::register_appchain(
         &users[0],
         &oct_token,
         &registry,
         &appchain_id,
         Some("appchain1 description".to_string()),
-        Some(AppchainTemplateType::Barnacle),
+        Some("Barnacle".to_string()),
         Some("http://ddfs.dsdfs".to_string()),
-        Some("https://jldfs.yoasdfasd".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
         Some("joe@lksdf.com".to_string()),
         Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
         Some(U128::from(10000000)),
-        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
         Some(U128::from(1000000)),
         Some(U128::from(100)),
         Some(FungibleTokenMetadata {
@@ -194,9 +192,8 @@ async fn test_case1() -> anyhow::Result<()> {
         Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
         amount,
     )
-    .await
-    .unwrap()
-    .is_success());
+    .await?;
+    common::assert_no_failure(&result);
     assert_eq!(
         common::get_ft_balance_of(&users[0], &oct_token).await?.0,
         common::to_oct_amount(TOTAL_SUPPLY / 10 - 1200)
@@ -210,15 +207,14 @@ async fn test_case1() -> anyhow::Result<()> {
     let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
     assert_eq!(&appchain.appchain_state, &AppchainState::Registered);
     //
-    assert!(appchain_owner_actions::transfer_appchain_ownership(
+    let result = appchain_owner_actions::transfer_appchain_ownership(
         &users[1],
         &registry,
         &appchain_id,
         &users[1],
     )
-    .await
-    .unwrap()
-    .is_failure());
+    .await?;
+    common::assert_error_code(&result, "CALLER_IS_NOT_APPCHAIN_OWNER");
     assert!(appchain_owner_actions::transfer_appchain_ownership(
         &users[0],
         &registry,
@@ -241,42 +237,49 @@ async fn test_case1() -> anyhow::Result<()> {
     //
     custom_metadata.clear();
     custom_metadata.insert("key3".to_string(), "value3".to_string());
-    assert!(appchain_lifecycle_manager::update_appchain_metadata(
+    let result = appchain_lifecycle_manager::update_appchain_metadata(
         &users[0],
         &registry,
         &appchain_id,
-        Option::from(String::from("https://oct.network")),
-        Option::None,
-        Option::None,
-        Option::None,
-        Option::from(String::from("yangzhen@oct.network")),
-        Option::None,
-        Option::from(U128::from(10_000_000_000_000_000_000_000_000)),
-        Option::from(U128::from(10_000_000_000_000_000_000_000_000)),
-        Option::from(U128::from(1_000_000_000_000_000_000_000_000)),
-        Option::from(U128::from(100_000_000_000_000_000_000)),
-        Option::None,
-        Option::from(custom_metadata.clone()),
+        &AppchainMetadataPatch {
+            website_url: FieldPatch::Set(String::from("https://oct.network")),
+            contact_email: FieldPatch::Set(String::from("yangzhen@oct.network")),
+            premined_wrapped_appchain_token: FieldPatch::Set(U128::from(
+                10_000_000_000_000_000_000_000_000,
+            )),
+            initial_supply_of_wrapped_appchain_token: FieldPatch::Set(U128::from(
+                100_000_000_000_000_000_000_000_000,
+            )),
+            ido_amount_of_wrapped_appchain_token: FieldPatch::Set(U128::from(
+                1_000_000_000_000_000_000_000_000,
+            )),
+            initial_era_reward: FieldPatch::Set(U128::from(100_000_000_000_000_000_000)),
+            custom_metadata: FieldPatch::Set(custom_metadata.clone()),
+            ..Default::default()
+        },
     )
-    .await
-    .unwrap()
-    .is_failure());
+    .await?;
+    common::assert_error_code(&result, "CALLER_IS_NOT_APPCHAIN_LIFECYCLE_MANAGER");
     assert!(appchain_lifecycle_manager::update_appchain_metadata(
         &root,
         &registry,
         &appchain_id,
-        Option::from(String::from("https://oct.network")),
-        Option::None,
-        Option::None,
-        Option::None,
-        Option::from(String::from("yangzhen@oct.network")),
-        Option::None,
-        Option::from(U128::from(10_000_000_000_000_000_000_000_000)),
-        Option::from(U128::from(10_000_000_000_000_000_000_000_000)),
-        Option::from(U128::from(1_000_000_000_000_000_000_000_000)),
-        Option::from(U128::from(100_000_000_000_000_000_000)),
-        Option::None,
-        Option::from(custom_metadata.clone()),
+        &AppchainMetadataPatch {
+            website_url: FieldPatch::Set(String::from("https://oct.network")),
+            contact_email: FieldPatch::Set(String::from("yangzhen@oct.network")),
+            premined_wrapped_appchain_token: FieldPatch::Set(U128::from(
+                10_000_000_000_000_000_000_000_000
+            )),
+            initial_supply_of_wrapped_appchain_token: FieldPatch::Set(U128::from(
+                100_000_000_000_000_000_000_000_000
+            )),
+            ido_amount_of_wrapped_appchain_token: FieldPatch::Set(U128::from(
+                1_000_000_000_000_000_000_000_000
+            )),
+            initial_era_reward: FieldPatch::Set(U128::from(100_000_000_000_000_000_000)),
+            custom_metadata: FieldPatch::Set(custom_metadata.clone()),
+            ..Default::default()
+        },
     )
     .await
     .unwrap()
@@ -292,18 +295,25 @@ async fn test_case1() -> anyhow::Result<()> {
         .eq("yangzhen@oct.network"));
     assert!(appchain.appchain_metadata.custom_metadata.keys().len() == 1);
     //
-    assert!(
-        appchain_lifecycle_manager::reject_appchain(&users[4], &registry, &appchain_id)
-            .await
-            .unwrap()
-            .is_failure()
-    );
-    assert!(
-        appchain_lifecycle_manager::reject_appchain(&root, &registry, &appchain_id)
-            .await
-            .unwrap()
-            .is_success()
+    common::assert_error_code(
+        &appchain_lifecycle_manager::reject_appchain(
+            &users[4],
+            &registry,
+            &appchain_id,
+            "Not qualified.",
+        )
+        .await?,
+        "CALLER_IS_NOT_APPCHAIN_LIFECYCLE_MANAGER",
     );
+    assert!(appchain_lifecycle_manager::reject_appchain(
+        &root,
+        &registry,
+        &appchain_id,
+        "Not qualified."
+    )
+    .await
+    .unwrap()
+    .is_success());
     assert_eq!(
         common::get_ft_balance_of(&users[1], &oct_token).await?.0,
         common::to_oct_amount(TOTAL_SUPPLY / 10)
@@ -315,11 +325,14 @@ async fn test_case1() -> anyhow::Result<()> {
         common::to_oct_amount(1200)
     );
     //
-    assert!(
-        appchain_lifecycle_manager::remove_appchain(&users[2], &registry, &appchain_id)
-            .await
-            .unwrap()
-            .is_failure()
+    // A rejected appchain can only be removed after the appeal window
+    common::assert_no_failure(
+        &registry_settings::change_appeal_window_in_secs(&root, &registry, 1).await?,
+    );
+    worker.fast_forward(1000).await?;
+    common::assert_error_code(
+        &appchain_lifecycle_manager::remove_appchain(&users[2], &registry, &appchain_id).await?,
+        "CALLER_IS_NOT_APPCHAIN_LIFECYCLE_MANAGER",
     );
     assert!(
         appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id)
@@ -331,7 +344,7 @@ async fn test_case1() -> anyhow::Result<()> {
         registry_viewer::print_appchains(
             &registry,
             Option::None,
-            1,
+            0,
             5,
             AppchainSortingField::RegisteredTime,
             SortingOrder::Descending
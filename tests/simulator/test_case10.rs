@@ -0,0 +1,200 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
+};
+use appchain_registry::types::{AppchainMetadataPatch, FieldPatch};
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
+use near_sdk::{json_types::U128, AccountId};
+use std::{collections::HashMap, str::FromStr};
+use workspaces::{result::ExecutionFinalResult, Account, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the validation of appchain metadata in registration and metadata updates.
+#[tokio::test]
+async fn test_case10() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    //
+    // Invalid fields are rejected in registration
+    //
+    common::assert_error_code(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "ftp://octopus.network",
+            "https://github.com/octopus-network/barnacle",
+            "joe@oct.network",
+            100000000,
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    common::assert_error_code(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "https://octopus.network",
+            "https://gitlab.com/octopus-network/barnacle",
+            "joe@oct.network",
+            100000000,
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    common::assert_error_code(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "https://octopus.network",
+            "https://github.com/octopus-network/barnacle",
+            "joe.oct.network",
+            100000000,
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    common::assert_error_code(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "https://octopus.network",
+            "https://github.com/octopus-network/barnacle",
+            "joe@oct.network",
+            10500000,
+        )
+        .await?,
+        "INITIAL_SUPPLY_NOT_ENOUGH",
+    );
+    common::assert_no_failure(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "https://octopus.network",
+            "https://github.com/octopus-network/barnacle",
+            "joe@oct.network",
+            11000000,
+        )
+        .await?,
+    );
+    //
+    // Metadata updates can NOT produce an invalid record
+    //
+    let appchain_id = "appchain1".to_string();
+    common::assert_error_code(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                github_address: FieldPatch::Set(String::from("https://github.com/octopus-network")),
+                ..Default::default()
+            },
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    common::assert_error_code(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                ido_amount_of_wrapped_appchain_token: FieldPatch::Set(U128::from(2000000)),
+                ..Default::default()
+            },
+        )
+        .await?,
+        "INITIAL_SUPPLY_NOT_ENOUGH",
+    );
+    common::assert_error_code(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                custom_metadata: FieldPatch::Set(HashMap::from([(
+                    "key1".to_string(),
+                    "v".repeat(513),
+                )])),
+                ..Default::default()
+            },
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                function_spec_url: FieldPatch::Set(String::from(
+                    "https://octopus.network/function_spec",
+                )),
+                github_address: FieldPatch::Set(String::from(
+                    "https://github.com/octopus-network/barnacle-evm.git",
+                )),
+                initial_supply_of_wrapped_appchain_token: FieldPatch::Set(U128::from(12000000)),
+                ido_amount_of_wrapped_appchain_token: FieldPatch::Set(U128::from(2000000)),
+                ..Default::default()
+            },
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(
+        appchain
+            .appchain_metadata
+            .ido_amount_of_wrapped_appchain_token
+            .0,
+        2000000
+    );
+    Ok(())
+}
+
+async fn register_appchain(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    website_url: &str,
+    github_address: &str,
+    contact_email: &str,
+    initial_supply: u128,
+) -> anyhow::Result<ExecutionFinalResult> {
+    Ok(appchain_owner_actions::register_appchain(
+        signer,
+        oct_token,
+        registry,
+        &"appchain1".to_string(),
+        Some("appchain description".to_string()),
+        Some("Barnacle".to_string()),
+        Some(website_url.to_string()),
+        Some(github_address.to_string()),
+        Some(contact_email.to_string()),
+        Some(AccountId::from_str(signer.id().as_str()).unwrap()),
+        Some(U128::from(10000000)),
+        Some(U128::from(initial_supply)),
+        Some(U128::from(1000000)),
+        Some(U128::from(100)),
+        Some(FungibleTokenMetadata {
+            spec: FT_METADATA_SPEC.to_string(),
+            name: "joeToken".to_string(),
+            symbol: "JOT".to_string(),
+            icon: Option::None,
+            reference: Option::None,
+            reference_hash: Option::None,
+            decimals: 18,
+        }),
+        Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
+        common::to_oct_amount(1000),
+    )
+    .await?)
+}
@@ -0,0 +1,116 @@
+use crate::{
+    common,
+    contract_interfaces::{
+        appchain_lifecycle_manager, appchain_owner_actions, registry_settings, registry_viewer,
+    },
+};
+use near_units::parse_near;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the storage balance of appchain owners in registration, ownership transfer and removal.
+#[tokio::test]
+async fn test_case11() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    //
+    // Registration fails without enough storage balance
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::storage_withdraw(&users[0], &registry, Option::None).await?,
+    );
+    common::assert_error_code(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+        "NOT_ENOUGH_STORAGE_BALANCE",
+    );
+    assert!(
+        registry_viewer::get_appchain_status_of(&registry, &appchain_id)
+            .await
+            .is_err()
+    );
+    //
+    // Registration succeeds after depositing storage balance
+    //
+    common::basic_actions::deposit_storage_to_registry(&users[0], &registry, parse_near!("1 N"))
+        .await?;
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    let storage_balance =
+        registry_viewer::get_appchain_storage_balance_of(&registry, &appchain_id).await?;
+    assert_eq!(
+        storage_balance.appchain_owner.as_str(),
+        users[0].id().as_str()
+    );
+    assert!(storage_balance.storage_used.0 > 0);
+    assert_eq!(
+        storage_balance.storage_staked.0,
+        u128::from(storage_balance.storage_used.0) * near_sdk::env::storage_byte_cost()
+    );
+    let owner_balance = registry_viewer::storage_balance_of(&registry, &users[0])
+        .await?
+        .unwrap();
+    assert!(owner_balance.available.0 < owner_balance.total.0);
+    //
+    // The storage used by the appchain is moved to the new owner
+    //
+    let new_owner_initial_balance = registry_viewer::storage_balance_of(&registry, &users[1])
+        .await?
+        .unwrap();
+    common::assert_no_failure(
+        &appchain_owner_actions::transfer_appchain_ownership(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &users[1],
+        )
+        .await?,
+    );
+    let previous_owner_balance = registry_viewer::storage_balance_of(&registry, &users[0])
+        .await?
+        .unwrap();
+    assert!(previous_owner_balance.available.0 > owner_balance.available.0);
+    let new_owner_balance = registry_viewer::storage_balance_of(&registry, &users[1])
+        .await?
+        .unwrap();
+    let storage_balance =
+        registry_viewer::get_appchain_storage_balance_of(&registry, &appchain_id).await?;
+    assert_eq!(
+        storage_balance.appchain_owner.as_str(),
+        users[1].id().as_str()
+    );
+    assert_eq!(
+        new_owner_initial_balance.available.0 - new_owner_balance.available.0,
+        storage_balance.storage_staked.0
+    );
+    //
+    // The storage is released when the appchain is removed
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(
+            &root,
+            &registry,
+            &appchain_id,
+            "Not qualified.",
+        )
+        .await?,
+    );
+    // A rejected appchain can only be removed after the appeal window
+    common::assert_no_failure(
+        &registry_settings::change_appeal_window_in_secs(&root, &registry, 1).await?,
+    );
+    worker.fast_forward(1000).await?;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id).await?,
+    );
+    let new_owner_balance_after_removal = registry_viewer::storage_balance_of(&registry, &users[1])
+        .await?
+        .unwrap();
+    assert!(new_owner_balance_after_removal.available.0 > new_owner_balance.available.0);
+    Ok(())
+}
@@ -0,0 +1,239 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
+};
+use appchain_registry::types::{AppchainMetadataPatch, FieldPatch};
+use near_sdk::json_types::U128;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the metadata updates by appchain owner and the metadata change requests.
+#[tokio::test]
+async fn test_case12() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    //
+    // Appchain owner can update non-economic fields directly
+    //
+    let website_changes = AppchainMetadataPatch {
+        website_url: FieldPatch::Set("https://barnacle.octopus.network".to_string()),
+        contact_email: FieldPatch::Set("team@barnacle.network".to_string()),
+        ..Default::default()
+    };
+    common::assert_error_code(
+        &appchain_owner_actions::update_appchain_metadata_by_owner(
+            &users[1],
+            &registry,
+            &appchain_id,
+            &website_changes,
+        )
+        .await?,
+        "CALLER_IS_NOT_APPCHAIN_OWNER",
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::update_appchain_metadata_by_owner(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &website_changes,
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(
+        appchain.appchain_metadata.website_url,
+        "https://barnacle.octopus.network"
+    );
+    assert_eq!(
+        appchain.appchain_metadata.contact_email,
+        "team@barnacle.network"
+    );
+    //
+    // Economic fields can NOT be updated by appchain owner directly
+    //
+    let economic_changes = AppchainMetadataPatch {
+        ido_amount_of_wrapped_appchain_token: FieldPatch::Set(U128::from(2000000)),
+        ..Default::default()
+    };
+    common::assert_error_code(
+        &appchain_owner_actions::update_appchain_metadata_by_owner(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &economic_changes,
+        )
+        .await?,
+        "METADATA_FIELD_NOT_UPDATABLE_BY_OWNER",
+    );
+    //
+    // Invalid change requests are rejected when they are submitted
+    //
+    common::assert_error_code(
+        &appchain_owner_actions::request_appchain_metadata_change(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                contact_email: FieldPatch::Set("not-an-email".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    //
+    // Economic fields can be changed by approved change requests
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::request_appchain_metadata_change(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &economic_changes,
+        )
+        .await?,
+    );
+    let requests = registry_viewer::get_pending_appchain_metadata_change_requests(
+        &registry,
+        Some(appchain_id.clone()),
+        None,
+        None,
+    )
+    .await?
+    .items;
+    assert_eq!(requests.len(), 1);
+    let request_id = requests[0].request_id.0;
+    common::assert_error_code(
+        &appchain_lifecycle_manager::approve_appchain_metadata_change_request(
+            &users[0], &registry, request_id,
+        )
+        .await?,
+        "CALLER_IS_NOT_APPCHAIN_LIFECYCLE_MANAGER",
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::approve_appchain_metadata_change_request(
+            &root, &registry, request_id,
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(
+        appchain
+            .appchain_metadata
+            .ido_amount_of_wrapped_appchain_token
+            .0,
+        2000000
+    );
+    assert!(
+        registry_viewer::get_pending_appchain_metadata_change_requests(&registry, None, None, None)
+            .await?
+            .items
+            .is_empty()
+    );
+    common::assert_error_code(
+        &appchain_lifecycle_manager::approve_appchain_metadata_change_request(
+            &root, &registry, request_id,
+        )
+        .await?,
+        "METADATA_CHANGE_REQUEST_NOT_FOUND",
+    );
+    //
+    // Change requests can be rejected by appchain lifecycle manager
+    // or cancelled by appchain owner
+    //
+    let economic_changes = AppchainMetadataPatch {
+        initial_era_reward: FieldPatch::Set(U128::from(200)),
+        ..Default::default()
+    };
+    for _ in 0..2 {
+        common::assert_no_failure(
+            &appchain_owner_actions::request_appchain_metadata_change(
+                &users[0],
+                &registry,
+                &appchain_id,
+                &economic_changes,
+            )
+            .await?,
+        );
+    }
+    let requests =
+        registry_viewer::get_pending_appchain_metadata_change_requests(&registry, None, None, None)
+            .await?
+            .items;
+    assert_eq!(requests.len(), 2);
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain_metadata_change_request(
+            &root,
+            &registry,
+            requests[0].request_id.0,
+        )
+        .await?,
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::cancel_appchain_metadata_change_request(
+            &users[0],
+            &registry,
+            requests[1].request_id.0,
+        )
+        .await?,
+    );
+    assert!(
+        registry_viewer::get_pending_appchain_metadata_change_requests(&registry, None, None, None)
+            .await?
+            .items
+            .is_empty()
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.appchain_metadata.initial_era_reward.0, 100);
+    //
+    // The pending change requests are removed with the appchain
+    //
+    let appchain_id2 = "appchain2".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[1], &oct_token, &registry, &appchain_id2)
+            .await?,
+    );
+    for (signer, appchain_id) in [(&users[0], &appchain_id), (&users[1], &appchain_id2)] {
+        common::assert_no_failure(
+            &appchain_owner_actions::request_appchain_metadata_change(
+                signer,
+                &registry,
+                appchain_id,
+                &economic_changes,
+            )
+            .await?,
+        );
+    }
+    common::assert_no_failure(
+        &appchain_owner_actions::withdraw_appchain(&users[0], &registry, &appchain_id, None)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id).await?,
+    );
+    assert!(
+        registry_viewer::get_pending_appchain_metadata_change_requests(
+            &registry,
+            Some(appchain_id.clone()),
+            None,
+            None,
+        )
+        .await?
+        .items
+        .is_empty()
+    );
+    let requests =
+        registry_viewer::get_pending_appchain_metadata_change_requests(&registry, None, None, None)
+            .await?
+            .items;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].appchain_id, appchain_id2);
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
+};
+use appchain_registry::types::{AppchainMetadataField, AppchainMetadataPatch, FieldPatch};
+use near_sdk::json_types::U128;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the revision history of appchain metadata.
+#[tokio::test]
+async fn test_case13() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    assert!(
+        registry_viewer::get_appchain_metadata_revisions(&registry, &appchain_id, None, None)
+            .await?
+            .items
+            .is_empty()
+    );
+    //
+    // Revision 1: updated by appchain lifecycle manager
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                initial_supply_of_wrapped_appchain_token: FieldPatch::Set(U128::from(200000000)),
+                ..Default::default()
+            },
+        )
+        .await?,
+    );
+    //
+    // Revision 2: updated by appchain owner
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::update_appchain_metadata_by_owner(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                website_url: FieldPatch::Set("https://barnacle.octopus.network".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?,
+    );
+    //
+    // Revision 3: approved change request
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::request_appchain_metadata_change(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                ido_amount_of_wrapped_appchain_token: FieldPatch::Set(U128::from(2000000)),
+                ..Default::default()
+            },
+        )
+        .await?,
+    );
+    let request_id =
+        registry_viewer::get_pending_appchain_metadata_change_requests(&registry, None, None, None)
+            .await?
+            .items[0]
+            .request_id
+            .0;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::approve_appchain_metadata_change_request(
+            &root, &registry, request_id,
+        )
+        .await?,
+    );
+    //
+    // Check the revisions
+    //
+    let revisions =
+        registry_viewer::get_appchain_metadata_revisions(&registry, &appchain_id, None, None)
+            .await?
+            .items;
+    assert_eq!(revisions.len(), 3);
+    assert_eq!(revisions[0].revision.0, 1);
+    assert_eq!(revisions[0].actor.as_str(), root.id().as_str());
+    assert_eq!(revisions[0].changes.len(), 1);
+    assert_eq!(
+        revisions[0].changes[0].field,
+        AppchainMetadataField::InitialSupplyOfWrappedAppchainToken
+    );
+    assert_eq!(revisions[0].changes[0].old_value, "\"100000000\"");
+    assert_eq!(revisions[0].changes[0].new_value, "\"200000000\"");
+    assert_eq!(revisions[1].actor.as_str(), users[0].id().as_str());
+    assert_eq!(
+        revisions[1].changes[0].field,
+        AppchainMetadataField::WebsiteUrl
+    );
+    assert!(revisions[1].change_request_id.is_none());
+    assert_eq!(revisions[2].change_request_id.unwrap().0, request_id);
+    let revisions =
+        registry_viewer::get_appchain_metadata_revisions(&registry, &appchain_id, Some(1), Some(1))
+            .await?
+            .items;
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].revision.0, 2);
+    //
+    // Check the metadata at revisions
+    //
+    let snapshot =
+        registry_viewer::get_appchain_metadata_at_revision(&registry, &appchain_id, 0).await?;
+    assert_eq!(
+        snapshot.metadata.initial_supply_of_wrapped_appchain_token.0,
+        100000000
+    );
+    assert_eq!(snapshot.metadata.website_url, "https://octopus.network");
+    assert_eq!(
+        snapshot.metadata.ido_amount_of_wrapped_appchain_token.0,
+        1000000
+    );
+    let snapshot =
+        registry_viewer::get_appchain_metadata_at_revision(&registry, &appchain_id, 2).await?;
+    assert_eq!(
+        snapshot.metadata.initial_supply_of_wrapped_appchain_token.0,
+        200000000
+    );
+    assert_eq!(
+        snapshot.metadata.website_url,
+        "https://barnacle.octopus.network"
+    );
+    assert_eq!(
+        snapshot.metadata.ido_amount_of_wrapped_appchain_token.0,
+        1000000
+    );
+    let snapshot =
+        registry_viewer::get_appchain_metadata_at_revision(&registry, &appchain_id, 3).await?;
+    assert_eq!(
+        snapshot.metadata.ido_amount_of_wrapped_appchain_token.0,
+        2000000
+    );
+    //
+    // The revisions are kept after the appchain is removed
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::withdraw_appchain(&users[0], &registry, &appchain_id, None)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id).await?,
+    );
+    let revisions =
+        registry_viewer::get_appchain_metadata_revisions(&registry, &appchain_id, None, None)
+            .await?
+            .items;
+    assert_eq!(revisions.len(), 3);
+    //
+    // The revisions of an appchain registered again with the same id are numbered
+    // after the revisions of the removed one
+    //
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                initial_supply_of_wrapped_appchain_token: FieldPatch::Set(U128::from(300000000)),
+                ..Default::default()
+            },
+        )
+        .await?,
+    );
+    let revisions =
+        registry_viewer::get_appchain_metadata_revisions(&registry, &appchain_id, None, None)
+            .await?
+            .items;
+    assert_eq!(revisions.len(), 4);
+    assert_eq!(revisions[3].revision.0, 4);
+    assert_eq!(revisions[3].changes[0].old_value, "\"100000000\"");
+    let snapshot =
+        registry_viewer::get_appchain_metadata_at_revision(&registry, &appchain_id, 3).await?;
+    assert_eq!(
+        snapshot.metadata.initial_supply_of_wrapped_appchain_token.0,
+        100000000
+    );
+    assert_eq!(snapshot.metadata.website_url, "https://octopus.network");
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, registry_viewer},
+};
+use appchain_registry::types::{AppchainMetadataPatch, FieldPatch};
+use std::collections::HashMap;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the set, clear and unchanged semantics of appchain metadata patch.
+#[tokio::test]
+async fn test_case14() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    //
+    // Preview a patch without writing it
+    //
+    let patch = AppchainMetadataPatch {
+        premined_wrapped_appchain_token_beneficiary: FieldPatch::Clear,
+        github_release: FieldPatch::Set("v1.0.0".to_string()),
+        custom_metadata_entries: HashMap::from([
+            ("key1".to_string(), FieldPatch::Clear),
+            ("key2".to_string(), FieldPatch::Set("value2".to_string())),
+        ]),
+        ..Default::default()
+    };
+    let preview =
+        registry_viewer::preview_appchain_metadata_patch(&registry, &appchain_id, &patch).await?;
+    assert!(preview
+        .metadata
+        .premined_wrapped_appchain_token_beneficiary
+        .is_none());
+    assert_eq!(preview.metadata.github_release, "v1.0.0");
+    assert_eq!(
+        preview.metadata.custom_metadata,
+        HashMap::from([("key2".to_string(), "value2".to_string())])
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert!(appchain
+        .appchain_metadata
+        .premined_wrapped_appchain_token_beneficiary
+        .is_some());
+    assert_eq!(
+        appchain.appchain_metadata.custom_metadata,
+        HashMap::from([("key1".to_string(), "value1".to_string())])
+    );
+    //
+    // Apply the patch, the fields not in the patch are unchanged
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &patch,
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert!(appchain
+        .appchain_metadata
+        .premined_wrapped_appchain_token_beneficiary
+        .is_none());
+    assert_eq!(appchain.appchain_metadata.github_release, "v1.0.0");
+    assert_eq!(
+        appchain.appchain_metadata.custom_metadata,
+        HashMap::from([("key2".to_string(), "value2".to_string())])
+    );
+    assert_eq!(
+        appchain.appchain_metadata.website_url,
+        "https://octopus.network"
+    );
+    assert_eq!(appchain.appchain_metadata.initial_era_reward.0, 100);
+    //
+    // Required fields can NOT be cleared
+    //
+    common::assert_error_code(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                website_url: FieldPatch::Clear,
+                ..Default::default()
+            },
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    //
+    // A patch must change something
+    //
+    common::assert_error_code(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch::default(),
+        )
+        .await?,
+        "EMPTY_APPCHAIN_METADATA_PATCH",
+    );
+    Ok(())
+}
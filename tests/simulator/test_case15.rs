@@ -0,0 +1,203 @@
+use crate::{
+    common,
+    contract_interfaces::{
+        appchain_lifecycle_manager, auditor_actions, registry_roles, registry_settings,
+        registry_viewer,
+    },
+};
+use appchain_registry::types::{AppchainState, AuditVerdict};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+const REPORT_URL: &str = "https://audits.oct.network/appchain1.pdf";
+const REPORT_SHA256: &str = "9F86D081884C7D659A2FEAA0C55AD015A3BF4F1B2B0B822CD15D6C15B0F00A08";
+
+/// Test the audit records of auditors and the required approving audits for passing auditing.
+#[tokio::test]
+async fn test_case15() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    let auditor1 = common::basic_actions::create_auditor(&root, &registry, "auditor1").await?;
+    let auditor2 = common::basic_actions::create_auditor(&root, &registry, "auditor2").await?;
+    assert_eq!(
+        registry_viewer::get_registry_roles(&registry)
+            .await?
+            .auditors
+            .len(),
+        2
+    );
+    common::assert_no_failure(
+        &registry_settings::change_required_approving_audits(&root, &registry, 2).await?,
+    );
+    //
+    // Only auditors can submit audit records, with valid report url and hash
+    //
+    common::assert_error_code(
+        &auditor_actions::submit_appchain_audit(
+            &users[1],
+            &registry,
+            &appchain_id,
+            REPORT_URL,
+            REPORT_SHA256,
+            AuditVerdict::Approved,
+        )
+        .await?,
+        "CALLER_IS_NOT_AUDITOR",
+    );
+    common::assert_error_code(
+        &auditor_actions::submit_appchain_audit(
+            &auditor1,
+            &registry,
+            &appchain_id,
+            REPORT_URL,
+            "not a hash",
+            AuditVerdict::Approved,
+        )
+        .await?,
+        "INVALID_FIELD",
+    );
+    //
+    // Passing auditing needs enough approving audits
+    //
+    common::assert_no_failure(
+        &auditor_actions::submit_appchain_audit(
+            &auditor1,
+            &registry,
+            &appchain_id,
+            REPORT_URL,
+            REPORT_SHA256,
+            AuditVerdict::Approved,
+        )
+        .await?,
+    );
+    common::assert_no_failure(
+        &auditor_actions::submit_appchain_audit(
+            &auditor2,
+            &registry,
+            &appchain_id,
+            REPORT_URL,
+            REPORT_SHA256,
+            AuditVerdict::Rejected,
+        )
+        .await?,
+    );
+    let records = registry_viewer::get_appchain_audit_records(&registry, &appchain_id, None, None)
+        .await?
+        .items;
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].report_sha256, REPORT_SHA256.to_lowercase());
+    common::assert_error_code(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id).await?,
+        "NOT_ENOUGH_APPROVING_AUDITS",
+    );
+    //
+    // A re-audit replaces the previous record of the auditor
+    //
+    common::assert_no_failure(
+        &auditor_actions::submit_appchain_audit(
+            &auditor2,
+            &registry,
+            &appchain_id,
+            REPORT_URL,
+            REPORT_SHA256,
+            AuditVerdict::Approved,
+        )
+        .await?,
+    );
+    let records = registry_viewer::get_appchain_audit_records(&registry, &appchain_id, None, None)
+        .await?
+        .items;
+    assert_eq!(records.len(), 2);
+    assert!(records
+        .iter()
+        .all(|record| record.verdict == AuditVerdict::Approved));
+    //
+    // The audits of removed auditors are not counted
+    //
+    common::assert_no_failure(&registry_roles::remove_auditor(&root, &registry, &auditor2).await?);
+    common::assert_error_code(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id).await?,
+        "NOT_ENOUGH_APPROVING_AUDITS",
+    );
+    common::assert_no_failure(
+        &registry_settings::change_required_approving_audits(&root, &registry, 1).await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id).await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.appchain_state, AppchainState::Audited);
+    //
+    // Audits can only be submitted for appchains in state 'registered'
+    //
+    common::assert_error_code(
+        &auditor_actions::submit_appchain_audit(
+            &auditor1,
+            &registry,
+            &appchain_id,
+            REPORT_URL,
+            REPORT_SHA256,
+            AuditVerdict::Approved,
+        )
+        .await?,
+        "INVALID_APPCHAIN_STATE",
+    );
+    //
+    // The audit records are kept after the appchain is removed, but they are NOT
+    // counted for a new appchain with the same id
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(
+            &root,
+            &registry,
+            &appchain_id,
+            "The appchain is a duplicate of an existing one.",
+        )
+        .await?,
+    );
+    // A rejected appchain can only be removed after the appeal window
+    common::assert_no_failure(
+        &registry_settings::change_appeal_window_in_secs(&root, &registry, 1).await?,
+    );
+    worker.fast_forward(1000).await?;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id).await?,
+    );
+    let records = registry_viewer::get_appchain_audit_records(&registry, &appchain_id, None, None)
+        .await?
+        .items;
+    assert_eq!(records.len(), 2);
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    common::assert_error_code(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id).await?,
+        "NOT_ENOUGH_APPROVING_AUDITS",
+    );
+    common::assert_no_failure(
+        &auditor_actions::submit_appchain_audit(
+            &auditor1,
+            &registry,
+            &appchain_id,
+            REPORT_URL,
+            REPORT_SHA256,
+            AuditVerdict::Approved,
+        )
+        .await?,
+    );
+    let records = registry_viewer::get_appchain_audit_records(&registry, &appchain_id, None, None)
+        .await?
+        .items;
+    assert_eq!(records.len(), 3);
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id).await?,
+    );
+    Ok(())
+}
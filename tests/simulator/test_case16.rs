@@ -0,0 +1,226 @@
+use crate::{
+    common,
+    contract_interfaces::{
+        appchain_lifecycle_manager, appchain_owner_actions, registry_settings, registry_viewer,
+    },
+};
+use appchain_registry::types::{AppchainReviewAction, AppchainState};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the reasons of rejection and withdrawal, and the appeals of rejected appchains.
+#[tokio::test]
+async fn test_case16() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id).await?;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id).await?,
+    );
+    //
+    // Rejection needs a reason
+    //
+    common::assert_error_code(
+        &appchain_lifecycle_manager::reject_appchain(&root, &registry, &appchain_id, " ").await?,
+        "INVALID_ARGUMENT",
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(
+            &root,
+            &registry,
+            &appchain_id,
+            "The token economics is not sustainable.",
+        )
+        .await?,
+    );
+    //
+    // Only the appchain owner can appeal, and only once for a rejection
+    //
+    common::assert_error_code(
+        &appchain_owner_actions::appeal_appchain_rejection(
+            &users[1],
+            &registry,
+            &appchain_id,
+            "Please reconsider.",
+        )
+        .await?,
+        "CALLER_IS_NOT_APPCHAIN_OWNER",
+    );
+    common::assert_error_code(
+        &appchain_lifecycle_manager::reinstate_appchain(&root, &registry, &appchain_id, None)
+            .await?,
+        "APPCHAIN_APPEAL_NOT_FOUND",
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::appeal_appchain_rejection(
+            &users[0],
+            &registry,
+            &appchain_id,
+            "The token economics is updated.",
+        )
+        .await?,
+    );
+    common::assert_error_code(
+        &appchain_owner_actions::appeal_appchain_rejection(
+            &users[0],
+            &registry,
+            &appchain_id,
+            "Please reconsider.",
+        )
+        .await?,
+        "APPCHAIN_NOT_APPEALABLE",
+    );
+    common::assert_error_code(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id).await?,
+        "APPCHAIN_APPEAL_PENDING",
+    );
+    //
+    // The appchain is reinstated to the state before rejection
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reinstate_appchain(
+            &root,
+            &registry,
+            &appchain_id,
+            Some("Appeal accepted."),
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.appchain_state, AppchainState::Audited);
+    let trail = registry_viewer::get_appchain_review_trail(&registry, &appchain_id, None, None)
+        .await?
+        .items;
+    assert_eq!(
+        trail
+            .iter()
+            .map(|record| record.action.clone())
+            .collect::<Vec<AppchainReviewAction>>(),
+        vec![
+            AppchainReviewAction::Rejected,
+            AppchainReviewAction::AppealFiled,
+            AppchainReviewAction::Reinstated,
+        ]
+    );
+    assert_eq!(trail[0].previous_state, AppchainState::Audited);
+    assert_eq!(
+        trail[0].reason,
+        Some("The token economics is not sustainable.".to_string())
+    );
+    assert_eq!(trail[1].actor.as_str(), users[0].id().as_str());
+    //
+    // A dismissed appeal keeps the appchain closed
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(&root, &registry, &appchain_id, "Rejected.")
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::appeal_appchain_rejection(
+            &users[0],
+            &registry,
+            &appchain_id,
+            "Please reconsider.",
+        )
+        .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::dismiss_appchain_appeal(
+            &root,
+            &registry,
+            &appchain_id,
+            "Nothing changed.",
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.appchain_state, AppchainState::Closed);
+    assert_eq!(
+        registry_viewer::get_appchain_review_trail(&registry, &appchain_id, None, None)
+            .await?
+            .items
+            .len(),
+        6
+    );
+    //
+    // Appeals are only allowed within the appeal window
+    //
+    let appchain_id2 = "appchain2".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[1], &oct_token, &registry, &appchain_id2)
+            .await?,
+    );
+    common::assert_no_failure(
+        &registry_settings::change_appeal_window_in_secs(&root, &registry, 1).await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(&root, &registry, &appchain_id2, "Rejected.")
+            .await?,
+    );
+    worker.fast_forward(1000).await?;
+    common::assert_error_code(
+        &appchain_owner_actions::appeal_appchain_rejection(
+            &users[1],
+            &registry,
+            &appchain_id2,
+            "Please reconsider.",
+        )
+        .await?,
+        "APPEAL_WINDOW_EXPIRED",
+    );
+    //
+    // A withdrawn appchain can NOT be appealed
+    //
+    let appchain_id3 = "appchain3".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[2], &oct_token, &registry, &appchain_id3)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::withdraw_appchain(
+            &users[2],
+            &registry,
+            &appchain_id3,
+            Some("The team is dissolved."),
+        )
+        .await?,
+    );
+    let trail = registry_viewer::get_appchain_review_trail(&registry, &appchain_id3, None, None)
+        .await?
+        .items;
+    assert_eq!(trail.len(), 1);
+    assert_eq!(trail[0].action, AppchainReviewAction::Withdrawn);
+    assert_eq!(trail[0].previous_state, AppchainState::Registered);
+    common::assert_error_code(
+        &appchain_owner_actions::appeal_appchain_rejection(
+            &users[2],
+            &registry,
+            &appchain_id3,
+            "Please reconsider.",
+        )
+        .await?,
+        "APPCHAIN_NOT_APPEALABLE",
+    );
+    //
+    // The review trail is kept after the appchain is removed
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id3).await?,
+    );
+    let trail = registry_viewer::get_appchain_review_trail(&registry, &appchain_id3, None, None)
+        .await?
+        .items;
+    assert_eq!(trail.len(), 2);
+    assert_eq!(trail[0].action, AppchainReviewAction::Withdrawn);
+    assert_eq!(trail[1].action, AppchainReviewAction::Removed);
+    assert_eq!(trail[1].previous_state, AppchainState::Closed);
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::{common, contract_interfaces::registry_viewer};
+use appchain_registry::types::{AppchainSortingField, AppchainSortingSpec, SortingOrder};
+use near_sdk::serde_json::json;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the cursor pagination of listing views.
+#[tokio::test]
+async fn test_case17() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (_root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    for index in 1..=3 {
+        common::assert_no_failure(
+            &common::basic_actions::register_appchain(
+                &users[index - 1],
+                &oct_token,
+                &registry,
+                &format!("appchain{}", index),
+            )
+            .await?,
+        );
+    }
+    //
+    // Walk through the appchain ids page by page
+    //
+    let page = registry_viewer::get_appchain_ids(&registry, None, Some(2)).await?;
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.total_count.0, 3);
+    assert_eq!(page.next_index.map(|index| index.0), Some(2));
+    let page =
+        registry_viewer::get_appchain_ids(&registry, page.next_index.map(|index| index.0), Some(2))
+            .await?;
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.total_count.0, 3);
+    assert!(page.next_index.is_none());
+    //
+    // An index out of range results in an empty page
+    //
+    let page = registry_viewer::get_appchain_ids(&registry, Some(10), None).await?;
+    assert!(page.items.is_empty());
+    assert_eq!(page.total_count.0, 3);
+    assert!(page.next_index.is_none());
+    //
+    // Pages of sorted appchains
+    //
+    let page = registry_viewer::get_appchains_with_state_of(
+        &registry,
+        None,
+        None,
+        vec![AppchainSortingSpec {
+            field: AppchainSortingField::AppchainId,
+            order: SortingOrder::Descending,
+        }],
+        None,
+        Some(2),
+    )
+    .await?;
+    assert_eq!(
+        page.items
+            .iter()
+            .map(|appchain| appchain.appchain_id.as_str())
+            .collect::<Vec<&str>>(),
+        ["appchain3", "appchain2"]
+    );
+    assert_eq!(page.next_index.map(|index| index.0), Some(2));
+    let page = registry_viewer::get_appchains_with_state_of(
+        &registry,
+        None,
+        None,
+        vec![AppchainSortingSpec {
+            field: AppchainSortingField::AppchainId,
+            order: SortingOrder::Descending,
+        }],
+        Some(2),
+        Some(2),
+    )
+    .await?;
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].appchain_id, "appchain1");
+    assert!(page.next_index.is_none());
+    //
+    // Pages of an `UnorderedMap`
+    //
+    let page = registry_viewer::get_reserved_appchain_ids(&registry, None, Some(4)).await?;
+    assert_eq!(page.items.len(), 4);
+    assert_eq!(page.total_count.0, 6);
+    assert_eq!(page.next_index.map(|index| index.0), Some(4));
+    //
+    // The limit should be in range [1, 100]
+    //
+    for limit in [0, 101] {
+        assert!(registry
+            .call("get_appchain_ids")
+            .args_json(json!({ "from_index": null, "limit": limit }))
+            .view()
+            .await
+            .is_err());
+    }
+    //
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, registry_settings, registry_viewer},
+};
+use appchain_registry::types::{
+    AppchainSortingField, AppchainSortingSpec, AppchainState, AppchainStatus, PagedResult,
+    SortingOrder,
+};
+use near_sdk::serde_json::json;
+use workspaces::Contract;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the sorting of appchains by multiple keys, and by the ordered indexes.
+#[tokio::test]
+async fn test_case18() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
+    for index in 1..=3 {
+        common::assert_no_failure(
+            &common::basic_actions::register_appchain(
+                &users[index - 1],
+                &oct_token,
+                &registry,
+                &format!("appchain{}", index),
+            )
+            .await?,
+        );
+        worker.fast_forward(10).await?;
+    }
+    let appchain_id2 = "appchain2".to_string();
+    let appchain_id3 = "appchain3".to_string();
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id2).await?;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id2)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(
+            &root,
+            &registry,
+            &appchain_id3,
+            "Not qualified.",
+        )
+        .await?,
+    );
+    //
+    // Sort by state, and then by registered time
+    //
+    let page = get_sorted_appchains(
+        &registry,
+        None,
+        &[
+            (
+                AppchainSortingField::AppchainState,
+                SortingOrder::Descending,
+            ),
+            (
+                AppchainSortingField::RegisteredTime,
+                SortingOrder::Ascending,
+            ),
+        ],
+        None,
+    )
+    .await?;
+    assert_eq!(
+        appchain_ids_of(&page),
+        ["appchain3", "appchain2", "appchain1"]
+    );
+    let page = get_sorted_appchains(
+        &registry,
+        None,
+        &[(AppchainSortingField::AppchainState, SortingOrder::Ascending)],
+        None,
+    )
+    .await?;
+    assert_eq!(
+        appchain_ids_of(&page),
+        ["appchain1", "appchain2", "appchain3"]
+    );
+    //
+    // A sorting field can NOT be specified more than once
+    //
+    assert!(registry
+        .call("get_appchains_with_state_of")
+        .args_json(json!({
+            "appchain_state": null,
+            "sorting": [
+                AppchainSortingSpec {
+                    field: AppchainSortingField::RegisteredTime,
+                    order: SortingOrder::Ascending,
+                },
+                AppchainSortingSpec {
+                    field: AppchainSortingField::RegisteredTime,
+                    order: SortingOrder::Descending,
+                },
+            ],
+        }))
+        .view()
+        .await
+        .is_err());
+    //
+    // Sort by the ordered indexes, the ties are ordered by appchain id
+    //
+    let page = get_sorted_appchains(
+        &registry,
+        None,
+        &[(AppchainSortingField::TotalStake, SortingOrder::Descending)],
+        None,
+    )
+    .await?;
+    assert_eq!(
+        appchain_ids_of(&page),
+        ["appchain3", "appchain2", "appchain1"]
+    );
+    let page = get_sorted_appchains(
+        &registry,
+        Some([AppchainState::Registered, AppchainState::Audited].to_vec()),
+        &[(AppchainSortingField::TotalStake, SortingOrder::Descending)],
+        None,
+    )
+    .await?;
+    assert_eq!(appchain_ids_of(&page), ["appchain2", "appchain1"]);
+    assert_eq!(page.total_count.0, 2);
+    let page = get_sorted_appchains(
+        &registry,
+        None,
+        &[(AppchainSortingField::VotingScore, SortingOrder::Ascending)],
+        Some(2),
+    )
+    .await?;
+    assert_eq!(appchain_ids_of(&page), ["appchain1", "appchain2"]);
+    assert_eq!(page.next_index.map(|index| index.0), Some(2));
+    //
+    // A removed appchain is removed from the ordered indexes
+    //
+    // A rejected appchain can only be removed after the appeal window
+    common::assert_no_failure(
+        &registry_settings::change_appeal_window_in_secs(&root, &registry, 1).await?,
+    );
+    worker.fast_forward(1000).await?;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id3).await?,
+    );
+    let page = get_sorted_appchains(
+        &registry,
+        None,
+        &[(AppchainSortingField::TotalStake, SortingOrder::Ascending)],
+        None,
+    )
+    .await?;
+    assert_eq!(appchain_ids_of(&page), ["appchain1", "appchain2"]);
+    assert_eq!(page.total_count.0, 2);
+    //
+    Ok(())
+}
+
+async fn get_sorted_appchains(
+    registry: &Contract,
+    appchain_state: Option<Vec<AppchainState>>,
+    sorting: &[(AppchainSortingField, SortingOrder)],
+    limit: Option<u32>,
+) -> anyhow::Result<PagedResult<AppchainStatus>> {
+    Ok(registry_viewer::get_appchains_with_state_of(
+        registry,
+        appchain_state,
+        None,
+        sorting
+            .iter()
+            .map(|(field, order)| AppchainSortingSpec {
+                field: field.clone(),
+                order: order.clone(),
+            })
+            .collect(),
+        None,
+        limit,
+    )
+    .await?)
+}
+
+fn appchain_ids_of(page: &PagedResult<AppchainStatus>) -> Vec<&str> {
+    page.items
+        .iter()
+        .map(|appchain| appchain.appchain_id.as_str())
+        .collect()
+}
@@ -0,0 +1,297 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
+};
+use appchain_registry::types::{
+    AppchainFilter, AppchainSortingField, AppchainSortingSpec, AppchainState, AppchainStatus,
+    PagedResult, SortingOrder,
+};
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
+use near_sdk::{
+    json_types::{U128, U64},
+    serde_json::json,
+    AccountId,
+};
+use std::{collections::HashMap, str::FromStr};
+use workspaces::{result::ExecutionFinalResult, Account, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the filtering of appchains by template type, owner, EVM chain id,
+/// registered time and custom metadata.
+#[tokio::test]
+async fn test_case19() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
+    common::assert_no_failure(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            "Barnacle",
+            None,
+            HashMap::from([
+                ("category".to_string(), "defi".to_string()),
+                ("key1".to_string(), "value1".to_string()),
+            ]),
+        )
+        .await?,
+    );
+    worker.fast_forward(10).await?;
+    common::assert_no_failure(
+        &register_appchain(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain2",
+            "BarnacleEvm",
+            Some(9001),
+            HashMap::from([("category".to_string(), "gaming".to_string())]),
+        )
+        .await?,
+    );
+    worker.fast_forward(10).await?;
+    common::assert_no_failure(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain3",
+            "BarnacleEvm",
+            Some(9002),
+            HashMap::from([("category".to_string(), "defi".to_string())]),
+        )
+        .await?,
+    );
+    let registered_time_of_appchain2 =
+        registry_viewer::get_appchain_status_of(&registry, &"appchain2".to_string())
+            .await?
+            .registered_time;
+    //
+    // Filter by each of the conditions
+    //
+    let cases = [
+        (
+            AppchainFilter {
+                template_types: Some(vec!["BarnacleEvm".to_string()]),
+                ..Default::default()
+            },
+            vec!["appchain2", "appchain3"],
+        ),
+        (
+            AppchainFilter {
+                owner: Some(AccountId::from_str(users[0].id().as_str()).unwrap()),
+                ..Default::default()
+            },
+            vec!["appchain1", "appchain3"],
+        ),
+        (
+            AppchainFilter {
+                has_evm_chain_id: Some(false),
+                ..Default::default()
+            },
+            vec!["appchain1"],
+        ),
+        (
+            AppchainFilter {
+                registered_time_from: Some(registered_time_of_appchain2),
+                ..Default::default()
+            },
+            vec!["appchain2", "appchain3"],
+        ),
+        (
+            AppchainFilter {
+                registered_time_to: Some(registered_time_of_appchain2),
+                ..Default::default()
+            },
+            vec!["appchain1"],
+        ),
+        (
+            AppchainFilter {
+                custom_metadata: Some(HashMap::from([(
+                    "category".to_string(),
+                    "defi".to_string(),
+                )])),
+                ..Default::default()
+            },
+            vec!["appchain1", "appchain3"],
+        ),
+        (
+            AppchainFilter {
+                custom_metadata: Some(HashMap::from([
+                    ("category".to_string(), "defi".to_string()),
+                    ("key1".to_string(), "value1".to_string()),
+                ])),
+                ..Default::default()
+            },
+            vec!["appchain1"],
+        ),
+    ];
+    for (filter, expected_ids) in cases {
+        let page =
+            get_filtered_appchains(&registry, None, filter, AppchainSortingField::AppchainId)
+                .await?;
+        assert_eq!(appchain_ids_of(&page), expected_ids);
+        assert_eq!(page.total_count.0, expected_ids.len() as u64);
+    }
+    //
+    // The conditions are combined, and work together with state filtering and pagination
+    //
+    let filter = AppchainFilter {
+        template_types: Some(vec!["BarnacleEvm".to_string()]),
+        owner: Some(AccountId::from_str(users[0].id().as_str()).unwrap()),
+        ..Default::default()
+    };
+    let page = get_filtered_appchains(
+        &registry,
+        Some([AppchainState::Registered].to_vec()),
+        filter.clone(),
+        AppchainSortingField::AppchainId,
+    )
+    .await?;
+    assert_eq!(appchain_ids_of(&page), ["appchain3"]);
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &"appchain3".to_string())
+        .await?;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::pass_auditing_appchain(
+            &root,
+            &registry,
+            &"appchain3".to_string(),
+        )
+        .await?,
+    );
+    let page = get_filtered_appchains(
+        &registry,
+        Some([AppchainState::Registered].to_vec()),
+        filter.clone(),
+        AppchainSortingField::AppchainId,
+    )
+    .await?;
+    assert!(page.items.is_empty());
+    assert_eq!(page.total_count.0, 0);
+    let page = get_filtered_appchains(
+        &registry,
+        Some([AppchainState::Audited].to_vec()),
+        filter,
+        AppchainSortingField::TotalStake,
+    )
+    .await?;
+    assert_eq!(appchain_ids_of(&page), ["appchain3"]);
+    let page = registry_viewer::get_appchains_with_state_of(
+        &registry,
+        None,
+        Some(AppchainFilter {
+            has_evm_chain_id: Some(true),
+            ..Default::default()
+        }),
+        vec![AppchainSortingSpec {
+            field: AppchainSortingField::AppchainId,
+            order: SortingOrder::Ascending,
+        }],
+        None,
+        Some(1),
+    )
+    .await?;
+    assert_eq!(appchain_ids_of(&page), ["appchain2"]);
+    assert_eq!(page.total_count.0, 2);
+    assert_eq!(page.next_index.map(|index| index.0), Some(1));
+    //
+    // The range of registered time should not be empty
+    //
+    assert!(registry
+        .call("get_appchains_with_state_of")
+        .args_json(json!({
+            "appchain_state": null,
+            "filter": AppchainFilter {
+                registered_time_from: Some(registered_time_of_appchain2),
+                registered_time_to: Some(registered_time_of_appchain2),
+                ..Default::default()
+            },
+            "sorting": [],
+        }))
+        .view()
+        .await
+        .is_err());
+    //
+    Ok(())
+}
+
+async fn get_filtered_appchains(
+    registry: &Contract,
+    appchain_state: Option<Vec<AppchainState>>,
+    filter: AppchainFilter,
+    sorting_field: AppchainSortingField,
+) -> anyhow::Result<PagedResult<AppchainStatus>> {
+    Ok(registry_viewer::get_appchains_with_state_of(
+        registry,
+        appchain_state,
+        Some(filter),
+        vec![AppchainSortingSpec {
+            field: sorting_field,
+            order: SortingOrder::Ascending,
+        }],
+        None,
+        None,
+    )
+    .await?)
+}
+
+fn appchain_ids_of(page: &PagedResult<AppchainStatus>) -> Vec<&str> {
+    page.items
+        .iter()
+        .map(|appchain| appchain.appchain_id.as_str())
+        .collect()
+}
+
+async fn register_appchain(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+    template_type: &str,
+    evm_chain_id: Option<u64>,
+    custom_metadata: HashMap<String, String>,
+) -> anyhow::Result<ExecutionFinalResult> {
+    appchain_owner_actions::commit_appchain_registration(signer, oct_token, registry, appchain_id)
+        .await?;
+    let result = common::call_ft_transfer_call(
+        signer,
+        &registry.as_account(),
+        common::to_oct_amount(1000),
+        json!({
+            "RegisterAppchain":{
+                "appchain_id": appchain_id,
+                "description": "appchain description",
+                "template_type": template_type,
+                "evm_chain_id": evm_chain_id.map(U64::from),
+                "website_url": "http://ddfs.dsdfs",
+                "github_address": "https://github.com/octopus-network/barnacle",
+                "contact_email": "joe@lksdf.com",
+                "premined_wrapped_appchain_token_beneficiary": signer.id(),
+                "premined_wrapped_appchain_token": U128::from(10000000),
+                "initial_supply_of_wrapped_appchain_token": U128::from(100000000),
+                "ido_amount_of_wrapped_appchain_token": U128::from(1000000),
+                "initial_era_reward": U128::from(100),
+                "fungible_token_metadata": FungibleTokenMetadata {
+                    spec: FT_METADATA_SPEC.to_string(),
+                    name: "joeToken".to_string(),
+                    symbol: "JOT".to_string(),
+                    icon: Option::None,
+                    reference: Option::None,
+                    reference_hash: Option::None,
+                    decimals: 18,
+                },
+                "custom_metadata": custom_metadata,
+                "registration_salt": appchain_owner_actions::REGISTRATION_SALT
+            }
+        })
+        .to_string(),
+        oct_token,
+    )
+    .await?;
+    Ok(result)
+}
@@ -5,9 +5,7 @@ use crate::{
         sudo_actions,
     },
 };
-use appchain_registry::types::{
-    AppchainSortingField, AppchainState, AppchainTemplateType, SortingOrder,
-};
+use appchain_registry::types::{AppchainSortingField, AppchainState, SortingOrder};
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
 use near_sdk::{json_types::U128, AccountId};
 use std::{collections::HashMap, str::FromStr};
@@ -21,6 +19,7 @@ async fn test_case2() -> anyhow::Result<()> {
     let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
     let (root, oct_token, registry, council, users) =
         common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
     //
     let appchain_id1 = "test_appchain1".to_string();
     let amount = common::to_oct_amount(1000);
@@ -30,13 +29,13 @@ async fn test_case2() -> anyhow::Result<()> {
         &registry,
         &appchain_id1,
         Some("appchain1 description".to_string()),
-        Some(AppchainTemplateType::Barnacle),
+        Some("Barnacle".to_string()),
         Some("http://ddfs.dsdfs".to_string()),
-        Some("https://jldfs.yoasdfasd".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
         Some("joe@lksdf.com".to_string()),
         Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
         Some(U128::from(10000000)),
-        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
         Some(U128::from(1000000)),
         Some(U128::from(100)),
         Some(FungibleTokenMetadata {
@@ -65,13 +64,13 @@ async fn test_case2() -> anyhow::Result<()> {
         &registry,
         &appchain_id2,
         Some("appchain2 description".to_string()),
-        Some(AppchainTemplateType::Barnacle),
+        Some("Barnacle".to_string()),
         Some("http://ddfs.dsdfs".to_string()),
-        Some("https://jldfs.yoasdfasd".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
         Some("joe@lksdf.com".to_string()),
         Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
         Some(U128::from(10000000)),
-        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
         Some(U128::from(1000000)),
         Some(U128::from(100)),
         Some(FungibleTokenMetadata {
@@ -100,13 +99,13 @@ async fn test_case2() -> anyhow::Result<()> {
         &registry,
         &appchain_id3,
         Some("appchain3 description".to_string()),
-        Some(AppchainTemplateType::Barnacle),
+        Some("Barnacle".to_string()),
         Some("http://ddfs.dsdfs".to_string()),
-        Some("https://jldfs.yoasdfasd".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
         Some("joe@lksdf.com".to_string()),
         Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
         Some(U128::from(10000000)),
-        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
         Some(U128::from(1000000)),
         Some(U128::from(100)),
         Some(FungibleTokenMetadata {
@@ -163,6 +162,7 @@ async fn test_case2() -> anyhow::Result<()> {
     .unwrap()
     .is_failure());
     //
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id1).await?;
     assert!(
         appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id1)
             .await
@@ -180,6 +180,7 @@ async fn test_case2() -> anyhow::Result<()> {
     let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id1).await?;
     assert_eq!(&appchain.appchain_state, &AppchainState::Voting);
     //
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id2).await?;
     assert!(
         appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id2)
             .await
@@ -197,6 +198,7 @@ async fn test_case2() -> anyhow::Result<()> {
     let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id2).await?;
     assert_eq!(&appchain.appchain_state, &AppchainState::Voting);
     //
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id3).await?;
     assert!(
         appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id3)
             .await
@@ -218,7 +220,7 @@ async fn test_case2() -> anyhow::Result<()> {
         registry_viewer::print_appchains(
             &registry,
             Option::None,
-            1,
+            0,
             10,
             AppchainSortingField::AppchainId,
             SortingOrder::Descending
@@ -279,7 +281,7 @@ async fn test_case2() -> anyhow::Result<()> {
         registry_viewer::print_appchains(
             &registry,
             Option::Some([AppchainState::Voting].to_vec()),
-            1,
+            0,
             5,
             AppchainSortingField::RegisteredTime,
             SortingOrder::Ascending
@@ -291,7 +293,7 @@ async fn test_case2() -> anyhow::Result<()> {
         registry_viewer::print_appchains(
             &registry,
             Option::Some([AppchainState::Booting].to_vec()),
-            1,
+            0,
             5,
             AppchainSortingField::RegisteredTime,
             SortingOrder::Ascending
@@ -0,0 +1,229 @@
+use crate::{
+    common,
+    contract_interfaces::{
+        appchain_lifecycle_manager, appchain_owner_actions, registry_settings, registry_viewer,
+    },
+};
+use appchain_registry::types::AppchainFilter;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the category vocabulary and the tags of appchains.
+#[tokio::test]
+async fn test_case20() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id1 = "appchain1".to_string();
+    let appchain_id2 = "appchain2".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id1)
+            .await?,
+    );
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[1], &oct_token, &registry, &appchain_id2)
+            .await?,
+    );
+    //
+    // The vocabulary of categories is managed by registry settings manager
+    //
+    common::assert_error_code(
+        &registry_settings::add_appchain_category(&root, &registry, "Metaverse").await?,
+        "INVALID_ARGUMENT",
+    );
+    common::assert_error_code(
+        &registry_settings::add_appchain_category(&root, &registry, "gaming").await?,
+        "APPCHAIN_CATEGORY_ALREADY_EXISTS",
+    );
+    common::assert_no_failure(
+        &registry_settings::add_appchain_category(&root, &registry, "metaverse").await?,
+    );
+    let registry_settings = registry_viewer::get_registry_settings(&registry).await?;
+    assert!(registry_settings
+        .appchain_categories
+        .contains(&"metaverse".to_string()));
+    //
+    // Tags proposed by the appchain owner need approval
+    //
+    common::assert_error_code(
+        &appchain_owner_actions::propose_appchain_tags(
+            &users[1],
+            &registry,
+            &appchain_id1,
+            vec!["gaming"],
+        )
+        .await?,
+        "CALLER_IS_NOT_APPCHAIN_OWNER",
+    );
+    common::assert_error_code(
+        &appchain_owner_actions::propose_appchain_tags(
+            &users[0],
+            &registry,
+            &appchain_id1,
+            vec!["gaming", "unknown"],
+        )
+        .await?,
+        "APPCHAIN_CATEGORY_NOT_FOUND",
+    );
+    common::assert_error_code(
+        &appchain_owner_actions::propose_appchain_tags(
+            &users[0],
+            &registry,
+            &appchain_id1,
+            vec!["gaming", "gaming"],
+        )
+        .await?,
+        "INVALID_ARGUMENT",
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::propose_appchain_tags(
+            &users[0],
+            &registry,
+            &appchain_id1,
+            vec!["gaming", "nft"],
+        )
+        .await?,
+    );
+    let proposals = registry_viewer::get_pending_appchain_tag_proposals(&registry, None, None)
+        .await?
+        .items;
+    assert_eq!(proposals.len(), 1);
+    assert_eq!(proposals[0].tags, vec!["gaming", "nft"]);
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id1).await?;
+    assert!(appchain.tags.is_empty());
+    common::assert_error_code(
+        &appchain_lifecycle_manager::approve_appchain_tag_proposal(
+            &users[0],
+            &registry,
+            &appchain_id1,
+        )
+        .await?,
+        "CALLER_IS_NOT_APPCHAIN_LIFECYCLE_MANAGER",
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::approve_appchain_tag_proposal(&root, &registry, &appchain_id1)
+            .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id1).await?;
+    assert_eq!(appchain.tags, vec!["gaming", "nft"]);
+    assert!(
+        registry_viewer::get_pending_appchain_tag_proposals(&registry, None, None)
+            .await?
+            .items
+            .is_empty()
+    );
+    common::assert_error_code(
+        &appchain_lifecycle_manager::approve_appchain_tag_proposal(&root, &registry, &appchain_id1)
+            .await?,
+        "APPCHAIN_TAG_PROPOSAL_NOT_FOUND",
+    );
+    //
+    // Proposals can be cancelled by the owner or rejected by lifecycle manager
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::propose_appchain_tags(
+            &users[1],
+            &registry,
+            &appchain_id2,
+            vec!["defi"],
+        )
+        .await?,
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::cancel_appchain_tag_proposal(&users[1], &registry, &appchain_id2)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::propose_appchain_tags(
+            &users[1],
+            &registry,
+            &appchain_id2,
+            vec!["defi"],
+        )
+        .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain_tag_proposal(&root, &registry, &appchain_id2)
+            .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id2).await?;
+    assert!(appchain.tags.is_empty());
+    //
+    // Lifecycle manager can set the tags directly
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::set_appchain_tags(
+            &root,
+            &registry,
+            &appchain_id2,
+            vec!["gaming", "metaverse"],
+        )
+        .await?,
+    );
+    //
+    // Query appchains by tag
+    //
+    let gaming = registry_viewer::get_appchains_by_tag(&registry, "gaming", None, None).await?;
+    assert_eq!(gaming.total_count.0, 2);
+    assert_eq!(
+        gaming
+            .items
+            .iter()
+            .map(|appchain| appchain.appchain_id.clone())
+            .collect::<Vec<String>>(),
+        vec![appchain_id1.clone(), appchain_id2.clone()]
+    );
+    let gaming =
+        registry_viewer::get_appchains_by_tag(&registry, "gaming", Some(1), Some(1)).await?;
+    assert_eq!(gaming.items.len(), 1);
+    assert_eq!(gaming.items[0].appchain_id, appchain_id2);
+    assert!(gaming.next_index.is_none());
+    let nft = registry_viewer::get_appchains_by_tag(&registry, "nft", None, None).await?;
+    assert_eq!(nft.items.len(), 1);
+    assert_eq!(nft.items[0].appchain_id, appchain_id1);
+    let result = registry_viewer::get_appchains_with_state_of(
+        &registry,
+        None,
+        Some(AppchainFilter {
+            tags: Some(vec!["gaming".to_string(), "metaverse".to_string()]),
+            ..Default::default()
+        }),
+        Vec::new(),
+        None,
+        None,
+    )
+    .await?;
+    assert_eq!(result.items.len(), 1);
+    assert_eq!(result.items[0].appchain_id, appchain_id2);
+    //
+    // A category used by appchains can NOT be removed
+    //
+    common::assert_error_code(
+        &registry_settings::remove_appchain_category(&root, &registry, "metaverse").await?,
+        "APPCHAIN_CATEGORY_IN_USE",
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::set_appchain_tags(
+            &root,
+            &registry,
+            &appchain_id2,
+            vec!["gaming"],
+        )
+        .await?,
+    );
+    assert!(
+        registry_viewer::get_appchains_by_tag(&registry, "metaverse", None, None)
+            .await?
+            .items
+            .is_empty()
+    );
+    common::assert_no_failure(
+        &registry_settings::remove_appchain_category(&root, &registry, "metaverse").await?,
+    );
+    common::assert_error_code(
+        &registry_settings::remove_appchain_category(&root, &registry, "metaverse").await?,
+        "APPCHAIN_CATEGORY_NOT_FOUND",
+    );
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::{common, contract_interfaces::registry_viewer};
+use appchain_registry::types::AppchainState;
+use near_sdk::serde_json::json;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the batch lookup of appchain statuses and summaries.
+#[tokio::test]
+async fn test_case21() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (_root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    for index in 1..=2 {
+        common::assert_no_failure(
+            &common::basic_actions::register_appchain(
+                &users[index - 1],
+                &oct_token,
+                &registry,
+                &format!("appchain{}", index),
+            )
+            .await?,
+        );
+    }
+    //
+    // The results are in the order of the given ids, with not-found entries
+    //
+    let statuses =
+        registry_viewer::get_appchain_statuses(&registry, &["appchain2", "unknown", "appchain1"])
+            .await?;
+    assert_eq!(
+        statuses
+            .iter()
+            .map(|result| result.appchain_id.clone())
+            .collect::<Vec<String>>(),
+        vec!["appchain2", "unknown", "appchain1"]
+    );
+    assert_eq!(
+        statuses[0].item.as_ref().unwrap().appchain_owner.as_str(),
+        users[1].id().as_str()
+    );
+    assert!(statuses[1].item.is_none());
+    assert_eq!(
+        statuses[2]
+            .item
+            .as_ref()
+            .unwrap()
+            .appchain_metadata
+            .description,
+        "appchain description"
+    );
+    //
+    // The summaries are consistent with the full statuses
+    //
+    let summaries =
+        registry_viewer::get_appchain_summaries(&registry, &["unknown", "appchain1"]).await?;
+    assert!(summaries[0].item.is_none());
+    let summary = summaries[1].item.as_ref().unwrap();
+    let status = statuses[2].item.as_ref().unwrap();
+    assert_eq!(summary.appchain_id, status.appchain_id);
+    assert_eq!(summary.appchain_state, AppchainState::Registered);
+    assert_eq!(summary.voting_score.0, status.voting_score.0);
+    assert_eq!(summary.total_stake.0, status.total_stake.0);
+    assert_eq!(summary.registered_time.0, status.registered_time.0);
+    //
+    // At most 100 appchains can be looked up at once
+    //
+    let appchain_ids: Vec<String> = (0..101).map(|index| format!("appchain{}", index)).collect();
+    assert!(registry
+        .call("get_appchain_statuses")
+        .args_json(json!({ "appchain_ids": appchain_ids }))
+        .view()
+        .await
+        .is_err());
+    assert!(registry_viewer::get_appchain_summaries(&registry, &[])
+        .await?
+        .is_empty());
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
+};
+use appchain_registry::types::{
+    AppchainChangeFeed, AppchainChangeKind, AppchainMetadataPatch, FieldPatch,
+};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the change feed of appchains.
+#[tokio::test]
+async fn test_case22() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id1 = "appchain1".to_string();
+    let appchain_id2 = "appchain2".to_string();
+    let feed = registry_viewer::get_changes_since(&registry, 0, None).await?;
+    assert!(feed.changes.is_empty());
+    assert_eq!(feed.latest_sequence.0, 0);
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id1)
+            .await?,
+    );
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[1], &oct_token, &registry, &appchain_id2)
+            .await?,
+    );
+    let feed = registry_viewer::get_changes_since(&registry, 0, None).await?;
+    assert_eq!(
+        changes_of(&feed),
+        vec![
+            (1, appchain_id1.clone(), AppchainChangeKind::Registered),
+            (2, appchain_id2.clone(), AppchainChangeKind::Registered),
+        ]
+    );
+    //
+    // Only the latest change of an appchain is kept, in the order of sequence
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::update_appchain_metadata_by_owner(
+            &users[0],
+            &registry,
+            &appchain_id1,
+            &AppchainMetadataPatch {
+                website_url: FieldPatch::Set("https://barnacle.octopus.network".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?,
+    );
+    let feed = registry_viewer::get_changes_since(&registry, 0, None).await?;
+    assert_eq!(feed.latest_sequence.0, 3);
+    assert_eq!(
+        changes_of(&feed),
+        vec![
+            (2, appchain_id2.clone(), AppchainChangeKind::Registered),
+            (3, appchain_id1.clone(), AppchainChangeKind::MetadataUpdated),
+        ]
+    );
+    //
+    // A mirror follows the feed from the last sequence it has seen
+    //
+    let feed = registry_viewer::get_changes_since(&registry, 2, None).await?;
+    assert_eq!(
+        changes_of(&feed),
+        vec![(3, appchain_id1.clone(), AppchainChangeKind::MetadataUpdated)]
+    );
+    let feed = registry_viewer::get_changes_since(&registry, 0, Some(1)).await?;
+    assert_eq!(
+        changes_of(&feed),
+        vec![(2, appchain_id2.clone(), AppchainChangeKind::Registered)]
+    );
+    assert!(registry_viewer::get_changes_since(&registry, 3, None)
+        .await?
+        .changes
+        .is_empty());
+    //
+    // State changes and removals are in the feed
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::withdraw_appchain(&users[1], &registry, &appchain_id2, None)
+            .await?,
+    );
+    let feed = registry_viewer::get_changes_since(&registry, 3, None).await?;
+    assert_eq!(
+        changes_of(&feed),
+        vec![(4, appchain_id2.clone(), AppchainChangeKind::StateChanged)]
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id2).await?,
+    );
+    let feed = registry_viewer::get_changes_since(&registry, 0, None).await?;
+    assert_eq!(
+        changes_of(&feed),
+        vec![
+            (3, appchain_id1.clone(), AppchainChangeKind::MetadataUpdated),
+            (5, appchain_id2.clone(), AppchainChangeKind::Removed),
+        ]
+    );
+    Ok(())
+}
+
+fn changes_of(feed: &AppchainChangeFeed) -> Vec<(u64, String, AppchainChangeKind)> {
+    feed.changes
+        .iter()
+        .map(|change| {
+            (
+                change.sequence.0,
+                change.appchain_id.clone(),
+                change.kind.clone(),
+            )
+        })
+        .collect()
+}
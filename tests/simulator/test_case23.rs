@@ -0,0 +1,123 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
+};
+use appchain_registry::types::{AppchainState, AppchainStateStatistics, RegistryStatistics};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the statistics of the registry.
+#[tokio::test]
+async fn test_case23() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
+    let statistics = registry_viewer::get_registry_statistics(&registry).await?;
+    assert_eq!(statistics.appchain_states.len(), 7);
+    assert!(statistics
+        .appchain_states
+        .iter()
+        .all(|state_statistics| state_statistics.appchain_count == 0));
+    assert_eq!(statistics.total_register_deposit.0, 0);
+    assert_eq!(statistics.distinct_owner_count.0, 0);
+    assert_eq!(statistics.distinct_voter_count.0, 0);
+    //
+    // Registrations are counted in state 'registered'
+    //
+    let appchain_id1 = "appchain1".to_string();
+    let appchain_id2 = "appchain2".to_string();
+    let appchain_id3 = "appchain3".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id1)
+            .await?,
+    );
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id2)
+            .await?,
+    );
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[1], &oct_token, &registry, &appchain_id3)
+            .await?,
+    );
+    let register_deposit = registry_viewer::get_appchain_status_of(&registry, &appchain_id1)
+        .await?
+        .register_deposit
+        .0;
+    let statistics = registry_viewer::get_registry_statistics(&registry).await?;
+    assert_eq!(
+        statistics_of(&statistics, AppchainState::Registered).appchain_count,
+        3
+    );
+    assert_eq!(statistics.total_register_deposit.0, register_deposit * 3);
+    assert_eq!(statistics.distinct_owner_count.0, 2);
+    //
+    // The statistics follow the state changes of appchains
+    //
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id1).await?;
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id1)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_owner_actions::withdraw_appchain(&users[1], &registry, &appchain_id3, None)
+            .await?,
+    );
+    let statistics = registry_viewer::get_registry_statistics(&registry).await?;
+    assert_eq!(
+        statistics_of(&statistics, AppchainState::Registered).appchain_count,
+        1
+    );
+    assert_eq!(
+        statistics_of(&statistics, AppchainState::Audited).appchain_count,
+        1
+    );
+    assert_eq!(
+        statistics_of(&statistics, AppchainState::Closed).appchain_count,
+        1
+    );
+    //
+    // The distinct owners follow the ownership transfers and removals
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::transfer_appchain_ownership(
+            &users[0],
+            &registry,
+            &appchain_id2,
+            &users[2],
+        )
+        .await?,
+    );
+    assert_eq!(
+        registry_viewer::get_registry_statistics(&registry)
+            .await?
+            .distinct_owner_count
+            .0,
+        3
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id3).await?,
+    );
+    let statistics = registry_viewer::get_registry_statistics(&registry).await?;
+    assert_eq!(
+        statistics_of(&statistics, AppchainState::Closed).appchain_count,
+        0
+    );
+    assert_eq!(statistics.distinct_owner_count.0, 2);
+    // The register deposits collected are not reduced by removals.
+    assert_eq!(statistics.total_register_deposit.0, register_deposit * 3);
+    Ok(())
+}
+
+fn statistics_of(
+    statistics: &RegistryStatistics,
+    appchain_state: AppchainState,
+) -> AppchainStateStatistics {
+    statistics
+        .appchain_states
+        .iter()
+        .find(|state_statistics| state_statistics.appchain_state == appchain_state)
+        .unwrap()
+        .clone()
+}
@@ -0,0 +1,77 @@
+use crate::{common, contract_interfaces::registry_viewer};
+use appchain_registry::types::{AppchainState, VersionedAppchainAnchor, VersionedAppchainOwner};
+use near_sdk::{serde_json::json, AccountId};
+use std::str::FromStr;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the queries for other contracts.
+#[tokio::test]
+async fn test_case24() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (_root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    //
+    // The state of an appchain can be checked without loading its status
+    //
+    assert!(
+        registry_viewer::is_appchain_in_state(&registry, &appchain_id, AppchainState::Registered)
+            .await?
+    );
+    assert!(
+        !registry_viewer::is_appchain_in_state(&registry, &appchain_id, AppchainState::Active)
+            .await?
+    );
+    assert!(
+        !registry_viewer::is_appchain_in_state(&registry, "unknown", AppchainState::Registered)
+            .await?
+    );
+    //
+    // The owner and the anchor are returned in versioned types
+    //
+    assert_eq!(
+        registry_viewer::get_appchain_owner(&registry, &appchain_id).await?,
+        Some(VersionedAppchainOwner::V1 {
+            appchain_id: appchain_id.clone(),
+            owner: AccountId::from_str(users[0].id().as_str()).unwrap(),
+        })
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_anchor(&registry, &appchain_id).await?,
+        Some(VersionedAppchainAnchor::V1 {
+            appchain_id: appchain_id.clone(),
+            appchain_state: AppchainState::Registered,
+            anchor: None,
+        })
+    );
+    assert!(registry_viewer::get_appchain_owner(&registry, "unknown")
+        .await?
+        .is_none());
+    assert!(registry_viewer::get_appchain_anchor(&registry, "unknown")
+        .await?
+        .is_none());
+    //
+    // The version is in the serialized result
+    //
+    let owner = registry
+        .call("get_appchain_owner")
+        .args_json(json!({ "appchain_id": appchain_id }))
+        .view()
+        .await?
+        .json::<near_sdk::serde_json::Value>()?;
+    assert_eq!(
+        owner,
+        json!({
+            "version": "V1",
+            "appchain_id": "appchain1",
+            "owner": users[0].id().as_str(),
+        })
+    );
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
+};
+use appchain_registry::types::{AppchainMetadataPatch, FieldPatch};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test that the actions of the appchain lifecycle manager do NOT depend on
+/// the storage balance of the appchain owner.
+#[tokio::test]
+async fn test_case25() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    //
+    // The owner withdraws all of the available storage balance
+    //
+    common::assert_no_failure(
+        &appchain_owner_actions::storage_withdraw(&users[0], &registry, Option::None).await?,
+    );
+    let owner_balance = registry_viewer::storage_balance_of(&registry, &users[0])
+        .await?
+        .unwrap();
+    assert_eq!(owner_balance.available.0, 0);
+    let storage_balance =
+        registry_viewer::get_appchain_storage_balance_of(&registry, &appchain_id).await?;
+    //
+    // The actions of the lifecycle manager which increase storage still succeed
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::update_appchain_metadata(
+            &root,
+            &registry,
+            &appchain_id,
+            &AppchainMetadataPatch {
+                website_url: FieldPatch::Set(String::from(
+                    "https://a-much-longer-website-url-of-the-appchain.oct.network",
+                )),
+                ..Default::default()
+            },
+        )
+        .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::set_appchain_tags(
+            &root,
+            &registry,
+            &appchain_id,
+            vec!["defi", "gaming"],
+        )
+        .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(
+            &root,
+            &registry,
+            &appchain_id,
+            "The appchain is a duplicate of an existing one.",
+        )
+        .await?,
+    );
+    //
+    // The increased storage is NOT charged to the owner
+    //
+    let owner_balance_after_actions = registry_viewer::storage_balance_of(&registry, &users[0])
+        .await?
+        .unwrap();
+    assert_eq!(owner_balance_after_actions.total, owner_balance.total);
+    assert_eq!(
+        owner_balance_after_actions.available,
+        owner_balance.available
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_storage_balance_of(&registry, &appchain_id)
+            .await?
+            .storage_used,
+        storage_balance.storage_used
+    );
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::{
+    common,
+    contract_interfaces::{registry_settings, registry_viewer},
+};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the deprecated function for changing the register deposit of the built-in templates.
+#[tokio::test]
+async fn test_case26() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, _oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    common::assert_error_code(
+        &registry_settings::change_minimum_register_deposit(
+            &users[0],
+            &registry,
+            common::to_oct_amount(2000),
+        )
+        .await?,
+        "CALLER_IS_NOT_REGISTRY_SETTINGS_MANAGER",
+    );
+    common::assert_error_code(
+        &registry_settings::change_minimum_register_deposit(&root, &registry, 0).await?,
+        "INVALID_ARGUMENT",
+    );
+    common::assert_no_failure(
+        &registry_settings::change_minimum_register_deposit(
+            &root,
+            &registry,
+            common::to_oct_amount(2000),
+        )
+        .await?,
+    );
+    for template_type in ["Barnacle", "BarnacleEvm"] {
+        let template =
+            registry_viewer::get_appchain_template_of(&registry, &template_type.to_string())
+                .await?
+                .unwrap();
+        assert_eq!(template.register_deposit.0, common::to_oct_amount(2000));
+    }
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_owner_actions, registry_settings, registry_viewer},
+};
+use appchain_registry::types::RegisterDepositVoucherHolder;
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
+use near_sdk::{json_types::U128, serde_json::json};
+use std::collections::HashMap;
+use workspaces::{result::ExecutionFinalResult, Account, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+const VOUCHER_SECRET: &str = "open-sesame";
+const VOUCHER_SECRET_HASH: &str =
+    "d7ecdf25eaf3deba0f2628771dbdd22d4138ab6cf38f91ed02a2ca0dec7c8ab7";
+
+/// Test that a voucher bound to a secret hash can only be redeemed by revealing
+/// a registration commitment which is bound to the secret.
+#[tokio::test]
+async fn test_case27() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    common::assert_no_failure(
+        &registry_settings::issue_register_deposit_voucher(
+            &root,
+            &registry,
+            "voucher1",
+            RegisterDepositVoucherHolder::SecretHash(VOUCHER_SECRET_HASH.to_string()),
+            100,
+            1,
+        )
+        .await?,
+    );
+    //
+    // The secret is NOT accepted in a registration without commitment
+    //
+    common::assert_error_code(
+        &register_appchain_with_voucher_secret(&users[0], &oct_token, &registry, "appchain1", None)
+            .await?,
+        "REGISTRATION_COMMITMENT_REQUIRED",
+    );
+    //
+    // The secret is NOT accepted in revealing a commitment which is not bound to it,
+    // so a front-runner can NOT redeem the voucher with a commitment made in advance
+    //
+    appchain_owner_actions::commit_appchain_registration(
+        &users[1],
+        &oct_token,
+        &registry,
+        "appchain2",
+    )
+    .await?;
+    common::assert_error_code(
+        &register_appchain_with_voucher_secret(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain2",
+            Some(appchain_owner_actions::REGISTRATION_SALT),
+        )
+        .await?,
+        "REGISTRATION_COMMITMENT_NOT_FOUND",
+    );
+    //
+    // The secret is accepted in revealing a commitment which is bound to it
+    //
+    appchain_owner_actions::commit_appchain_registration_with_voucher_secret(
+        &users[0],
+        &oct_token,
+        &registry,
+        "appchain1",
+        Some(VOUCHER_SECRET),
+    )
+    .await?;
+    common::assert_no_failure(
+        &register_appchain_with_voucher_secret(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            Some(appchain_owner_actions::REGISTRATION_SALT),
+        )
+        .await?,
+    );
+    let appchain =
+        registry_viewer::get_appchain_status_of(&registry, &"appchain1".to_string()).await?;
+    assert_eq!(appchain.register_deposit.0, 0);
+    Ok(())
+}
+
+async fn register_appchain_with_voucher_secret(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+    registration_salt: Option<&str>,
+) -> anyhow::Result<ExecutionFinalResult> {
+    let result = common::call_ft_transfer_call(
+        signer,
+        &registry.as_account(),
+        common::to_oct_amount(1000),
+        json!({
+            "RegisterAppchain":{
+                "appchain_id": appchain_id,
+                "description": "appchain description",
+                "template_type": "Barnacle",
+                "website_url": "http://ddfs.dsdfs",
+                "github_address": "https://github.com/octopus-network/barnacle",
+                "contact_email": "joe@lksdf.com",
+                "premined_wrapped_appchain_token_beneficiary": signer.id(),
+                "premined_wrapped_appchain_token": U128::from(10000000),
+                "initial_supply_of_wrapped_appchain_token": U128::from(100000000),
+                "ido_amount_of_wrapped_appchain_token": U128::from(1000000),
+                "initial_era_reward": U128::from(100),
+                "fungible_token_metadata": FungibleTokenMetadata {
+                    spec: FT_METADATA_SPEC.to_string(),
+                    name: "joeToken".to_string(),
+                    symbol: "JOT".to_string(),
+                    icon: Option::None,
+                    reference: Option::None,
+                    reference_hash: Option::None,
+                    decimals: 18,
+                },
+                "custom_metadata": HashMap::from([("key1".to_string(), "value1".to_string())]),
+                "voucher_id": "voucher1",
+                "voucher_secret": VOUCHER_SECRET,
+                "registration_salt": registration_salt
+            }
+        })
+        .to_string(),
+        oct_token,
+    )
+    .await?;
+    Ok(result)
+}
@@ -0,0 +1,57 @@
+use crate::{
+    common,
+    contract_interfaces::{registry_settings, registry_viewer},
+};
+use near_units::parse_near;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the initial balance of anchor accounts and the gas for cross-contract calls.
+#[tokio::test]
+async fn test_case28() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, _oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    //
+    // Initial balance of anchor account of an appchain template
+    //
+    common::assert_no_failure(
+        &registry_settings::change_appchain_anchor_init_balance(
+            &root,
+            &registry,
+            "BarnacleEvm".to_string(),
+            parse_near!("30 N"),
+        )
+        .await?,
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_template_of(&registry, &"BarnacleEvm".to_string())
+            .await?
+            .unwrap()
+            .anchor_init_balance
+            .0,
+        parse_near!("30 N")
+    );
+    //
+    // Gas for calling `ft_transfer` of OCT token
+    //
+    common::assert_error_code(
+        &registry_settings::change_t_gas_for_ft_transfer(&users[0], &registry, 30).await?,
+        "CALLER_IS_NOT_REGISTRY_SETTINGS_MANAGER",
+    );
+    common::assert_error_code(
+        &registry_settings::change_t_gas_for_ft_transfer(&root, &registry, 1).await?,
+        "INVALID_ARGUMENT",
+    );
+    common::assert_no_failure(
+        &registry_settings::change_t_gas_for_ft_transfer(&root, &registry, 30).await?,
+    );
+    assert_eq!(
+        registry_viewer::get_registry_settings(&registry)
+            .await?
+            .t_gas_for_ft_transfer,
+        30
+    );
+    Ok(())
+}
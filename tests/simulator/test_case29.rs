@@ -0,0 +1,79 @@
+use crate::{
+    common,
+    contract_interfaces::{registry_settings, registry_viewer},
+};
+use near_sdk::json_types::Base58CryptoHash;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the register deposit and the anchor wasm hash of appchain templates.
+#[tokio::test]
+async fn test_case29() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, _oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    //
+    // Register deposit of an appchain template
+    //
+    let template_type = "Barnacle".to_string();
+    let amount = common::to_oct_amount(1200);
+    common::assert_error_code(
+        &registry_settings::change_appchain_template_register_deposit(
+            &users[0],
+            &registry,
+            template_type.clone(),
+            amount,
+        )
+        .await?,
+        "CALLER_IS_NOT_REGISTRY_SETTINGS_MANAGER",
+    );
+    common::assert_no_failure(
+        &registry_settings::change_appchain_template_register_deposit(
+            &root,
+            &registry,
+            template_type.clone(),
+            amount,
+        )
+        .await?,
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_template_of(&registry, &template_type)
+            .await?
+            .unwrap()
+            .register_deposit
+            .0,
+        common::to_oct_amount(1200)
+    );
+    //
+    // Anchor wasm hash of an appchain template
+    //
+    let anchor_wasm_hash = Base58CryptoHash::from([1u8; 32]);
+    common::assert_error_code(
+        &registry_settings::change_appchain_template_anchor_wasm_hash(
+            &users[0],
+            &registry,
+            template_type.clone(),
+            Some(anchor_wasm_hash),
+        )
+        .await?,
+        "CALLER_IS_NOT_REGISTRY_SETTINGS_MANAGER",
+    );
+    common::assert_no_failure(
+        &registry_settings::change_appchain_template_anchor_wasm_hash(
+            &root,
+            &registry,
+            template_type.clone(),
+            Some(anchor_wasm_hash),
+        )
+        .await?,
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_template_of(&registry, &template_type)
+            .await?
+            .unwrap()
+            .anchor_wasm_hash,
+        Some(anchor_wasm_hash)
+    );
+    Ok(())
+}
@@ -2,9 +2,7 @@ use crate::{
     common,
     contract_interfaces::{appchain_lifecycle_manager, appchain_owner_actions, registry_viewer},
 };
-use appchain_registry::types::{
-    AppchainSortingField, AppchainState, AppchainTemplateType, SortingOrder,
-};
+use appchain_registry::types::{AppchainSortingField, AppchainState, SortingOrder};
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
 use near_sdk::{json_types::U128, AccountId};
 use std::{collections::HashMap, str::FromStr};
@@ -17,6 +15,7 @@ async fn test_case3() -> anyhow::Result<()> {
     let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
     let (root, oct_token, registry, _council, users) =
         common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
     //
     let mut i = 1;
     while i <= 50 {
@@ -28,13 +27,13 @@ async fn test_case3() -> anyhow::Result<()> {
             &registry,
             &appchain_id,
             Some("appchain1 description".to_string()),
-            Some(AppchainTemplateType::Barnacle),
+            Some("Barnacle".to_string()),
             Some("http://ddfs.dsdfs".to_string()),
-            Some("https://jldfs.yoasdfasd".to_string()),
+            Some("https://github.com/octopus-network/barnacle".to_string()),
             Some("joe@lksdf.com".to_string()),
             Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
             Some(U128::from(10000000)),
-            Some(U128::from(10000000)),
+            Some(U128::from(100000000)),
             Some(U128::from(1000000)),
             Some(U128::from(100)),
             Some(FungibleTokenMetadata {
@@ -55,6 +54,7 @@ async fn test_case3() -> anyhow::Result<()> {
         let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
         assert_eq!(&appchain.appchain_state, &AppchainState::Registered);
         //
+        common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id).await?;
         assert!(
             appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id)
                 .await
@@ -78,7 +78,7 @@ async fn test_case3() -> anyhow::Result<()> {
         registry_viewer::print_appchains(
             &registry,
             Option::None,
-            1,
+            0,
             50,
             AppchainSortingField::AppchainId,
             SortingOrder::Descending
@@ -91,7 +91,7 @@ async fn test_case3() -> anyhow::Result<()> {
         registry_viewer::print_appchains(
             &registry,
             Option::Some([AppchainState::Voting, AppchainState::Booting].to_vec()),
-            1,
+            0,
             50,
             AppchainSortingField::RegisteredTime,
             SortingOrder::Ascending
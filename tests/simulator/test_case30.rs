@@ -0,0 +1,54 @@
+use crate::{common, contract_interfaces::registry_viewer};
+use appchain_registry::types::AppchainState;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the index of anchor accounts and the authentication of appchain anchors.
+#[tokio::test]
+async fn test_case30() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    let anchor = common::basic_actions::boot_appchain(
+        &worker,
+        &root,
+        &council,
+        &auditor,
+        &registry,
+        &appchain_id,
+    )
+    .await?;
+    //
+    // The anchor account is indexed when the appchain starts booting
+    //
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(
+        appchain.appchain_anchor.map(|anchor| anchor.to_string()),
+        Some(anchor.id().to_string())
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_id_of_anchor(&registry, &anchor.id().to_string()).await?,
+        Some(appchain_id.clone())
+    );
+    //
+    // The anchor account is authenticated by the index of anchors
+    //
+    common::assert_error_code(
+        &common::basic_actions::sync_state_of(&users[0], &registry, &appchain_id).await?,
+        "CALLER_IS_NOT_APPCHAIN_ANCHOR",
+    );
+    common::assert_no_failure(
+        &common::basic_actions::sync_state_of(&anchor, &registry, &appchain_id).await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(&appchain.appchain_state, &AppchainState::Active);
+    assert_eq!(appchain.validator_count, 2);
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::{common, contract_interfaces::registry_viewer};
+use appchain_registry::types::AppchainSyncMetrics;
+use near_sdk::json_types::{U128, U64};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the metrics synced by appchain anchors.
+#[tokio::test]
+async fn test_case31() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    let anchor = common::basic_actions::boot_appchain(
+        &worker,
+        &root,
+        &council,
+        &auditor,
+        &registry,
+        &appchain_id,
+    )
+    .await?;
+    common::assert_no_failure(
+        &common::basic_actions::sync_state_of(&anchor, &registry, &appchain_id).await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert!(appchain.sync_metrics.is_none());
+    //
+    // The metrics are only synced by the anchor
+    //
+    let metrics = AppchainSyncMetrics {
+        era_number: U64::from(10),
+        finalized_block_height: U64::from(14400),
+        total_rewards_distributed: U128::from(5000),
+        wrapped_token_supply: U128::from(100000000),
+        anchor_version: "v2.5.0".to_string(),
+    };
+    common::assert_error_code(
+        &common::basic_actions::sync_state_and_metrics_of(
+            &users[0],
+            &registry,
+            &appchain_id,
+            &metrics,
+        )
+        .await?,
+        "CALLER_IS_NOT_APPCHAIN_ANCHOR",
+    );
+    common::assert_no_failure(
+        &common::basic_actions::sync_state_and_metrics_of(
+            &anchor,
+            &registry,
+            &appchain_id,
+            &metrics,
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.sync_metrics, Some(metrics.clone()));
+    //
+    // The metrics synced by anchor can NOT move backward
+    //
+    let backward_metrics = AppchainSyncMetrics {
+        era_number: U64::from(9),
+        wrapped_token_supply: U128::from(90000000),
+        ..metrics.clone()
+    };
+    common::assert_error_code(
+        &common::basic_actions::sync_state_and_metrics_of(
+            &anchor,
+            &registry,
+            &appchain_id,
+            &backward_metrics,
+        )
+        .await?,
+        "SYNC_METRIC_MOVED_BACKWARD",
+    );
+    //
+    // The supply of wrapped appchain token can decrease
+    //
+    let decreased_supply_metrics = AppchainSyncMetrics {
+        era_number: U64::from(11),
+        wrapped_token_supply: U128::from(90000000),
+        ..metrics
+    };
+    common::assert_no_failure(
+        &common::basic_actions::sync_state_and_metrics_of(
+            &anchor,
+            &registry,
+            &appchain_id,
+            &decreased_supply_metrics,
+        )
+        .await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.sync_metrics, Some(decreased_supply_metrics));
+    Ok(())
+}
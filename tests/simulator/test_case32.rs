@@ -0,0 +1,78 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_owner_actions, registry_settings, registry_viewer},
+};
+use appchain_registry::types::{AppchainChangeKind, AppchainHealth};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the liveness of appchain anchors and the flagging of stale appchains.
+#[tokio::test]
+async fn test_case32() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    let anchor = common::basic_actions::boot_appchain(
+        &worker,
+        &root,
+        &council,
+        &auditor,
+        &registry,
+        &appchain_id,
+    )
+    .await?;
+    common::assert_no_failure(
+        &common::basic_actions::sync_state_of(&anchor, &registry, &appchain_id).await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert!(appchain.last_sync_time.0 > 0);
+    assert_eq!(appchain.health, AppchainHealth::Healthy);
+    //
+    // The appchain whose anchor has not synced within the threshold can be flagged as stale
+    //
+    assert!(registry_viewer::get_stale_appchains(&registry)
+        .await?
+        .items
+        .is_empty());
+    common::assert_error_code(
+        &appchain_owner_actions::flag_stale_appchain(&users[1], &registry, &appchain_id).await?,
+        "APPCHAIN_ANCHOR_NOT_STALE",
+    );
+    common::assert_no_failure(
+        &registry_settings::change_anchor_staleness_threshold_in_secs(&root, &registry, 1).await?,
+    );
+    worker.fast_forward(1000).await?;
+    let stale_appchains = registry_viewer::get_stale_appchains(&registry).await?.items;
+    assert_eq!(stale_appchains.len(), 1);
+    assert_eq!(stale_appchains[0].appchain_id, appchain_id);
+    assert_eq!(stale_appchains[0].health, AppchainHealth::Healthy);
+    common::assert_no_failure(
+        &appchain_owner_actions::flag_stale_appchain(&users[1], &registry, &appchain_id).await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.health, AppchainHealth::Stale);
+    common::assert_error_code(
+        &appchain_owner_actions::flag_stale_appchain(&users[1], &registry, &appchain_id).await?,
+        "APPCHAIN_ALREADY_STALE",
+    );
+    //
+    // The stale flag is cleared by the next sync of anchor
+    //
+    common::assert_no_failure(
+        &common::basic_actions::sync_state_of(&anchor, &registry, &appchain_id).await?,
+    );
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(appchain.health, AppchainHealth::Healthy);
+    let feed = registry_viewer::get_changes_since(&registry, 0, None).await?;
+    let change = feed.changes.last().unwrap();
+    assert_eq!(change.appchain_id, appchain_id);
+    assert_eq!(change.kind, AppchainChangeKind::HealthChanged);
+    Ok(())
+}
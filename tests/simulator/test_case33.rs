@@ -0,0 +1,61 @@
+use crate::{
+    common,
+    contract_interfaces::{
+        appchain_lifecycle_manager, appchain_owner_actions, registry_settings, registry_viewer,
+    },
+};
+use appchain_registry::types::AppchainReviewAction;
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the removal of rejected appchains, which waits for the appeal window.
+#[tokio::test]
+async fn test_case33() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let appchain_id = "appchain1".to_string();
+    common::assert_no_failure(
+        &common::basic_actions::register_appchain(&users[0], &oct_token, &registry, &appchain_id)
+            .await?,
+    );
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::reject_appchain(&root, &registry, &appchain_id, "Rejected.")
+            .await?,
+    );
+    //
+    // A rejected appchain can NOT be removed within the appeal window
+    //
+    common::assert_error_code(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id).await?,
+        "APPEAL_WINDOW_NOT_EXPIRED",
+    );
+    common::assert_no_failure(
+        &registry_settings::change_appeal_window_in_secs(&root, &registry, 1).await?,
+    );
+    worker.fast_forward(1000).await?;
+    common::assert_error_code(
+        &appchain_owner_actions::appeal_appchain_rejection(
+            &users[0],
+            &registry,
+            &appchain_id,
+            "Please reconsider.",
+        )
+        .await?,
+        "APPEAL_WINDOW_EXPIRED",
+    );
+    //
+    // The rejected appchain can be removed after the appeal window
+    //
+    common::assert_no_failure(
+        &appchain_lifecycle_manager::remove_appchain(&root, &registry, &appchain_id).await?,
+    );
+    let trail = registry_viewer::get_appchain_review_trail(&registry, &appchain_id, None, None)
+        .await?
+        .items;
+    assert_eq!(trail.len(), 2);
+    assert_eq!(trail[0].action, AppchainReviewAction::Rejected);
+    assert_eq!(trail[1].action, AppchainReviewAction::Removed);
+    Ok(())
+}
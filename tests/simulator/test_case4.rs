@@ -5,9 +5,7 @@ use crate::{
     },
 };
 use appchain_anchor::types::ProtocolSettings;
-use appchain_registry::types::{
-    AppchainSortingField, AppchainState, AppchainTemplateType, SortingOrder,
-};
+use appchain_registry::types::{AppchainSortingField, AppchainState, SortingOrder};
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
 use near_sdk::{json_types::U128, serde_json::json, AccountId};
 use std::{collections::HashMap, str::FromStr};
@@ -21,6 +19,7 @@ async fn test_case4() -> anyhow::Result<()> {
     let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
     let (root, oct_token, registry, council, users) =
         common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    let auditor = common::basic_actions::create_auditor(&root, &registry, "auditor").await?;
     //
     let appchain_id = String::from("appchain1");
     let amount = common::to_oct_amount(1000);
@@ -30,13 +29,13 @@ async fn test_case4() -> anyhow::Result<()> {
         &registry,
         &appchain_id,
         Some("appchain1 description".to_string()),
-        Some(AppchainTemplateType::Barnacle),
+        Some("Barnacle".to_string()),
         Some("http://ddfs.dsdfs".to_string()),
-        Some("https://jldfs.yoasdfasd".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
         Some("joe@lksdf.com".to_string()),
         Some(AccountId::from_str(users[1].id().as_str()).unwrap()),
         Some(U128::from(10000000)),
-        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
         Some(U128::from(1000000)),
         Some(U128::from(100)),
         Some(FungibleTokenMetadata {
@@ -58,7 +57,7 @@ async fn test_case4() -> anyhow::Result<()> {
         registry_viewer::print_appchains(
             &registry,
             Option::None,
-            1,
+            0,
             5,
             AppchainSortingField::AppchainId,
             SortingOrder::Ascending
@@ -66,6 +65,7 @@ async fn test_case4() -> anyhow::Result<()> {
         .await?,
         1
     );
+    common::basic_actions::approve_appchain_audit(&auditor, &registry, &appchain_id).await?;
     assert!(
         appchain_lifecycle_manager::pass_auditing_appchain(&root, &registry, &appchain_id)
             .await
@@ -109,7 +109,7 @@ async fn test_case4() -> anyhow::Result<()> {
         .call("new")
         .args_json(json!({
             "appchain_id": "appchain1",
-            "appchain_template_type": AppchainTemplateType::Barnacle,
+            "appchain_template_type": "Barnacle",
             "appchain_registry": registry.id(),
             "oct_token": oct_token.id(),
         }))
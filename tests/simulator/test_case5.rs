@@ -0,0 +1,217 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_owner_actions, registry_settings, registry_viewer},
+};
+use appchain_registry::types::RegisterDepositVoucherHolder;
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
+use near_sdk::{json_types::U128, serde_json::json, AccountId};
+use std::{collections::HashMap, str::FromStr};
+use workspaces::{Account, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test register deposit refunding and register deposit vouchers.
+#[tokio::test]
+async fn test_case5() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    //
+    // Overpaid register deposit is refunded
+    //
+    assert!(
+        register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            common::to_oct_amount(1500),
+            None,
+            None,
+        )
+        .await?
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[0], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10 - 1000)
+    );
+    let appchain =
+        registry_viewer::get_appchain_status_of(&registry, &"appchain1".to_string()).await?;
+    assert_eq!(appchain.register_deposit.0, common::to_oct_amount(1000));
+    //
+    // Voucher bound to an account
+    //
+    common::assert_error_code(
+        &registry_settings::issue_register_deposit_voucher(
+            &users[0],
+            &registry,
+            "voucher1",
+            RegisterDepositVoucherHolder::Account(
+                AccountId::from_str(users[1].id().as_str()).unwrap(),
+            ),
+            50,
+            1,
+        )
+        .await?,
+        "CALLER_IS_NOT_REGISTRY_SETTINGS_MANAGER",
+    );
+    assert!(registry_settings::issue_register_deposit_voucher(
+        &root,
+        &registry,
+        "voucher1",
+        RegisterDepositVoucherHolder::Account(AccountId::from_str(users[1].id().as_str()).unwrap()),
+        50,
+        1,
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        register_appchain(
+            &users[2],
+            &oct_token,
+            &registry,
+            "appchain2",
+            common::to_oct_amount(1000),
+            Some("voucher1"),
+            None,
+        )
+        .await?
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[2], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10 - 10)
+    );
+    assert!(
+        register_appchain(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain2",
+            common::to_oct_amount(1000),
+            Some("voucher1"),
+            None,
+        )
+        .await?
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10 - 500)
+    );
+    assert!(
+        register_appchain(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain3",
+            common::to_oct_amount(1000),
+            Some("voucher1"),
+            None,
+        )
+        .await?
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10 - 510)
+    );
+    //
+    // Voucher bound to a secret hash, with a full waiver,
+    // the commitment deposit is also refunded
+    //
+    assert!(registry_settings::issue_register_deposit_voucher(
+        &root,
+        &registry,
+        "voucher2",
+        RegisterDepositVoucherHolder::SecretHash(
+            "d7ecdf25eaf3deba0f2628771dbdd22d4138ab6cf38f91ed02a2ca0dec7c8ab7".to_string()
+        ),
+        100,
+        2,
+    )
+    .await
+    .unwrap()
+    .is_success());
+    assert!(
+        register_appchain(
+            &users[3],
+            &oct_token,
+            &registry,
+            "appchain4",
+            common::to_oct_amount(1000),
+            Some("voucher2"),
+            Some("open-sesame"),
+        )
+        .await?
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[3], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10)
+    );
+    let appchain =
+        registry_viewer::get_appchain_status_of(&registry, &"appchain4".to_string()).await?;
+    assert_eq!(appchain.register_deposit.0, 0);
+    assert_eq!(
+        common::get_ft_balance_of(&registry.as_account(), &oct_token)
+            .await?
+            .0,
+        common::to_oct_amount(1520)
+    );
+    Ok(())
+}
+
+async fn register_appchain(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+    amount: u128,
+    voucher_id: Option<&str>,
+    voucher_secret: Option<&str>,
+) -> anyhow::Result<bool> {
+    appchain_owner_actions::commit_appchain_registration_with_voucher_secret(
+        signer,
+        oct_token,
+        registry,
+        appchain_id,
+        voucher_secret,
+    )
+    .await?;
+    let result = common::call_ft_transfer_call(
+        signer,
+        &registry.as_account(),
+        amount,
+        json!({
+            "RegisterAppchain":{
+                "appchain_id": appchain_id,
+                "description": "appchain description",
+                "template_type": "Barnacle",
+                "website_url": "http://ddfs.dsdfs",
+                "github_address": "https://github.com/octopus-network/barnacle",
+                "contact_email": "joe@lksdf.com",
+                "premined_wrapped_appchain_token_beneficiary": signer.id(),
+                "premined_wrapped_appchain_token": U128::from(10000000),
+                "initial_supply_of_wrapped_appchain_token": U128::from(100000000),
+                "ido_amount_of_wrapped_appchain_token": U128::from(1000000),
+                "initial_era_reward": U128::from(100),
+                "fungible_token_metadata": FungibleTokenMetadata {
+                    spec: FT_METADATA_SPEC.to_string(),
+                    name: "joeToken".to_string(),
+                    symbol: "JOT".to_string(),
+                    icon: Option::None,
+                    reference: Option::None,
+                    reference_hash: Option::None,
+                    decimals: 18,
+                },
+                "custom_metadata": HashMap::from([("key1".to_string(), "value1".to_string())]),
+                "voucher_id": voucher_id,
+                "voucher_secret": voucher_secret,
+                "registration_salt": appchain_owner_actions::REGISTRATION_SALT
+            }
+        })
+        .to_string(),
+        oct_token,
+    )
+    .await?;
+    Ok(result.failures().is_empty())
+}
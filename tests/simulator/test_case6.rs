@@ -0,0 +1,163 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_owner_actions, registry_viewer},
+};
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
+use near_sdk::{
+    json_types::{U128, U64},
+    serde_json::json,
+};
+use std::collections::HashMap;
+use workspaces::{result::ExecutionFinalResult, Account, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the uniqueness of EVM chain id of appchains.
+#[tokio::test]
+async fn test_case6() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (_root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    //
+    // `BarnacleEvm` appchains must have an EVM chain id,
+    // and other appchains must not have one.
+    //
+    common::assert_error_code(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            "BarnacleEvm",
+            None,
+        )
+        .await?,
+        "MISSING_EVM_CHAIN_ID",
+    );
+    common::assert_error_code(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            "Barnacle",
+            Some(9000),
+        )
+        .await?,
+        "EVM_CHAIN_ID_NOT_SUPPORTED",
+    );
+    //
+    // Reserved EVM chain ids can not be used
+    //
+    common::assert_error_code(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            "BarnacleEvm",
+            Some(1),
+        )
+        .await?,
+        "EVM_CHAIN_ID_RESERVED",
+    );
+    common::assert_no_failure(
+        &register_appchain(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            "BarnacleEvm",
+            Some(9000),
+        )
+        .await?,
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_id_of_evm_chain_id(&registry, 9000).await?,
+        Some("appchain1".to_string())
+    );
+    //
+    // Duplicated EVM chain ids are rejected
+    //
+    common::assert_error_code(
+        &register_appchain(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain2",
+            "BarnacleEvm",
+            Some(9000),
+        )
+        .await?,
+        "EVM_CHAIN_ID_ALREADY_USED",
+    );
+    common::assert_no_failure(
+        &register_appchain(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain2",
+            "BarnacleEvm",
+            Some(9001),
+        )
+        .await?,
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_id_of_evm_chain_id(&registry, 9001).await?,
+        Some("appchain2".to_string())
+    );
+    assert_eq!(
+        registry_viewer::get_appchain_id_of_evm_chain_id(&registry, 9002).await?,
+        None
+    );
+    Ok(())
+}
+
+async fn register_appchain(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+    template_type: &str,
+    evm_chain_id: Option<u64>,
+) -> anyhow::Result<ExecutionFinalResult> {
+    appchain_owner_actions::commit_appchain_registration(signer, oct_token, registry, appchain_id)
+        .await?;
+    let result = common::call_ft_transfer_call(
+        signer,
+        &registry.as_account(),
+        common::to_oct_amount(1000),
+        json!({
+            "RegisterAppchain":{
+                "appchain_id": appchain_id,
+                "description": "appchain description",
+                "template_type": template_type,
+                "evm_chain_id": evm_chain_id.map(U64::from),
+                "website_url": "http://ddfs.dsdfs",
+                "github_address": "https://github.com/octopus-network/barnacle",
+                "contact_email": "joe@lksdf.com",
+                "premined_wrapped_appchain_token_beneficiary": signer.id(),
+                "premined_wrapped_appchain_token": U128::from(10000000),
+                "initial_supply_of_wrapped_appchain_token": U128::from(100000000),
+                "ido_amount_of_wrapped_appchain_token": U128::from(1000000),
+                "initial_era_reward": U128::from(100),
+                "fungible_token_metadata": FungibleTokenMetadata {
+                    spec: FT_METADATA_SPEC.to_string(),
+                    name: "joeToken".to_string(),
+                    symbol: "JOT".to_string(),
+                    icon: Option::None,
+                    reference: Option::None,
+                    reference_hash: Option::None,
+                    decimals: 18,
+                },
+                "custom_metadata": HashMap::from([("key1".to_string(), "value1".to_string())]),
+                "registration_salt": appchain_owner_actions::REGISTRATION_SALT
+            }
+        })
+        .to_string(),
+        oct_token,
+    )
+    .await?;
+    Ok(result)
+}
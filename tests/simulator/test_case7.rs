@@ -0,0 +1,163 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_owner_actions, registry_settings, registry_viewer},
+};
+use appchain_registry::types::AppchainIdAvailability;
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
+use near_sdk::{json_types::U128, AccountId};
+use std::{collections::HashMap, str::FromStr};
+use workspaces::{result::ExecutionFinalResult, Account, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the naming rules, reserved ids and deny patterns of appchain id.
+#[tokio::test]
+async fn test_case7() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    //
+    // Naming rules
+    //
+    for appchain_id in [
+        "My-Chain",
+        "1chain",
+        "chain-",
+        "my--chain",
+        "ab",
+        "my.chain",
+    ] {
+        assert!(matches!(
+            registry_viewer::check_appchain_id_availability(&registry, appchain_id, None).await?,
+            AppchainIdAvailability::Invalid { .. }
+        ));
+    }
+    common::assert_error_code(
+        &register_appchain(&users[0], &oct_token, &registry, "My-Chain").await?,
+        "APPCHAIN_ID_NOT_AVAILABLE",
+    );
+    //
+    // Default reserved ids, matched after normalization
+    //
+    assert_eq!(
+        registry_viewer::check_appchain_id_availability(&registry, "octo-pus", None).await?,
+        AppchainIdAvailability::Reserved {
+            reserved_id: "octopus".to_string(),
+            assigned_to: None
+        }
+    );
+    common::assert_error_code(
+        &register_appchain(&users[0], &oct_token, &registry, "octo_pus").await?,
+        "APPCHAIN_ID_NOT_AVAILABLE",
+    );
+    //
+    // Reserved id assigned to an account
+    //
+    let user1_id = AccountId::from_str(users[1].id().as_str()).unwrap();
+    common::assert_error_code(
+        &registry_settings::reserve_appchain_id(&users[0], &registry, "mychain", None).await?,
+        "CALLER_IS_NOT_REGISTRY_SETTINGS_MANAGER",
+    );
+    assert!(registry_settings::reserve_appchain_id(
+        &root,
+        &registry,
+        "mychain",
+        Some(user1_id.clone())
+    )
+    .await
+    .unwrap()
+    .is_success());
+    common::assert_error_code(
+        &register_appchain(&users[0], &oct_token, &registry, "my-chain").await?,
+        "APPCHAIN_ID_NOT_AVAILABLE",
+    );
+    assert_eq!(
+        registry_viewer::check_appchain_id_availability(
+            &registry,
+            "my-chain",
+            Some(users[1].id().as_str())
+        )
+        .await?,
+        AppchainIdAvailability::Available
+    );
+    common::assert_no_failure(
+        &register_appchain(&users[1], &oct_token, &registry, "my-chain").await?,
+    );
+    assert_eq!(
+        registry_viewer::check_appchain_id_availability(&registry, "my-chain", None).await?,
+        AppchainIdAvailability::Registered
+    );
+    //
+    // Registered ids, matched after normalization
+    //
+    assert_eq!(
+        registry_viewer::check_appchain_id_availability(&registry, "mychain", None).await?,
+        AppchainIdAvailability::Registered
+    );
+    common::assert_error_code(
+        &register_appchain(&users[2], &oct_token, &registry, "my_chain").await?,
+        "APPCHAIN_ID_NOT_AVAILABLE",
+    );
+    //
+    // Deny patterns
+    //
+    assert!(
+        registry_settings::add_appchain_id_deny_pattern(&root, &registry, "*near*")
+            .await
+            .unwrap()
+            .is_success()
+    );
+    assert_eq!(
+        registry_viewer::check_appchain_id_availability(&registry, "super-near-chain", None)
+            .await?,
+        AppchainIdAvailability::Denied {
+            pattern: "*near*".to_string()
+        }
+    );
+    common::assert_error_code(
+        &register_appchain(&users[2], &oct_token, &registry, "super-near-chain").await?,
+        "APPCHAIN_ID_NOT_AVAILABLE",
+    );
+    common::assert_no_failure(
+        &register_appchain(&users[2], &oct_token, &registry, "super-chain").await?,
+    );
+    Ok(())
+}
+
+async fn register_appchain(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+) -> anyhow::Result<ExecutionFinalResult> {
+    let result = appchain_owner_actions::register_appchain(
+        signer,
+        oct_token,
+        registry,
+        &appchain_id.to_string(),
+        Some("appchain description".to_string()),
+        Some("Barnacle".to_string()),
+        Some("http://ddfs.dsdfs".to_string()),
+        Some("https://github.com/octopus-network/barnacle".to_string()),
+        Some("joe@lksdf.com".to_string()),
+        Some(AccountId::from_str(signer.id().as_str()).unwrap()),
+        Some(U128::from(10000000)),
+        Some(U128::from(100000000)),
+        Some(U128::from(1000000)),
+        Some(U128::from(100)),
+        Some(FungibleTokenMetadata {
+            spec: FT_METADATA_SPEC.to_string(),
+            name: "joeToken".to_string(),
+            symbol: "JOT".to_string(),
+            icon: Option::None,
+            reference: Option::None,
+            reference_hash: Option::None,
+            decimals: 18,
+        }),
+        Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
+        common::to_oct_amount(1000),
+    )
+    .await?;
+    Ok(result)
+}
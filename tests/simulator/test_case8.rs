@@ -0,0 +1,208 @@
+use crate::{
+    common,
+    contract_interfaces::{appchain_owner_actions, registry_settings, registry_viewer},
+};
+use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
+use near_sdk::{json_types::U128, serde_json::json};
+use std::collections::HashMap;
+use workspaces::{result::ExecutionFinalResult, Account, Contract};
+
+const TOTAL_SUPPLY: u128 = 100_000_000;
+
+/// Test the commit-reveal flow of appchain registration.
+#[tokio::test]
+async fn test_case8() -> anyhow::Result<()> {
+    let worker = workspaces::sandbox().await?;
+    let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
+    let (root, oct_token, registry, _council, users) =
+        common::basic_actions::initialize_contracts_and_users(&worker, total_supply, false).await?;
+    //
+    // Registration without commitment is rejected if it is required
+    //
+    common::assert_no_failure(
+        &registry_settings::change_registration_commitment_required(&root, &registry, true).await?,
+    );
+    common::assert_error_code(
+        &reveal_appchain_registration(&users[0], &oct_token, &registry, "appchain1", None).await?,
+        "REGISTRATION_COMMITMENT_REQUIRED",
+    );
+    //
+    // Others can NOT take the appchain id by replaying the revealed message
+    //
+    appchain_owner_actions::commit_appchain_registration(
+        &users[0],
+        &oct_token,
+        &registry,
+        "appchain1",
+    )
+    .await?;
+    assert_eq!(
+        common::get_ft_balance_of(&users[0], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10 - 10)
+    );
+    common::assert_error_code(
+        &reveal_appchain_registration(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain1",
+            Some(appchain_owner_actions::REGISTRATION_SALT),
+        )
+        .await?,
+        "REGISTRATION_COMMITMENT_NOT_FOUND",
+    );
+    common::assert_no_failure(
+        &reveal_appchain_registration(
+            &users[0],
+            &oct_token,
+            &registry,
+            "appchain1",
+            Some(appchain_owner_actions::REGISTRATION_SALT),
+        )
+        .await?,
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[0], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10 - 1000)
+    );
+    //
+    // Expired commitment can NOT be revealed, and can be removed by anyone
+    //
+    assert!(
+        registry_settings::change_registration_reveal_window_in_secs(&root, &registry, 30)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    appchain_owner_actions::commit_appchain_registration(
+        &users[1],
+        &oct_token,
+        &registry,
+        "appchain2",
+    )
+    .await?;
+    let commitment = registry_viewer::compute_registration_commitment(
+        &registry,
+        "appchain2",
+        appchain_owner_actions::REGISTRATION_SALT,
+        &users[1],
+    )
+    .await?;
+    common::assert_error_code(
+        &appchain_owner_actions::remove_expired_registration_commitment(
+            &users[2],
+            &registry,
+            &commitment,
+        )
+        .await?,
+        "REGISTRATION_COMMITMENT_NOT_EXPIRED",
+    );
+    worker.fast_forward(1000).await?;
+    common::assert_error_code(
+        &reveal_appchain_registration(
+            &users[1],
+            &oct_token,
+            &registry,
+            "appchain2",
+            Some(appchain_owner_actions::REGISTRATION_SALT),
+        )
+        .await?,
+        "REGISTRATION_COMMITMENT_EXPIRED",
+    );
+    assert!(
+        appchain_owner_actions::remove_expired_registration_commitment(
+            &users[2],
+            &registry,
+            &commitment
+        )
+        .await
+        .unwrap()
+        .is_success()
+    );
+    assert!(
+        registry_viewer::get_registration_commitment_of(&registry, &commitment)
+            .await?
+            .is_none()
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&users[1], &oct_token).await?.0,
+        common::to_oct_amount(TOTAL_SUPPLY / 10 - 10)
+    );
+    //
+    // Registration without commitment is allowed if it is not required
+    //
+    assert!(
+        registry_settings::change_registration_commitment_required(&root, &registry, false)
+            .await
+            .unwrap()
+            .is_success()
+    );
+    common::assert_no_failure(
+        &reveal_appchain_registration(&users[2], &oct_token, &registry, "appchain3", None).await?,
+    );
+    assert_eq!(
+        common::get_ft_balance_of(&registry.as_account(), &oct_token)
+            .await?
+            .0,
+        common::to_oct_amount(2010)
+    );
+    //
+    // No commitment deposit is left to be refunded
+    //
+    assert_eq!(
+        registry_viewer::get_unrefunded_commitment_deposit_of(&registry, &users[0])
+            .await?
+            .0,
+        0
+    );
+    common::assert_error_code(
+        &appchain_owner_actions::withdraw_unrefunded_commitment_deposit(&users[0], &registry)
+            .await?,
+        "UNREFUNDED_COMMITMENT_DEPOSIT_NOT_FOUND",
+    );
+    Ok(())
+}
+
+async fn reveal_appchain_registration(
+    signer: &Account,
+    oct_token: &Contract,
+    registry: &Contract,
+    appchain_id: &str,
+    registration_salt: Option<&str>,
+) -> anyhow::Result<ExecutionFinalResult> {
+    let result = common::call_ft_transfer_call(
+        signer,
+        &registry.as_account(),
+        common::to_oct_amount(1000),
+        json!({
+            "RegisterAppchain":{
+                "appchain_id": appchain_id,
+                "description": "appchain description",
+                "template_type": "Barnacle",
+                "website_url": "http://ddfs.dsdfs",
+                "github_address": "https://github.com/octopus-network/barnacle",
+                "contact_email": "joe@lksdf.com",
+                "premined_wrapped_appchain_token_beneficiary": signer.id(),
+                "premined_wrapped_appchain_token": U128::from(10000000),
+                "initial_supply_of_wrapped_appchain_token": U128::from(100000000),
+                "ido_amount_of_wrapped_appchain_token": U128::from(1000000),
+                "initial_era_reward": U128::from(100),
+                "fungible_token_metadata": FungibleTokenMetadata {
+                    spec: FT_METADATA_SPEC.to_string(),
+                    name: "joeToken".to_string(),
+                    symbol: "JOT".to_string(),
+                    icon: Option::None,
+                    reference: Option::None,
+                    reference_hash: Option::None,
+                    decimals: 18,
+                },
+                "custom_metadata": HashMap::from([("key1".to_string(), "value1".to_string())]),
+                "registration_salt": registration_salt
+            }
+        })
+        .to_string(),
+        oct_token,
+    )
+    .await?;
+    Ok(result)
+}
@@ -1,6 +1,12 @@
-use crate::common;
+use crate::{
+    common,
+    contract_interfaces::{appchain_owner_actions, registry_viewer},
+};
 use appchain_registry::{
-    types::{AppchainSortingField, AppchainStatus, RegistryRoles, RegistrySettings, SortingOrder},
+    types::{
+        AppchainSortingField, AppchainSortingSpec, AppchainState, AppchainStatus, PagedResult,
+        RegistryRoles, RegistrySettings, SortingOrder,
+    },
 };
 use near_contract_standards::fungible_token::metadata::{FungibleTokenMetadata, FT_METADATA_SPEC};
 use near_sdk::{
@@ -18,7 +24,7 @@ const TOTAL_SUPPLY: u128 = 100_000_000;
 async fn test_case9() -> anyhow::Result<()> {
     let worker = workspaces::sandbox().await?;
     let total_supply = common::to_oct_amount(TOTAL_SUPPLY);
-    let (root, oct_token, registry, _council, users) =
+    let (root, oct_token, registry, council, users) =
         common::basic_actions::initialize_contracts_and_users(&worker, total_supply, true).await?;
     let amount = common::to_oct_amount(1000);
     //
@@ -115,6 +121,43 @@ async fn test_case9() -> anyhow::Result<()> {
         .await;
     print_view_result_details::<AppchainStatus>("get_appchain_status_of", &result);
     //
+    // Boot the appchain before migration
+    //
+    let appchain_id = "appchain1".to_string();
+    for (signer, function_name, args) in [
+        (
+            &root,
+            "pass_auditing_appchain",
+            json!({ "appchain_id": appchain_id }),
+        ),
+        (
+            &root,
+            "start_voting_appchain",
+            json!({
+                "appchain_id": appchain_id,
+                "dao_proposal_url": "https://dao.oct.network/proposals/1",
+            }),
+        ),
+        (
+            &root,
+            "change_octopus_council",
+            json!({ "account": council.id() }),
+        ),
+        (
+            &council,
+            "start_booting_appchain",
+            json!({ "appchain_id": appchain_id }),
+        ),
+    ] {
+        let result = signer
+            .call(registry.id(), function_name)
+            .args_json(args)
+            .gas(200_000_000_000_000)
+            .transact()
+            .await?;
+        assert!(result.is_success(), "Failed in calling '{}'", function_name);
+    }
+    //
     // perform migration
     //
     root.call(registry.id(), "store_wasm_of_self")
@@ -145,6 +188,19 @@ async fn test_case9() -> anyhow::Result<()> {
     //
     print_view_function_results(&registry).await;
     //
+    // The anchor of a migrated live appchain is not stale right after the upgrade
+    //
+    let appchain = registry_viewer::get_appchain_status_of(&registry, &appchain_id).await?;
+    assert_eq!(&appchain.appchain_state, &AppchainState::Booting);
+    assert!(registry_viewer::get_stale_appchains(&registry)
+        .await?
+        .items
+        .is_empty());
+    common::assert_error_code(
+        &appchain_owner_actions::flag_stale_appchain(&users[0], &registry, &appchain_id).await?,
+        "APPCHAIN_ANCHOR_NOT_STALE",
+    );
+    //
     Ok(())
 }
 
@@ -165,8 +221,12 @@ async fn print_view_function_results(registry: &Contract) {
     let result = registry.call("get_total_stake").view().await;
     print_view_result_details::<U128>("get_total_stake", &result);
     //
-    let result = registry.call("get_appchain_ids").view().await;
-    print_view_result_details::<Vec<String>>("get_appchain_ids", &result);
+    let result = registry
+        .call("get_appchain_ids")
+        .args_json(json!({ "from_index": null, "limit": null }))
+        .view()
+        .await;
+    print_view_result_details::<PagedResult<String>>("get_appchain_ids", &result);
     //
     let result = registry
         .call("get_appchains_count_of")
@@ -179,14 +239,19 @@ async fn print_view_function_results(registry: &Contract) {
         .call("get_appchains_with_state_of")
         .args_json(json!({
             "appchain_state": null,
-            "page_number": 1,
-            "page_size": 5,
-            "sorting_field": AppchainSortingField::RegisteredTime,
-            "sorting_order": SortingOrder::Descending,
+            "sorting": [AppchainSortingSpec {
+                field: AppchainSortingField::RegisteredTime,
+                order: SortingOrder::Descending,
+            }],
+            "from_index": null,
+            "limit": 5,
         }))
         .view()
         .await;
-    print_view_result_details::<Vec<AppchainStatus>>("get_appchains_with_state_of", &result);
+    print_view_result_details::<PagedResult<AppchainStatus>>(
+        "get_appchains_with_state_of",
+        &result,
+    );
     //
     let result = registry
         .call("get_appchain_status_of")